@@ -32,6 +32,8 @@ fn create_test_index(file_count: usize, file_size_kb: usize) -> IndexFile {
         max_file_bytes: 10 * 1024 * 1024,
         max_total_bytes: 50 * 1024 * 1024,
         max_chunks_per_file: 2000,
+        notebook_outputs: false,
+        sample_data_rows: None,
     };
 
     ingest_files(files, options)
@@ -57,6 +59,8 @@ fn bench_index_creation(c: &mut Criterion) {
                         max_file_bytes: 10 * 1024 * 1024,
                         max_total_bytes: 50 * 1024 * 1024,
                         max_chunks_per_file: 2000,
+                        notebook_outputs: false,
+                        sample_data_rows: None,
                     };
 
                     black_box(ingest_files(files, options))
@@ -0,0 +1,3 @@
+; C/C++ call sites: free functions, qualified calls, templates and member calls.
+(call_expression
+  function: [(identifier) (qualified_identifier) (template_function) (field_expression)] @call)
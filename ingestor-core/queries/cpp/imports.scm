@@ -0,0 +1,2 @@
+; C/C++ includes: quoted project headers and <system> headers.
+(preproc_include path: (_) @include)
@@ -0,0 +1,3 @@
+; C/C++ type references: plain and namespace-qualified type names.
+(type_identifier) @type
+(qualified_identifier name: [(type_identifier) (qualified_identifier) (template_type)]) @qualified
@@ -0,0 +1,3 @@
+; C# call sites: invocations and `new` expressions.
+(invocation_expression function: (_) @call)
+(object_creation_expression type: (_) @construct)
@@ -0,0 +1,2 @@
+; C# `using` directives, plain and aliased.
+(using_directive) @import
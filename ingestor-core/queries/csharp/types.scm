@@ -0,0 +1,8 @@
+; C# type positions; identifiers are extracted from each captured type.
+(base_list (_) @base)
+(parameter type: (_) @type)
+(method_declaration type: (_) @type)
+(property_declaration type: (_) @type)
+(variable_declaration type: (_) @type)
+(type_argument_list (_) @type)
+(type_constraint type: (_) @type)
@@ -0,0 +1,2 @@
+; Go call sites: plain functions and selector calls (`pkg.Func`, `recv.Method`).
+(call_expression function: [(identifier) (selector_expression)] @call)
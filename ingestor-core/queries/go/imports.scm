@@ -0,0 +1,2 @@
+; Go imports: one capture per spec, with optional alias in the `name` field.
+(import_spec path: (_)) @import
@@ -0,0 +1,3 @@
+; Go type references: local and package-qualified type names.
+(type_identifier) @type
+(qualified_type) @qualified
@@ -0,0 +1,3 @@
+; Java call sites: method invocations and constructor calls.
+(method_invocation) @call
+(object_creation_expression type: (_) @construct)
@@ -0,0 +1,2 @@
+; Java imports: single-type and static imports (on-demand `.*` imports carry the asterisk).
+(import_declaration) @import
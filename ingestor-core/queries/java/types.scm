@@ -0,0 +1,3 @@
+; Java type references, including `extends`/`implements` clauses.
+(type_identifier) @type
+(scoped_type_identifier) @scoped
@@ -0,0 +1,3 @@
+; Python call sites and bare decorators (decorator calls match `call` below).
+(call function: [(identifier) (attribute)] @call)
+(decorator [(identifier) (attribute)] @decorator)
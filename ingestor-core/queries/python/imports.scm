@@ -0,0 +1,3 @@
+; Python imports: plain and `from ... import` statements.
+(import_statement) @import
+(import_from_statement) @import_from
@@ -0,0 +1,3 @@
+; Python type references: annotations and base classes.
+(type) @annotation
+(class_definition superclasses: (argument_list [(identifier) (attribute)] @base))
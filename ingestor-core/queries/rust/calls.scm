@@ -0,0 +1,9 @@
+; Rust call sites: free functions, path calls, method calls and macros.
+(call_expression function: (identifier) @call)
+(call_expression function: (scoped_identifier) @call)
+(call_expression function: (field_expression field: (field_identifier) @call))
+(call_expression function: (generic_function function: (identifier) @call))
+(call_expression function: (generic_function function: (scoped_identifier) @call))
+(call_expression function: (generic_function function: (field_expression field: (field_identifier) @call)))
+(macro_invocation macro: (identifier) @macro)
+(macro_invocation macro: (scoped_identifier) @macro)
@@ -0,0 +1,3 @@
+; Rust imports: `use` trees and out-of-line `mod` declarations.
+(use_declaration argument: (_) @import)
+(mod_item name: (identifier)) @module
@@ -0,0 +1,2 @@
+; Rust type references: named types, trait bounds and generic arguments.
+(type_identifier) @type
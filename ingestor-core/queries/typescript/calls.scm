@@ -0,0 +1,4 @@
+; TypeScript call sites, constructor calls and bare decorators.
+(call_expression function: [(identifier) (member_expression)] @call)
+(new_expression constructor: [(identifier) (member_expression)] @construct)
+(decorator (identifier) @decorator)
@@ -0,0 +1,2 @@
+; TypeScript imports; `import type` and `{ type X }` are told apart by the adapter.
+(import_statement) @import
@@ -0,0 +1,3 @@
+; TypeScript type references: plain and namespace-qualified type names.
+(type_identifier) @type
+(nested_type_identifier) @nested
@@ -143,7 +143,7 @@ enum Commands {
         #[arg(long)]
         pattern: Option<String>,
 
-        /// Filter by kind: function, method, class, interface, type, enum, constant, variable, test
+        /// Filter by kind: function, method, class, interface, type, enum, table, constant, variable, test
         #[arg(long)]
         kind: Option<String>,
 
@@ -161,7 +161,7 @@ enum Commands {
         /// Exact symbol or prefix pattern, for example `parseConfig` or `parse*`
         symbol: String,
 
-        /// Filter by kind: function, method, class, interface, type, enum, constant, variable, test
+        /// Filter by kind: function, method, class, interface, type, enum, table, constant, variable, test
         #[arg(long)]
         kind: Option<String>,
 
@@ -398,6 +398,8 @@ fn cmd_index(
             chunk_target_chars: Some(chunk_size),
             max_file_bytes: Some(max_file),
             max_total_bytes: None,
+            notebook_outputs: None,
+            sample_data_rows: None,
         }),
     };
 
@@ -481,6 +483,8 @@ fn cmd_search(
             kind,
             symbol_prefix: None,
             heading_prefix: None,
+            log_level: None,
+            exclude_generated: None,
         }),
         limit: Some(limit),
         max_tokens: Some(max_tokens),
@@ -591,6 +595,8 @@ fn cmd_search_persistent(
             kind,
             symbol_prefix: None,
             heading_prefix: None,
+            log_level: None,
+            exclude_generated: None,
         }),
         limit: Some(limit),
         max_tokens: Some(max_tokens),
@@ -1049,6 +1049,7 @@ mod tests {
                 is_generated: false,
                 quality_score: None,
                 resolution_tier: ResolutionTier::GenericTreeSitter,
+                log_level: None,
                 ast_kind: None,
                 qualified_name: None,
                 symbol_id: None,
@@ -1060,6 +1061,7 @@ mod tests {
                 imports: Vec::new(),
                 exports: Vec::new(),
                 calls: Vec::new(),
+                method_calls: Vec::new(),
                 type_refs: Vec::new(),
                 doc_summary: None,
             }],
@@ -1,11 +1,20 @@
+mod formats;
 mod generic;
 mod language;
 mod legacy;
+mod notebook;
+mod parsers;
+#[cfg(feature = "treesitter")]
+mod query_loader;
 mod registry;
+mod sfc;
 #[cfg(test)]
 mod symbol_id;
 
-use crate::model::{Chunk, ChunkKind, IngestOptions};
+pub(crate) use formats::sample_records;
+pub(crate) use parsers::GoModules;
+
+use crate::model::{Chunk, ChunkKind, IngestOptions, ResolutionTier};
 
 pub fn chunk_file(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Vec<Chunk> {
     registry::parse(path, text, kind, options)
@@ -17,3 +26,9 @@ pub fn chunk_file(path: &str, text: &str, kind: ChunkKind, options: &IngestOptio
         })
         .unwrap_or_else(|| legacy::chunk_file(path, text, kind, options))
 }
+
+/// The tier files like `path` are parsed at, before scope resolution raises any of
+/// their chunks.
+pub(crate) fn adapter_tier(path: &str, kind: ChunkKind) -> ResolutionTier {
+    registry::adapter_tier(path, kind).unwrap_or(ResolutionTier::TextOnly)
+}
@@ -0,0 +1,420 @@
+//! Line-oriented adapters for languages and file formats without a bundled
+//! tree-sitter grammar.
+//!
+//! These scan the text with regular expressions and a small amount of lexical
+//! state (quotes, comments, here-documents), so they work in builds without the
+//! `treesitter` feature. Each returns `None` when a file has no structure worth
+//! keeping, which leaves it to the plain-text chunker.
+
+mod css;
+mod csv;
+mod dockerfile;
+mod graphql;
+mod jsonl;
+mod log;
+mod makefile;
+mod openapi;
+mod proto;
+mod shell;
+mod sql;
+mod terraform;
+mod toml;
+mod xml;
+mod yaml;
+
+pub(crate) use css::CssAdapter;
+pub(crate) use csv::CsvAdapter;
+pub(crate) use dockerfile::DockerfileAdapter;
+pub(crate) use graphql::GraphqlAdapter;
+pub(crate) use jsonl::JsonlAdapter;
+pub(crate) use log::LogAdapter;
+pub(crate) use makefile::MakefileAdapter;
+pub(crate) use openapi::OpenApiAdapter;
+pub(crate) use proto::ProtoAdapter;
+pub(crate) use shell::ShellAdapter;
+pub(crate) use sql::SqlAdapter;
+pub(crate) use terraform::TerraformAdapter;
+pub(crate) use toml::TomlAdapter;
+pub(crate) use xml::XmlAdapter;
+pub(crate) use yaml::YamlAdapter;
+
+use crate::chunk::legacy::ChunkDraft;
+use crate::model::{ChunkKind, IngestOptions};
+
+/// A keyed span of a configuration file: a YAML mapping key or sequence item, a
+/// TOML table or key. Line numbers are 0-based and inclusive.
+struct KeyNode {
+    /// Key path as addressed, e.g. `jobs.build.steps[2]` or `dependencies.serde`.
+    path: String,
+    /// Path segments, with sequence indexes kept on their key (`steps[2]`).
+    heading: Vec<String>,
+    start: usize,
+    end: usize,
+    children: Vec<KeyNode>,
+}
+
+impl KeyNode {
+    fn child_path(&self, key: &str) -> (String, Vec<String>) {
+        let mut heading = self.heading.clone();
+        heading.push(key.to_string());
+        let path = if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{key}", self.path)
+        };
+        (path, heading)
+    }
+
+    fn item_path(&self, index: usize) -> (String, Vec<String>) {
+        let mut heading = self.heading.clone();
+        match heading.last_mut() {
+            Some(last) => last.push_str(&format!("[{index}]")),
+            None => heading.push(format!("[{index}]")),
+        }
+        (format!("{}[{index}]", self.path), heading)
+    }
+}
+
+/// Pull `start` up over the `#` comment lines directly above it, but not past `floor`.
+fn with_leading_comments(lines: &[&str], start: usize, floor: usize) -> usize {
+    let mut first = start;
+    while first > floor && lines[first - 1].trim_start().starts_with('#') {
+        first -= 1;
+    }
+    first
+}
+
+/// The first sentence of a block of `#` (or `//`) comment lines, for `doc_summary`.
+fn comment_summary<'a>(lines: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let joined = lines
+        .into_iter()
+        .map(|line| line.trim().trim_start_matches(['#', '/']).trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let sentence = joined.split(". ").next().unwrap_or(&joined).trim();
+    (!sentence.is_empty()).then(|| sentence.chars().take(200).collect())
+}
+
+/// Drafts for the top-level lines in `from..to` that belong to no declaration,
+/// packed up to the target size, with the files `import` finds on them as imports.
+fn loose_line_drafts(
+    lines: &[&str],
+    (from, to): (usize, usize),
+    kind: ChunkKind,
+    options: &IngestOptions,
+    import: impl Fn(&str) -> Option<String>,
+    drafts: &mut Vec<ChunkDraft>,
+) {
+    let content_lines: Vec<usize> = (from..to).filter(|idx| !lines[*idx].trim().is_empty()).collect();
+    let Some(&first) = content_lines.first() else {
+        return;
+    };
+    let mut start = first;
+    for (position, &idx) in content_lines.iter().enumerate() {
+        let next = content_lines.get(position + 1).copied();
+        if next.is_none_or(|next| span_len(lines, start, next) > options.chunk_target_chars) {
+            let imports = lines[start..=idx].iter().filter_map(|line| import(line)).collect();
+            let content = lines[start..=idx].join("\n");
+            drafts.push(ChunkDraft {
+                imports,
+                ..ChunkDraft::plain(kind, start + 1, idx + 1, content, Vec::new(), None, None)
+            });
+            start = next.unwrap_or(idx);
+        }
+    }
+}
+
+/// Byte offset of the `}` closing the `{` at `open`, skipping quoted strings,
+/// `"""` block strings, `/* */` comments and comments starting with one of
+/// `line_comments`.
+fn closing_brace(text: &str, open: usize, line_comments: &[&str]) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let skip_past = |from: usize, needle: &[u8]| {
+        bytes[from..]
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .map_or(bytes.len(), |found| from + found + needle.len() - 1)
+    };
+    let mut depth = 0usize;
+    let mut idx = open;
+    while idx < bytes.len() {
+        let rest = &bytes[idx..];
+        match bytes[idx] {
+            b'"' if rest.starts_with(b"\"\"\"") => idx = skip_past(idx + 3, b"\"\"\""),
+            quote @ (b'"' | b'\'') => {
+                idx += 1;
+                while idx < bytes.len() && bytes[idx] != quote && bytes[idx] != b'\n' {
+                    idx += if bytes[idx] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'/' if rest.starts_with(b"/*") => idx = skip_past(idx + 2, b"*/"),
+            _ if line_comments.iter().any(|comment| rest.starts_with(comment.as_bytes())) => {
+                idx = skip_past(idx, b"\n");
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Stretch sibling spans so they cover `start..=end` without gaps: each runs up to
+/// the next one, the first from `start` and the last to `end`.
+fn partition(nodes: &mut [KeyNode], start: usize, end: usize) {
+    let count = nodes.len();
+    for idx in 0..count {
+        if idx == 0 {
+            nodes[idx].start = start;
+        }
+        nodes[idx].end = if idx + 1 < count { nodes[idx + 1].start - 1 } else { end };
+    }
+}
+
+/// Drafts for the top-level nodes of each root (a document, or a root element),
+/// the root's children. Runs of small siblings are packed up to
+/// `chunk_target_chars`, and a node larger than that is split into its children
+/// the same way; a node without children is split by lines, addressed `path#n`.
+fn key_node_drafts(
+    lines: &[&str],
+    mut roots: Vec<KeyNode>,
+    symbol: impl Fn(&KeyNode) -> Option<String>,
+    kind: ChunkKind,
+    options: &IngestOptions,
+) -> Vec<ChunkDraft> {
+    let mut drafts = Vec::new();
+    if roots.is_empty() || lines.is_empty() {
+        return drafts;
+    }
+    partition(&mut roots, 0, lines.len() - 1);
+    for root in &mut roots {
+        if root.children.is_empty() {
+            let key_line = root.start;
+            split_node(lines, root, key_line, &None, kind, options, &mut drafts);
+            continue;
+        }
+        let symbols: Vec<Option<String>> = root.children.iter().map(&symbol).collect();
+        pack_children(lines, root, &symbols, kind, options, &mut drafts);
+    }
+    drafts
+}
+
+fn span_len(lines: &[&str], start: usize, end: usize) -> usize {
+    lines[start..=end].iter().map(|line| line.len() + 1).sum()
+}
+
+fn push_span(
+    lines: &[&str],
+    (start, end): (usize, usize),
+    address: &str,
+    heading: &[String],
+    symbol: &Option<String>,
+    kind: ChunkKind,
+    drafts: &mut Vec<ChunkDraft>,
+) {
+    let content = lines[start..=end].join("\n").trim().to_string();
+    if content.is_empty() {
+        return;
+    }
+    drafts.push(ChunkDraft::plain(
+        kind,
+        start + 1,
+        end + 1,
+        content,
+        heading.to_vec(),
+        symbol.clone(),
+        (!address.is_empty()).then(|| address.to_string()),
+    ));
+}
+
+/// Drafts for `node`, split when larger than the target size. Split by lines, the
+/// first part runs at least through `key_line`, so a table header or parent key
+/// stretched over the node stays with the node's own first line.
+fn split_node(
+    lines: &[&str],
+    node: &mut KeyNode,
+    key_line: usize,
+    symbol: &Option<String>,
+    kind: ChunkKind,
+    options: &IngestOptions,
+    drafts: &mut Vec<ChunkDraft>,
+) {
+    if span_len(lines, node.start, node.end) <= options.chunk_target_chars {
+        push_span(lines, (node.start, node.end), &node.path, &node.heading, symbol, kind, drafts);
+        return;
+    }
+
+    if node.children.is_empty() {
+        let mut part = 1;
+        let mut first = node.start;
+        for line in node.start..=node.end {
+            let last = line == node.end;
+            if last || (line >= key_line && span_len(lines, first, line + 1) > options.chunk_target_chars) {
+                // A key line kept with the lines above it may leave a single part.
+                let address = if part == 1 && last {
+                    node.path.clone()
+                } else {
+                    format!("{}#{part}", node.path)
+                };
+                push_span(lines, (first, line), &address, &node.heading, symbol, kind, drafts);
+                part += 1;
+                first = line + 1;
+            }
+        }
+        return;
+    }
+
+    let symbols = vec![symbol.clone(); node.children.len()];
+    pack_children(lines, node, &symbols, kind, options, drafts);
+}
+
+/// Drafts for the children of `node`, stretched to cover it. Runs of consecutive
+/// children within the target size share a chunk, addressed by the child's path
+/// when alone, by `node`'s when they are all of its children, and by the range
+/// they cover otherwise, leaving out children that carry `node`'s own path;
+/// larger children are split further. `symbols` holds each child's symbol.
+fn pack_children(
+    lines: &[&str],
+    node: &mut KeyNode,
+    symbols: &[Option<String>],
+    kind: ChunkKind,
+    options: &IngestOptions,
+    drafts: &mut Vec<ChunkDraft>,
+) {
+    let key_lines: Vec<usize> = node.children.iter().map(|child| child.start).collect();
+    partition(&mut node.children, node.start, node.end);
+    // Consecutive small children: `(first child, last child)`.
+    let mut run: Option<(usize, usize)> = None;
+    let flush = |run: &mut Option<(usize, usize)>, children: &[KeyNode], drafts: &mut Vec<ChunkDraft>| {
+        if let Some((first, last)) = run.take() {
+            // A node's own start and end tags at the ends of the run don't name it.
+            let own = |idx: &usize| children[*idx].path == node.path;
+            let named = (first..=last).find(|idx| !own(idx)).zip((first..=last).rev().find(|idx| !own(idx)));
+            let (address, heading) = match named {
+                _ if first == 0 && last + 1 == children.len() => (node.path.clone(), &node.heading),
+                Some((a, b)) if a == b => (children[a].path.clone(), &children[a].heading),
+                Some((a, b)) => (run_address(&children[a].path, &children[b].path), &node.heading),
+                None => (node.path.clone(), &node.heading),
+            };
+            // Children packed together keep a symbol only when they all share it.
+            let (a, b) = named.unwrap_or((first, last));
+            let shared = symbols[a..=b].iter().all(|symbol| *symbol == symbols[a]);
+            let symbol = if shared { symbols[a].clone() } else { None };
+            let span = (children[first].start, children[last].end);
+            push_span(lines, span, &address, heading, &symbol, kind, drafts);
+        }
+    };
+    let mut children = std::mem::take(&mut node.children);
+    for idx in 0..children.len() {
+        let child_len = span_len(lines, children[idx].start, children[idx].end);
+        if child_len > options.chunk_target_chars {
+            flush(&mut run, &children, drafts);
+            split_node(lines, &mut children[idx], key_lines[idx], &symbols[idx], kind, options, drafts);
+            continue;
+        }
+        run = match run {
+            Some((first, _)) if span_len(lines, children[first].start, children[idx].end) <= options.chunk_target_chars => {
+                Some((first, idx))
+            }
+            _ => {
+                flush(&mut run, &children, drafts);
+                Some((idx, idx))
+            }
+        };
+    }
+    flush(&mut run, &children, drafts);
+    node.children = children;
+}
+
+/// Address of a packed run of siblings: `bin[1..3]` for items of one array, half-open
+/// like record ranges, else `first..last` by their paths.
+fn run_address(first: &str, last: &str) -> String {
+    let item = |path: &str| {
+        let (key, index) = path.strip_suffix(']')?.rsplit_once('[')?;
+        Some((key.to_string(), index.parse::<usize>().ok()?))
+    };
+    match (item(first), item(last)) {
+        (Some((key, start)), Some((last_key, end))) if key == last_key => format!("{key}[{start}..{}]", end + 1),
+        _ => format!("{first}..{last}"),
+    }
+}
+
+/// Drafts of data records packed up to the target size, addressed by the 0-based,
+/// half-open range of records each holds (`rows[40..80]`). A CSV header is repeated
+/// at the top of every chunk; only the first chunk's lines include it.
+fn record_drafts(
+    lines: &[&str],
+    header: Option<RecordSpan>,
+    records: &[RecordSpan],
+    kind: ChunkKind,
+    options: &IngestOptions,
+) -> Vec<ChunkDraft> {
+    let header_text = header.map(|(start, end)| lines[start..=end].join("\n"));
+    let header_len = header.map_or(0, |(start, end)| span_len(lines, start, end));
+    let mut drafts = Vec::new();
+    let mut first = 0;
+    let mut size = header_len;
+    for idx in 0..=records.len() {
+        let len = records.get(idx).map(|&(start, end)| span_len(lines, start, end));
+        let full = idx > first && len.is_none_or(|len| size + len > options.chunk_target_chars);
+        if full {
+            let start = match (first, header) {
+                (0, Some((header_start, _))) => header_start,
+                _ => records[first].0,
+            };
+            let end = records[idx - 1].1;
+            let mut content = header_text.as_ref().map(|text| format!("{text}\n")).unwrap_or_default();
+            content.push_str(&lines[records[first].0..=end].join("\n"));
+            let address = format!("rows[{first}..{idx}]");
+            drafts.push(ChunkDraft::plain(kind, start + 1, end + 1, content, Vec::new(), None, Some(address)));
+            first = idx;
+            size = header_len;
+        }
+        size += len.unwrap_or(0);
+    }
+    drafts
+}
+
+/// Line span of a data record, 0-based and inclusive.
+type RecordSpan = (usize, usize);
+
+/// Header and record spans of a CSV or JSON Lines file, by extension.
+fn data_records(path: &str, lines: &[&str]) -> Option<(Option<RecordSpan>, Vec<RecordSpan>)> {
+    let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+    match extension.as_str() {
+        "csv" | "tsv" => {
+            let mut records = csv::records(lines);
+            if records.is_empty() {
+                return None;
+            }
+            let header = records.remove(0);
+            Some((Some(header), records))
+        }
+        "jsonl" | "ndjson" => Some((None, jsonl::records(lines))),
+        _ => None,
+    }
+}
+
+/// `text` cut down to its header and first `limit` records when a CSV or JSON Lines
+/// file holds more than that, with the number of records in the whole file.
+pub(crate) fn sample_records(path: &str, text: &str, limit: usize) -> Option<(String, usize)> {
+    let lines: Vec<&str> = text.lines().collect();
+    let (header, records) = data_records(path, &lines)?;
+    if records.len() <= limit {
+        return None;
+    }
+    let end = match limit {
+        0 => header.map_or(0, |(_, end)| end + 1),
+        _ => records[limit - 1].1 + 1,
+    };
+    let mut sample = lines[..end].join("\n");
+    sample.push('\n');
+    Some((sample, records.len()))
+}
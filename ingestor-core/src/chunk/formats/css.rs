@@ -0,0 +1,524 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// CSS, SCSS and Less: one chunk per rule set, at-rule block and mixin, with the
+/// selector (nested selectors resolved against their parent) as the symbol.
+/// Top-level statements such as `@use` and variables are grouped between them.
+/// The indented `.sass` syntax has no braces and stays plain text.
+pub(crate) struct CssAdapter;
+
+impl LanguageAdapter for CssAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Css)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Css) && !request.path.to_ascii_lowercase().ends_with(".sass")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_css_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// `@use`, `@forward` and `@import` statements.
+fn import_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"@(?:use|forward|import)\b([^;{}]*)").expect("css import regex"))
+}
+
+/// The quoted or `url(...)` targets of an import statement.
+fn import_target_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#""([^"]+)"|'([^']+)'|url\(\s*([^)"'\s]+)\s*\)"#).expect("css import target regex")
+    })
+}
+
+fn include_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"@include\s+([\w.-]+)").expect("css include regex"))
+}
+
+fn extend_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"@extend\s+([^;{}!]+)").expect("css extend regex"))
+}
+
+/// A top-level or nested piece of a stylesheet, as byte offsets into the text.
+enum Item {
+    /// A declaration or at-rule statement, with any comments before it.
+    Statement { start: usize, end: usize },
+    /// A `header { ... }` block, with any comments before the header.
+    Block {
+        start: usize,
+        open: usize,
+        end: usize,
+        children: Vec<Item>,
+    },
+}
+
+impl Item {
+    fn span(&self) -> (usize, usize) {
+        match self {
+            Item::Statement { start, end } | Item::Block { start, end, .. } => (*start, *end),
+        }
+    }
+}
+
+/// Split a stylesheet into statements and (nested) blocks, skipping over comments,
+/// strings, parentheses and `#{...}` interpolation.
+fn parse_items(text: &str, line_comments: bool) -> Vec<Item> {
+    let bytes = text.as_bytes();
+    // Open blocks: start, `{` offset, children so far.
+    let mut frames: Vec<(usize, usize, Vec<Item>)> = Vec::new();
+    let mut roots = Vec::new();
+    let mut pending: Option<usize> = None;
+    let mut parens = 0usize;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let ch = bytes[idx];
+        let container = match frames.last_mut() {
+            Some((_, _, children)) => children,
+            None => &mut roots,
+        };
+        match ch {
+            _ if ch.is_ascii_whitespace() => {
+                idx += 1;
+                continue;
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                pending.get_or_insert(idx);
+                idx = text[idx + 2..].find("*/").map_or(text.len(), |len| idx + len + 4);
+                continue;
+            }
+            b'/' if line_comments && parens == 0 && bytes.get(idx + 1) == Some(&b'/') => {
+                pending.get_or_insert(idx);
+                idx = text[idx..].find('\n').map_or(text.len(), |len| idx + len);
+                continue;
+            }
+            b'"' | b'\'' => {
+                pending.get_or_insert(idx);
+                idx = text[idx + 1..].find(ch as char).map_or(text.len(), |len| idx + len + 2);
+                continue;
+            }
+            b'#' if bytes.get(idx + 1) == Some(&b'{') => {
+                pending.get_or_insert(idx);
+                idx = text[idx..].find('}').map_or(text.len(), |len| idx + len + 1);
+                continue;
+            }
+            b'(' => parens += 1,
+            b')' => parens = parens.saturating_sub(1),
+            b';' if parens == 0 => {
+                let start = pending.take().unwrap_or(idx);
+                container.push(Item::Statement { start, end: idx + 1 });
+                idx += 1;
+                continue;
+            }
+            b'{' => {
+                let start = pending.take().unwrap_or(idx);
+                frames.push((start, idx, Vec::new()));
+                parens = 0;
+                idx += 1;
+                continue;
+            }
+            b'}' => {
+                if let Some(start) = pending.take() {
+                    container.push(Item::Statement { start, end: idx });
+                }
+                if let Some((start, open, children)) = frames.pop() {
+                    let block = Item::Block {
+                        start,
+                        open,
+                        end: idx + 1,
+                        children,
+                    };
+                    match frames.last_mut() {
+                        Some((_, _, children)) => children.push(block),
+                        None => roots.push(block),
+                    }
+                }
+                idx += 1;
+                continue;
+            }
+            _ => {}
+        }
+        pending.get_or_insert(idx);
+        idx += text[idx..].chars().next().map_or(1, char::len_utf8);
+    }
+
+    if let Some(start) = pending {
+        match frames.last_mut() {
+            Some((_, _, children)) => children.push(Item::Statement { start, end: text.len() }),
+            None => roots.push(Item::Statement { start, end: text.len() }),
+        }
+    }
+    while let Some((start, open, children)) = frames.pop() {
+        let block = Item::Block {
+            start,
+            open,
+            end: text.len(),
+            children,
+        };
+        match frames.last_mut() {
+            Some((_, _, children)) => children.push(block),
+            None => roots.push(block),
+        }
+    }
+    roots
+}
+
+/// A block header without comments, on one line.
+fn clean_header(header: &str, line_comments: bool) -> String {
+    let mut cleaned = String::with_capacity(header.len());
+    let mut rest = header;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("/*") {
+            rest = after.find("*/").map_or("", |idx| &after[idx + 2..]);
+            cleaned.push(' ');
+        } else if line_comments && rest.starts_with("//") {
+            rest = rest.find('\n').map_or("", |idx| &rest[idx..]);
+        } else {
+            let ch = rest.chars().next().unwrap_or(' ');
+            cleaned.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// What a block declares: its symbol and kind.
+struct Rule {
+    symbol: String,
+    kind: AstNodeKind,
+    /// Selector that nested `&` references expand to, when the block is a rule set.
+    selector: Option<String>,
+}
+
+fn classify(header: &str, parent_selector: Option<&str>, declarations: &[String], has_blocks: bool) -> Rule {
+    if let Some(at_rule) = header.strip_prefix('@') {
+        let keyword = at_rule.split(|ch: char| !(ch.is_alphanumeric() || ch == '-')).next().unwrap_or("");
+        if matches!(keyword, "mixin" | "function") {
+            let name = at_rule[keyword.len()..]
+                .trim_start()
+                .split(|ch: char| ch == '(' || ch.is_whitespace())
+                .next()
+                .unwrap_or("");
+            return Rule {
+                symbol: name.to_string(),
+                kind: AstNodeKind::Function,
+                selector: None,
+            };
+        }
+        return Rule {
+            symbol: header.to_string(),
+            kind: AstNodeKind::Other,
+            selector: parent_selector.map(str::to_string),
+        };
+    }
+
+    // Less mixin definitions: `.rounded(@radius: 4px)`.
+    if header.starts_with(['.', '#']) && header.ends_with(')') && !header.contains(',') {
+        if let Some((name, _)) = header.split_once('(') {
+            if !name.contains([' ', ':', '>']) {
+                return Rule {
+                    symbol: name.to_string(),
+                    kind: AstNodeKind::Function,
+                    selector: None,
+                };
+            }
+        }
+    }
+
+    let selector = match parent_selector {
+        Some(parent) if !parent.contains(',') => {
+            let parts: Vec<String> = header
+                .split(',')
+                .map(str::trim)
+                .map(|part| {
+                    if part.contains('&') {
+                        part.replace('&', parent)
+                    } else {
+                        format!("{parent} {part}")
+                    }
+                })
+                .collect();
+            parts.join(", ")
+        }
+        _ => header.to_string(),
+    };
+    let custom_properties = !has_blocks
+        && !declarations.is_empty()
+        && declarations.iter().all(|declaration| declaration.trim_start().starts_with("--"));
+    Rule {
+        symbol: selector.clone(),
+        kind: if custom_properties {
+            AstNodeKind::Variable
+        } else {
+            AstNodeKind::Other
+        },
+        selector: Some(selector),
+    }
+}
+
+struct Sheet<'a> {
+    text: &'a str,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<usize>,
+    kind: ChunkKind,
+    line_comments: bool,
+    target: usize,
+}
+
+impl Sheet<'_> {
+    fn line_at(&self, byte: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= byte)
+    }
+
+    /// A draft for `start..end`, with the imports, `@include` calls and `@extend`
+    /// references written in it.
+    fn draft(&self, start: usize, end: usize) -> ChunkDraft {
+        let content = &self.text[start..end];
+        let mut imports: Vec<String> = Vec::new();
+        for statement in import_re().captures_iter(content) {
+            for target in import_target_re().captures_iter(&statement[1]) {
+                let target = (1..=3).find_map(|group| target.get(group)).map_or("", |found| found.as_str());
+                if !imports.iter().any(|import| import == target) {
+                    imports.push(target.to_string());
+                }
+            }
+        }
+        let mut calls: Vec<String> = Vec::new();
+        for include in include_re().captures_iter(content) {
+            if !calls.iter().any(|call| call == &include[1]) {
+                calls.push(include[1].to_string());
+            }
+        }
+        let mut type_refs: Vec<String> = Vec::new();
+        for extend in extend_re().captures_iter(content) {
+            let target = extend[1].trim().to_string();
+            if !type_refs.contains(&target) {
+                type_refs.push(target);
+            }
+        }
+        ChunkDraft {
+            imports,
+            calls,
+            method_calls: Vec::new(),
+            type_refs,
+            ..ChunkDraft::plain(
+                self.kind,
+                self.line_at(start),
+                self.line_at(end.saturating_sub(1).max(start)),
+                content.trim().to_string(),
+                Vec::new(),
+                None,
+                None,
+            )
+        }
+    }
+
+    /// Runs of consecutive statements become one chunk each, up to the target size.
+    fn statements(&self, spans: &[(usize, usize)], drafts: &mut Vec<ChunkDraft>, label: impl Fn(ChunkDraft) -> ChunkDraft) {
+        let mut run: Option<(usize, usize)> = None;
+        for &(start, end) in spans {
+            run = match run {
+                Some((first, _)) if end - first <= self.target => Some((first, end)),
+                Some((first, last)) => {
+                    drafts.push(label(self.draft(first, last)));
+                    Some((start, end))
+                }
+                None => Some((start, end)),
+            };
+        }
+        if let Some((first, last)) = run {
+            drafts.push(label(self.draft(first, last)));
+        }
+    }
+
+    /// Blocks under `parent` (its heading path and selector), or the top level of the sheet.
+    fn items(&self, items: &[Item], parent: Option<(&[String], Option<&str>)>, drafts: &mut Vec<ChunkDraft>) {
+        let mut statements: Vec<(usize, usize)> = Vec::new();
+        for item in items {
+            let Item::Block {
+                start,
+                open,
+                end,
+                children,
+            } = item
+            else {
+                statements.push(item.span());
+                continue;
+            };
+            self.statements(&statements, drafts, |draft| draft);
+            statements.clear();
+
+            let header = clean_header(&self.text[*start..*open], self.line_comments);
+            let mut declarations: Vec<(usize, usize)> = children
+                .iter()
+                .filter(|child| matches!(child, Item::Statement { .. }))
+                .map(Item::span)
+                .collect();
+            let declaration_text: Vec<String> = declarations
+                .iter()
+                .map(|&(start, end)| clean_header(&self.text[start..end], self.line_comments))
+                .collect();
+            let has_blocks = declarations.len() < children.len();
+            let rule = classify(&header, parent.and_then(|(_, selector)| selector), &declaration_text, has_blocks);
+            let mut heading_path = parent.map_or_else(Vec::new, |(heading, _)| heading.to_vec());
+            heading_path.push(rule.symbol.clone());
+
+            let symbol = Some(rule.symbol.clone()).filter(|symbol| !symbol.is_empty());
+            let label = |draft: ChunkDraft| ChunkDraft {
+                heading_path: heading_path.clone(),
+                symbol: symbol.clone(),
+                ast_kind: Some(rule.kind),
+                qualified_name: symbol.clone(),
+                signature: Some(header.clone()),
+                parent_symbol: parent.and_then(|(heading, _)| heading.last().cloned()),
+                ..draft
+            };
+            if end - start <= self.target || !has_blocks {
+                drafts.push(label(self.draft(*start, *end)));
+                continue;
+            }
+
+            // Too large: the block's own declarations, led by its header, then each nested block.
+            if let Some(first) = declarations.first_mut() {
+                first.0 = *start;
+            }
+            self.statements(&declarations, drafts, label);
+            let nested = (heading_path.as_slice(), rule.selector.as_deref());
+            for child in children.iter().filter(|child| matches!(child, Item::Block { .. })) {
+                self.items(std::slice::from_ref(child), Some(nested), drafts);
+            }
+        }
+        self.statements(&statements, drafts, |draft| draft);
+    }
+}
+
+fn parse_css_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lower = path.to_ascii_lowercase();
+    let line_comments = lower.ends_with(".scss") || lower.ends_with(".less");
+    let items = parse_items(text, line_comments);
+    if !items.iter().any(|item| matches!(item, Item::Block { .. })) {
+        return None;
+    }
+    let line_starts = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let sheet = Sheet {
+        text,
+        line_starts,
+        kind,
+        line_comments,
+        target: options.chunk_target_chars,
+    };
+    let mut drafts = Vec::new();
+    sheet.items(&items, None, &mut drafts);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(path: &str, text: &str, chunk_target_chars: usize) -> Vec<crate::Chunk> {
+        let options = IngestOptions {
+            chunk_target_chars,
+            ..IngestOptions::default()
+        };
+        parse_css_chunks(path, text, ChunkKind::Unknown, &options).expect("stylesheet should chunk")
+    }
+
+    fn symbols(chunks: &[crate::Chunk]) -> Vec<&str> {
+        chunks.iter().map(|chunk| chunk.symbol.as_deref().unwrap_or("")).collect()
+    }
+
+    #[test]
+    fn rule_sets_and_at_rules_become_symbols() {
+        let text = r#"@import url(reset.css);
+
+:root {
+  --brand: #0a58ca;
+  --radius: 4px;
+}
+
+/* Buttons */
+.btn-primary,
+.btn-primary:hover {
+  color: var(--brand);
+  background: url("data:image/svg+xml;{}");
+}
+
+@media (max-width: 600px) {
+  .btn-primary { width: 100%; }
+}
+"#;
+        let chunks = chunks("theme.css", text, 4_000);
+        assert_eq!(
+            symbols(&chunks),
+            ["", ":root", ".btn-primary, .btn-primary:hover", "@media (max-width: 600px)"]
+        );
+        assert_eq!(chunks[0].imports, ["reset.css"]);
+        assert_eq!(chunks[1].ast_kind, Some(AstNodeKind::Variable));
+        let buttons = &chunks[2];
+        assert_eq!((buttons.start_line, buttons.end_line), (8, 13));
+        assert!(buttons.content.starts_with("/* Buttons */"));
+        assert_eq!(buttons.heading_path, [".btn-primary, .btn-primary:hover"]);
+        assert_eq!(chunks[3].end_line, 17);
+    }
+
+    #[test]
+    fn scss_nesting_resolves_selectors_and_records_uses_and_mixins() {
+        let text = r#"@use "sass:math";
+@use 'tokens' as t; // design tokens
+
+@mixin button-variant($background) {
+  background: $background;
+}
+
+.btn {
+  padding: 0 math.div(t.$space, 2);
+  // Variants {
+  &-primary {
+    @include button-variant(t.$brand);
+  }
+  &-ghost {
+    @extend .btn-link;
+    border: 0;
+  }
+}
+"#;
+        let whole = chunks("buttons.scss", text, 4_000);
+        assert_eq!(symbols(&whole), ["", "button-variant", ".btn"]);
+        assert_eq!(whole[0].imports, ["sass:math", "tokens"]);
+        assert_eq!(whole[1].ast_kind, Some(AstNodeKind::Function));
+
+        let split = chunks("buttons.scss", text, 80);
+        assert_eq!(symbols(&split)[2..], [".btn", ".btn-primary", ".btn-ghost"]);
+        assert!(split[2].content.starts_with(".btn {\n  padding"), "{}", split[2].content);
+        let primary = &split[3];
+        assert_eq!(primary.heading_path, [".btn", ".btn-primary"]);
+        assert_eq!(primary.calls, ["button-variant"]);
+        assert_eq!(primary.start_line, 10);
+        assert_eq!(split[4].type_refs, [".btn-link"]);
+    }
+
+    #[test]
+    fn stylesheets_without_blocks_stay_plain_text() {
+        let options = IngestOptions::default();
+        assert!(parse_css_chunks("vars.scss", "$brand: #000;\n", ChunkKind::Unknown, &options).is_none());
+    }
+}
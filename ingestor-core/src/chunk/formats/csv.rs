@@ -0,0 +1,128 @@
+use super::record_drafts;
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::finalize_chunks;
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+
+/// CSV and TSV: groups of rows, each chunk repeating the header line so that its
+/// rows stay searchable by column name. Addressed by row range (`rows[40..80]`).
+pub(crate) struct CsvAdapter;
+
+impl LanguageAdapter for CsvAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("csv".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(extension)) if extension == "csv" || extension == "tsv")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_csv_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Line spans of the records of a CSV file, header first. A quoted field may run
+/// over several lines.
+pub(super) fn records(lines: &[&str]) -> Vec<(usize, usize)> {
+    let mut records = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut quoted = false;
+    for (idx, line) in lines.iter().enumerate() {
+        if start.is_none() && line.trim().is_empty() {
+            continue;
+        }
+        let first = *start.get_or_insert(idx);
+        // `""` inside a quoted field toggles twice and leaves it open.
+        quoted ^= line.bytes().filter(|byte| *byte == b'"').count() % 2 == 1;
+        if !quoted {
+            records.push((first, idx));
+            start = None;
+        }
+    }
+    if let Some(first) = start {
+        records.push((first, lines.len() - 1));
+    }
+    records
+}
+
+fn parse_csv_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let records = records(&lines);
+    let (header, rows) = records.split_first()?;
+    if rows.is_empty() {
+        return None;
+    }
+    let drafts = record_drafts(&lines, Some(*header), rows, kind, options);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_groups_repeat_the_header() {
+        let text = "\
+id,name,notes
+1,Ada,\"wrote the first
+program\"
+2,Grace,compilers
+3,Edsger,\"\"\"goto\"\" considered harmful\"
+4,Barbara,abstract data types
+";
+        let options = IngestOptions {
+            chunk_target_chars: 90,
+            ..IngestOptions::default()
+        };
+        let chunks = parse_csv_chunks("people.csv", text, ChunkKind::Text, &options).expect("csv should chunk");
+        let addresses: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["rows[0..2]", "rows[2..4]"]);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 4));
+        assert_eq!((chunks[1].start_line, chunks[1].end_line), (5, 6));
+        assert_eq!(
+            chunks[1].content,
+            "id,name,notes\n3,Edsger,\"\"\"goto\"\" considered harmful\"\n4,Barbara,abstract data types"
+        );
+    }
+    #[test]
+    fn quoted_newlines_stay_inside_their_record() {
+        // A quoted header cell, a field holding a blank line, a blank line between
+        // records, and a quote left open at the end of the file.
+        let text = "\
+id,\"release
+notes\"
+1,\"first line
+
+after a blank line\"
+
+2,plain
+3,\"never closed
+4,swallowed
+";
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(records(&lines), [(0, 1), (2, 4), (6, 6), (7, 8)]);
+
+        let options = IngestOptions {
+            chunk_target_chars: 60,
+            ..IngestOptions::default()
+        };
+        let chunks = parse_csv_chunks("releases.csv", text, ChunkKind::Text, &options).expect("csv should chunk");
+        let addresses: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["rows[0..1]", "rows[1..3]"]);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 5));
+        assert!(chunks[1].content.starts_with("id,\"release\nnotes\"\n2,plain"), "{}", chunks[1].content);
+
+        // Sampling cuts after whole records, never inside a quoted field.
+        let (sample, total) = crate::chunk::sample_records("releases.csv", text, 1).expect("sampled");
+        assert_eq!(total, 3);
+        assert!(sample.ends_with("after a blank line\"\n"), "{sample}");
+    }
+}
@@ -0,0 +1,205 @@
+use super::{comment_summary, with_leading_comments};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Parser directives, written as comments at the top of the file.
+const DIRECTIVES: &[&str] = &["syntax=", "escape=", "check="];
+
+fn is_directive(line: &str) -> bool {
+    let comment = line.trim_start_matches(['#', ' ']);
+    DIRECTIVES.iter().any(|directive| comment.starts_with(directive))
+}
+
+fn from_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)^\s*FROM\s+(?:--\S+\s+)*(\S+)(?:\s+AS\s+([A-Za-z0-9_.-]+))?").expect("dockerfile from regex")
+    })
+}
+
+fn arg_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)^\s*ARG\s+([A-Za-z_]\w*)=(\S+)").expect("dockerfile arg regex"))
+}
+
+fn variable_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\$\{(\w+)\}|\$(\w+)").expect("dockerfile variable regex"))
+}
+
+/// `image` with the `ARG`s declared before the first stage substituted by their
+/// defaults; variables without one are left as written.
+fn expand_args(image: &str, args: &[(String, String)]) -> String {
+    variable_re()
+        .replace_all(image, |caps: &regex::Captures| {
+            let name = caps.get(1).or(caps.get(2)).map_or("", |name| name.as_str());
+            match args.iter().find(|(arg, _)| arg == name) {
+                Some((_, value)) => value.clone(),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+fn copy_from_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)--from=([^\s\\]+)").expect("dockerfile copy regex"))
+}
+
+/// Dockerfiles: one chunk per build stage, from its `FROM` line to the next. Named
+/// stages (`FROM rust:1.80 AS build`) become symbols; base images and the stages
+/// that `FROM` and `COPY --from=` pull in are recorded as imports, with global
+/// `ARG` defaults filled in.
+pub(crate) struct DockerfileAdapter;
+
+impl LanguageAdapter for DockerfileAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("dockerfile".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == self.language_id()
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_dockerfile_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+fn parse_dockerfile_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let froms: Vec<usize> = (0..lines.len()).filter(|idx| from_re().is_match(lines[*idx])).collect();
+    if froms.is_empty() {
+        return None;
+    }
+
+    let args: Vec<(String, String)> = lines[..froms[0]]
+        .iter()
+        .filter_map(|line| arg_re().captures(line))
+        .map(|caps| (caps[1].to_string(), caps[2].trim_matches(['"', '\'']).to_string()))
+        .collect();
+
+    let mut drafts = Vec::new();
+    for (index, &from) in froms.iter().enumerate() {
+        // The first stage also takes the parser directives and `ARG`s above it.
+        let start = if index == 0 { 0 } else { with_leading_comments(&lines, from, froms[index - 1] + 1) };
+        let end = froms.get(index + 1).map_or(lines.len(), |next| with_leading_comments(&lines, *next, from + 1));
+        let end = (from..end).rfind(|idx| !lines[*idx].trim().is_empty()).unwrap_or(from);
+
+        let caps = from_re().captures(lines[from]).expect("stage line matches");
+        let name = caps.get(2).map(|name| name.as_str().to_string());
+        let mut imports = vec![expand_args(&caps[1], &args)];
+        for line in &lines[from + 1..=end] {
+            for caps in copy_from_re().captures_iter(line) {
+                if !imports.contains(&caps[1].to_string()) {
+                    imports.push(caps[1].to_string());
+                }
+            }
+        }
+        imports.retain(|image| !image.eq_ignore_ascii_case("scratch"));
+
+        let comment_start = with_leading_comments(&lines, from, start);
+        drafts.push(ChunkDraft {
+            kind,
+            start_line: start + 1,
+            end_line: end + 1,
+            content: lines[start..=end].join("\n").trim().to_string(),
+            heading_path: Vec::new(),
+            symbol: name.clone(),
+            address: Some(format!("stage[{index}]")),
+            ast_kind: name.as_ref().map(|_| AstNodeKind::Module),
+            qualified_name: name.clone(),
+            signature: Some(lines[from].trim().to_string()),
+            parent_symbol: None,
+            imports,
+            exports: Vec::new(),
+            calls: Vec::new(),
+            method_calls: Vec::new(),
+            type_refs: Vec::new(),
+            doc_summary: comment_summary(lines[comment_start..from].iter().copied().filter(|line| !is_directive(line))),
+            symbol_id: None,
+            symbol_tail: name,
+            module_path: None,
+            visibility: None,
+            log_level: None,
+        });
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stages_become_symbols_with_their_sources_as_imports() {
+        let text = "\
+# syntax=docker/dockerfile:1
+ARG RUST_VERSION=1.80
+
+# Compile the release binary.
+FROM rust:${RUST_VERSION} AS build
+WORKDIR /src
+COPY . .
+RUN cargo build --release
+
+FROM gcr.io/distroless/cc AS runtime
+COPY --from=build /src/target/release/llmx /usr/local/bin/llmx
+ENTRYPOINT [\"llmx\"]
+";
+        let chunks = parse_dockerfile_chunks("Dockerfile", text, ChunkKind::Unknown).expect("dockerfile should chunk");
+        assert_eq!(chunks.len(), 2);
+
+        let build = &chunks[0];
+        assert_eq!(build.symbol.as_deref(), Some("build"));
+        assert_eq!((build.start_line, build.end_line), (1, 8));
+        assert_eq!(build.imports, ["rust:1.80"]);
+        assert_eq!(build.doc_summary.as_deref(), Some("Compile the release binary."));
+
+        let runtime = &chunks[1];
+        assert_eq!(runtime.symbol.as_deref(), Some("runtime"));
+        assert_eq!(runtime.address.as_deref(), Some("stage[1]"));
+        assert_eq!((runtime.start_line, runtime.end_line), (10, 12));
+        assert_eq!(runtime.imports, ["gcr.io/distroless/cc", "build"]);
+    }
+    #[test]
+    fn platform_flags_stage_bases_and_unset_args() {
+        let text = "\
+ARG BASE=\"node:20-alpine\"
+ARG TAG
+FROM --platform=$BUILDPLATFORM ${BASE} AS deps
+RUN npm ci
+
+FROM deps AS test
+RUN npm test
+
+FROM registry.example.com/app:$TAG
+COPY --from=deps /app/node_modules ./node_modules
+";
+        let chunks = parse_dockerfile_chunks("Dockerfile", text, ChunkKind::Unknown).expect("dockerfile should chunk");
+        let imports: Vec<_> = chunks.iter().map(|chunk| chunk.imports.clone()).collect();
+        assert_eq!(
+            imports,
+            [
+                vec!["node:20-alpine".to_string()],
+                vec!["deps".to_string()],
+                vec!["registry.example.com/app:$TAG".to_string(), "deps".to_string()],
+            ]
+        );
+        assert_eq!(chunks[0].signature.as_deref(), Some("FROM --platform=$BUILDPLATFORM ${BASE} AS deps"));
+        assert_eq!(chunks[1].symbol.as_deref(), Some("test"));
+        assert_eq!(chunks[2].symbol, None);
+        assert_eq!(chunks[2].address.as_deref(), Some("stage[2]"));
+    }
+}
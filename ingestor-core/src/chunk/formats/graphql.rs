@@ -0,0 +1,446 @@
+use super::{closing_brace, comment_summary, loose_line_drafts};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Built-in scalars, which name no type in the schema.
+const SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
+/// Types whose fields are the schema's entry points; each field is chunked on its own.
+const ROOT_TYPES: &[&str] = &["Query", "Mutation", "Subscription"];
+
+fn definition_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^(extend\s+)?(type|input|interface|enum|union|scalar|schema|directive|query|mutation|subscription|fragment)\b(?:\s+@?([A-Za-z_]\w*))?",
+        )
+        .expect("graphql definition regex")
+    })
+}
+
+fn field_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s*([A-Za-z_]\w*)\s*[(:]").expect("graphql field regex"))
+}
+
+/// `: [User!]!` in field, argument and input value definitions.
+fn type_ref_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r":\s*\[*\s*([A-Za-z_]\w*)").expect("graphql type regex"))
+}
+
+/// Names after `implements` (`A & B`) or a union's `=` (`A | B`).
+fn member_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?:\bimplements|=|&|\|)\s*&?\s*([A-Za-z_]\w*)").expect("graphql member regex")
+    })
+}
+
+/// Variable types (`$id: ID!`) and type conditions (`on User`) in operations and fragments.
+fn operation_ref_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\$\w+\s*:\s*\[*\s*([A-Za-z_]\w*)|\bon\s+([A-Za-z_]\w*)").expect("graphql operation regex")
+    })
+}
+
+fn spread_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\.\.\.\s*([A-Za-z_]\w*)").expect("graphql spread regex"))
+}
+
+fn import_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"^#\s*import\s+(?:.*\bfrom\s+)?["']([^"']+)["']"#).expect("graphql import regex")
+    })
+}
+
+/// GraphQL schemas and documents: one chunk per type, input, interface, enum and
+/// union, and one per field of the `Query`, `Mutation` and `Subscription` root
+/// types (`Query.user`). Operations and fragments become symbols too. The types a
+/// definition's fields, arguments and variables name are recorded as type
+/// references, and fragment spreads as calls.
+pub(crate) struct GraphqlAdapter;
+
+impl LanguageAdapter for GraphqlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("graphql".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(extension)) if extension == "graphql" || extension == "gql")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_graphql_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Pull `start` up over the descriptions (`"..."`, `"""..."""`) and `#` comments
+/// directly above it, but not past `floor`.
+fn with_descriptions(lines: &[&str], start: usize, floor: usize) -> usize {
+    let mut first = start;
+    while first > floor {
+        let above = lines[first - 1].trim();
+        let closes_block = above.ends_with("\"\"\"") && (above == "\"\"\"" || !above.starts_with("\"\"\""));
+        if closes_block {
+            match (floor..first - 1).rev().find(|idx| lines[*idx].trim().starts_with("\"\"\"")) {
+                Some(opening) => first = opening,
+                None => break,
+            }
+        } else if above.starts_with('#') || (above.len() >= 2 && above.starts_with('"') && above.ends_with('"')) {
+            first -= 1;
+        } else {
+            break;
+        }
+    }
+    first
+}
+
+/// The first sentence of the descriptions and comments in `lines`.
+fn description_summary(lines: &[&str]) -> Option<String> {
+    comment_summary(lines.iter().map(|line| line.trim().trim_matches('"')))
+}
+
+/// Last line of the definition whose header is on line `idx`: its closing brace,
+/// or, for bodiless definitions (`union`, `scalar`), its last continuation line.
+fn definition_end(text: &str, lines: &[&str], line_starts: &[usize], idx: usize) -> usize {
+    let line_at = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+    let mut last = idx;
+    for line in idx..lines.len() {
+        if line > idx && (lines[line].trim().is_empty() || !lines[line].starts_with([' ', '\t', '|', '&', '='])) {
+            break;
+        }
+        if let Some(column) = lines[line].find('{') {
+            return closing_brace(text, line_starts[line] + column, &["#"]).map_or(lines.len() - 1, line_at);
+        }
+        last = line;
+    }
+    last
+}
+
+fn push_name(names: &mut Vec<String>, name: &str, own: &str) {
+    if name != own && !SCALARS.contains(&name) && !names.iter().any(|existing| existing == name) {
+        names.push(name.to_string());
+    }
+}
+
+/// Types named by the field and argument definitions in `body`.
+fn field_types(body: &str, own: &str) -> Vec<String> {
+    let mut type_refs = Vec::new();
+    for caps in type_ref_re().captures_iter(body) {
+        push_name(&mut type_refs, &caps[1], own);
+    }
+    type_refs
+}
+
+/// A top-level definition: keyword, name, and its lines (first with descriptions,
+/// header, last).
+struct Definition<'a> {
+    keyword: &'a str,
+    name: Option<&'a str>,
+    start: usize,
+    header: usize,
+    end: usize,
+}
+
+fn definition_draft(lines: &[&str], definition: &Definition<'_>, kind: ChunkKind) -> ChunkDraft {
+    let Definition { keyword, name, start, header, end } = *definition;
+    let own = name.unwrap_or("");
+    let header_text = lines[header..=end]
+        .iter()
+        .take_while(|line| !line.contains('{'))
+        .chain(lines[header..=end].iter().find(|line| line.contains('{')))
+        .map(|line| line.split('{').next().unwrap_or(line).trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let body = lines[header..=end].join("\n");
+    let executable = matches!(keyword, "query" | "mutation" | "subscription" | "fragment");
+
+    let mut type_refs = Vec::new();
+    let mut calls = Vec::new();
+    if executable {
+        for caps in operation_ref_re().captures_iter(&body) {
+            let name = caps.get(1).or(caps.get(2)).map_or("", |name| name.as_str());
+            push_name(&mut type_refs, name, own);
+        }
+        for caps in spread_re().captures_iter(&body) {
+            if &caps[1] != "on" {
+                push_name(&mut calls, &caps[1], own);
+            }
+        }
+    } else {
+        if matches!(keyword, "type" | "interface" | "union") {
+            for caps in member_re().captures_iter(&header_text) {
+                push_name(&mut type_refs, &caps[1], own);
+            }
+        }
+        let fields = body.split_once('{').map_or("", |(_, fields)| fields);
+        for name in field_types(fields, own) {
+            push_name(&mut type_refs, &name, own);
+        }
+    }
+    let ast_kind = match keyword {
+        "type" => AstNodeKind::Class,
+        "interface" => AstNodeKind::Interface,
+        "enum" => AstNodeKind::Enum,
+        "input" | "union" | "scalar" => AstNodeKind::Type,
+        _ if executable => AstNodeKind::Function,
+        _ => AstNodeKind::Other,
+    };
+    ChunkDraft {
+        kind,
+        start_line: start + 1,
+        end_line: end + 1,
+        content: lines[start..=end].join("\n").trim().to_string(),
+        heading_path: Vec::new(),
+        symbol: name.map(str::to_string),
+        address: None,
+        ast_kind: name.map(|_| ast_kind),
+        qualified_name: name.map(str::to_string),
+        signature: Some(header_text),
+        parent_symbol: None,
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls,
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary: description_summary(&lines[start..header]),
+        symbol_id: None,
+        symbol_tail: name.map(str::to_string),
+        module_path: None,
+        visibility: None,
+        log_level: None,
+    }
+}
+
+/// One draft per field of a root type. The first field takes the type's header and
+/// the last its closing brace.
+fn root_field_drafts(lines: &[&str], definition: &Definition<'_>, root: &str, kind: ChunkKind) -> Vec<ChunkDraft> {
+    let Definition { start, header, end, .. } = *definition;
+    let candidates: Vec<usize> = (header + 1..end).filter(|idx| field_re().is_match(lines[*idx])).collect();
+    let indent = |idx: usize| lines[idx].len() - lines[idx].trim_start().len();
+    let Some(field_indent) = candidates.iter().map(|idx| indent(*idx)).min() else {
+        return vec![definition_draft(lines, definition, kind)];
+    };
+    let fields: Vec<usize> = candidates.into_iter().filter(|idx| indent(*idx) == field_indent).collect();
+    let starts: Vec<usize> = fields
+        .iter()
+        .enumerate()
+        .map(|(position, idx)| match position {
+            0 => start,
+            _ => with_descriptions(lines, *idx, fields[position - 1] + 1),
+        })
+        .collect();
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(position, &field)| {
+            let field_end = starts.get(position + 1).map_or(end, |next| next - 1);
+            let field_end = (field..=field_end).rfind(|line| !lines[*line].trim().is_empty()).unwrap_or(field);
+            let definition_lines: Vec<&str> =
+                lines[field..=field_end].iter().copied().filter(|line| line.trim() != "}").collect();
+            let signature = definition_lines.iter().map(|line| line.trim()).collect::<Vec<_>>().join(" ");
+            let name = field_re().captures(lines[field]).map_or("", |caps| caps.get(1).map_or("", |name| name.as_str()));
+            let doc_start = with_descriptions(lines, field, starts[position]);
+            ChunkDraft {
+                kind,
+                start_line: starts[position] + 1,
+                end_line: field_end + 1,
+                content: lines[starts[position]..=field_end].join("\n").trim().to_string(),
+                heading_path: Vec::new(),
+                symbol: Some(name.to_string()),
+                address: None,
+                ast_kind: Some(AstNodeKind::Method),
+                qualified_name: Some(format!("{root}.{name}")),
+                signature: Some(signature),
+                parent_symbol: Some(root.to_string()),
+                imports: Vec::new(),
+                exports: Vec::new(),
+                calls: Vec::new(),
+                method_calls: Vec::new(),
+                type_refs: field_types(&definition_lines.join("\n"), ""),
+                doc_summary: description_summary(&lines[doc_start..field]),
+                symbol_id: None,
+                symbol_tail: Some(name.to_string()),
+                module_path: None,
+                visibility: None,
+                log_level: None,
+            }
+        })
+        .collect()
+}
+
+fn parse_graphql_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let import = |line: &str| import_re().captures(line).map(|caps| caps[1].to_string());
+
+    let mut drafts = Vec::new();
+    let mut region_start = 0;
+    let mut idx = 0;
+    while idx < lines.len() {
+        let Some(caps) = definition_re().captures(lines[idx]) else {
+            idx += 1;
+            continue;
+        };
+        let definition = Definition {
+            keyword: caps.get(2).map_or("", |keyword| keyword.as_str()),
+            name: caps.get(3).map(|name| name.as_str()),
+            start: with_descriptions(&lines, idx, region_start),
+            header: idx,
+            end: definition_end(text, &lines, &line_starts, idx),
+        };
+        loose_line_drafts(&lines, (region_start, definition.start), kind, options, import, &mut drafts);
+        match definition.name {
+            Some(root) if definition.keyword == "type" && ROOT_TYPES.contains(&root) => {
+                drafts.extend(root_field_drafts(&lines, &definition, root, kind));
+            }
+            _ => drafts.push(definition_draft(&lines, &definition, kind)),
+        }
+        region_start = definition.end + 1;
+        idx = definition.end + 1;
+    }
+    if !drafts.iter().any(|draft| draft.symbol.is_some()) {
+        return None;
+    }
+    loose_line_drafts(&lines, (region_start, lines.len()), kind, options, import, &mut drafts);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(path: &str, text: &str) -> Vec<crate::Chunk> {
+        parse_graphql_chunks(path, text, ChunkKind::Unknown, &IngestOptions::default()).expect("graphql should chunk")
+    }
+
+    #[test]
+    fn schema_types_and_root_fields_become_symbols() {
+        let text = r#"scalar DateTime
+
+"""
+A registered account.
+"""
+type User implements Node & Entity {
+  id: ID!
+  posts(first: Int = 10, after: Cursor): [Post!]!
+  createdAt: DateTime
+}
+
+union SearchResult = User | Post
+
+type Query {
+  "Fetch one user by ID."
+  user(id: ID!): User
+
+  search(
+    term: String!
+    filter: SearchFilter
+  ): [SearchResult!]!
+}
+"#;
+        let chunks = chunks("schema.graphql", text);
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref().unwrap_or("")).collect();
+        assert_eq!(symbols, ["DateTime", "User", "SearchResult", "user", "search"]);
+
+        let user = &chunks[1];
+        assert_eq!((user.start_line, user.end_line), (3, 10));
+        assert_eq!(user.type_refs, ["Node", "Entity", "Cursor", "Post", "DateTime"]);
+        assert_eq!(user.doc_summary.as_deref(), Some("A registered account."));
+        assert_eq!(user.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(chunks[2].type_refs, ["User", "Post"]);
+
+        let query = &chunks[3];
+        assert_eq!(query.qualified_name.as_deref(), Some("Query.user"));
+        assert_eq!(query.parent_symbol.as_deref(), Some("Query"));
+        assert_eq!((query.start_line, query.end_line), (14, 16));
+        assert_eq!(query.type_refs, ["User"]);
+        assert_eq!(query.doc_summary.as_deref(), Some("Fetch one user by ID."));
+
+        let search = &chunks[4];
+        assert_eq!((search.start_line, search.end_line), (18, 22));
+        assert_eq!(search.type_refs, ["SearchFilter", "SearchResult"]);
+        assert_eq!(search.signature.as_deref(), Some("search( term: String! filter: SearchFilter ): [SearchResult!]!"));
+    }
+
+    #[test]
+    fn operations_record_variable_types_and_fragment_spreads() {
+        let text = r#"# import "./fragments.graphql"
+
+query UserProfile($id: ID!, $since: DateTime) {
+  user(id: $id) {
+    ...UserFields
+    ... on Admin { permissions }
+  }
+}
+
+fragment UserFields on User {
+  name
+}
+"#;
+        let chunks = chunks("queries/profile.graphql", text);
+        assert_eq!(chunks[0].imports, ["./fragments.graphql"]);
+
+        let query = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("UserProfile")).expect("query");
+        assert_eq!(query.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(query.type_refs, ["DateTime", "Admin"]);
+        assert_eq!(query.calls, ["UserFields"]);
+
+        let fragment = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("UserFields")).expect("fragment");
+        assert_eq!(fragment.type_refs, ["User"]);
+    }
+    #[test]
+    fn braces_in_strings_one_line_bodies_and_extended_roots() {
+        let text = r#""""
+Accounts, addressed as `{id}`.
+"""
+type Account { id: ID! role: Role }
+
+enum Role { ADMIN MEMBER }
+
+extend type Query {
+  """
+  Look up an account; returns `{}` when missing.
+  """
+  account(id: ID!, filter: String = "}"): Account
+  accounts: [Account!]!
+}
+
+type Mutation {
+  closeAccount(id: ID!): Boolean
+}
+"#;
+        let chunks = chunks("schema.graphql", text);
+        let names: Vec<_> = chunks.iter().map(|chunk| chunk.qualified_name.as_deref().unwrap_or("")).collect();
+        assert_eq!(names, ["Account", "Role", "Query.account", "Query.accounts", "Mutation.closeAccount"]);
+
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 4));
+        assert_eq!(chunks[0].type_refs, ["Role"]);
+        assert_eq!((chunks[1].start_line, chunks[1].end_line), (6, 6));
+
+        let account = &chunks[2];
+        assert_eq!((account.start_line, account.end_line), (8, 12));
+        assert_eq!(account.doc_summary.as_deref(), Some("Look up an account; returns `{}` when missing."));
+        assert_eq!(account.signature.as_deref(), Some(r#"account(id: ID!, filter: String = "}"): Account"#));
+        assert_eq!((chunks[3].start_line, chunks[3].end_line), (13, 14));
+        assert!(chunks[4].type_refs.is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+use super::record_drafts;
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::finalize_chunks;
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+
+/// JSON Lines: groups of records, addressed by record range (`rows[40..80]`).
+pub(crate) struct JsonlAdapter;
+
+impl LanguageAdapter for JsonlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Json)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        let path = request.path.to_ascii_lowercase();
+        path.ends_with(".jsonl") || path.ends_with(".ndjson")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_jsonl_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Line spans of the records of a JSON Lines file: its non-blank lines.
+pub(super) fn records(lines: &[&str]) -> Vec<(usize, usize)> {
+    (0..lines.len())
+        .filter(|idx| !lines[*idx].trim().is_empty())
+        .map(|idx| (idx, idx))
+        .collect()
+}
+
+fn parse_jsonl_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let records = records(&lines);
+    if records.is_empty() {
+        return None;
+    }
+    let drafts = record_drafts(&lines, None, &records, kind, options);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_grouped_by_range() {
+        let text = "{\"id\":1,\"event\":\"signup\"}\n\n{\"id\":2,\"event\":\"login\"}\n{\"id\":3,\"event\":\"logout\"}\n";
+        let options = IngestOptions {
+            chunk_target_chars: 60,
+            ..IngestOptions::default()
+        };
+        let chunks = parse_jsonl_chunks("events.jsonl", text, ChunkKind::Text, &options).expect("jsonl should chunk");
+        let addresses: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["rows[0..2]", "rows[2..3]"]);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 3));
+        assert_eq!(chunks[1].content, "{\"id\":3,\"event\":\"logout\"}");
+    }
+}
@@ -0,0 +1,363 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{ChunkKind, IngestOptions, LanguageId, LogLevel, ResolutionTier};
+use crate::util::compact_repeated_lines_by;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Log files: entries (a timestamped line and the continuation lines under it) are
+/// grouped by request ID when most entries carry one, and otherwise by minute,
+/// packed up to the target size. Repeated lines are folded, and each chunk records
+/// its most severe level.
+pub(crate) struct LogAdapter;
+
+impl LanguageAdapter for LogAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("log".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == self.language_id()
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_log_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// How far into a line a timestamp may start (after a host, PID or `[`).
+const TIMESTAMP_PREFIX_BYTES: usize = 48;
+
+/// ISO 8601, syslog (`May  1 10:03:22`), common log format (`01/May/2024:10:03:22`)
+/// and bare `10:03:22` timestamps; `minute` is the stamp truncated to the minute.
+fn timestamp_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"\b(?P<minute>(?:\d{4}-\d{2}-\d{2}[T ]|\d{2}/[A-Z][a-z]{2}/\d{4}:|(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec) +\d{1,2} )?\d{2}:\d{2}):\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?",
+        )
+        .expect("log timestamp regex")
+    })
+}
+
+fn request_id_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"(?i)\b(?:x-)?(?:request|req|trace|correlation)[_-]?id["']?\s*[=:]\s*["']?([\w.:-]+)"#)
+            .expect("log request id regex")
+    })
+}
+
+/// Upper-case level words, and `level=warn` / `[warn]` in any case.
+fn level_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        let levels = "trace|debug|info|notice|warn|warning|error|err|fatal|critical|crit|panic|emerg|alert";
+        Regex::new(&format!(
+            r#"\b({upper})\b|(?i:\blevel["']?\s*[=:]\s*["']?({levels})\b|\[({levels})\])"#,
+            upper = levels.to_ascii_uppercase(),
+        ))
+        .expect("log level regex")
+    })
+}
+
+fn log_level(line: &str) -> Option<LogLevel> {
+    let captures = level_re().captures(line)?;
+    let word = (1..=3).find_map(|group| captures.get(group))?.as_str().to_ascii_lowercase();
+    Some(match word.as_str() {
+        "trace" => LogLevel::Trace,
+        "debug" => LogLevel::Debug,
+        "info" | "notice" => LogLevel::Info,
+        "warn" | "warning" => LogLevel::Warn,
+        "error" | "err" => LogLevel::Error,
+        _ => LogLevel::Fatal,
+    })
+}
+
+fn timestamp(line: &str) -> Option<regex::Captures<'_>> {
+    timestamp_re()
+        .captures(line)
+        .filter(|captures| captures.get(0).is_some_and(|stamp| stamp.start() <= TIMESTAMP_PREFIX_BYTES))
+}
+
+/// A line with its timestamp removed, so that repeats differing only in time fold.
+fn without_timestamp(line: &str) -> String {
+    match timestamp(line).and_then(|captures| captures.get(0)) {
+        Some(stamp) => format!("{}{}", &line[..stamp.start()], &line[stamp.end()..]),
+        None => line.to_string(),
+    }
+}
+
+/// A timestamped line and the continuation lines (stack traces, wrapped messages) under
+/// it. Lines are 0-based and inclusive.
+struct Entry {
+    start: usize,
+    end: usize,
+    stamp: Option<String>,
+    minute: Option<String>,
+    request: Option<String>,
+    level: Option<LogLevel>,
+}
+
+/// Entries chunked together: one request's, or a run of minutes.
+struct Window {
+    request: Option<String>,
+    entries: Vec<usize>,
+}
+
+struct LogFile<'a> {
+    lines: Vec<&'a str>,
+    entries: Vec<Entry>,
+    kind: ChunkKind,
+    target: usize,
+}
+
+impl LogFile<'_> {
+    fn entry_len(&self, entry: usize) -> usize {
+        let entry = &self.entries[entry];
+        self.lines[entry.start..=entry.end].iter().map(|line| line.len() + 1).sum()
+    }
+
+    fn window_len(&self, window: &Window) -> usize {
+        window.entries.iter().map(|entry| self.entry_len(*entry)).sum()
+    }
+
+    /// Drafts for a window, split between entries when it exceeds the target size.
+    fn drafts(&self, window: &Window, drafts: &mut Vec<ChunkDraft>) {
+        let mut parts: Vec<&[usize]> = Vec::new();
+        let mut first = 0;
+        let mut size = 0;
+        for (position, entry) in window.entries.iter().enumerate() {
+            let len = self.entry_len(*entry);
+            if position > first && size + len > self.target {
+                parts.push(&window.entries[first..position]);
+                first = position;
+                size = 0;
+            }
+            size += len;
+        }
+        parts.push(&window.entries[first..]);
+
+        let split = parts.len() > 1;
+        for (part, entries) in parts.into_iter().enumerate() {
+            let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+                continue;
+            };
+            let (first, last) = (&self.entries[*first], &self.entries[*last]);
+            let content: Vec<&str> = entries
+                .iter()
+                .flat_map(|entry| {
+                    let entry = &self.entries[*entry];
+                    self.lines[entry.start..=entry.end].iter().copied()
+                })
+                .collect();
+            let (content, _) = compact_repeated_lines_by(&content.join("\n"), 3, without_timestamp);
+            let content = content.trim().to_string();
+            if content.is_empty() {
+                continue;
+            }
+
+            let (heading, address) = match &window.request {
+                Some(request) => {
+                    let address = if split {
+                        format!("request:{request}#{}", part + 1)
+                    } else {
+                        format!("request:{request}")
+                    };
+                    (format!("request {request}"), Some(address))
+                }
+                None => {
+                    let address = match (&first.stamp, &last.stamp) {
+                        (Some(from), Some(to)) if from != to => Some(format!("{from}..{to}")),
+                        (Some(from), _) => Some(from.clone()),
+                        (None, to) => to.clone(),
+                    };
+                    (first.minute.clone().or_else(|| last.minute.clone()).unwrap_or_default(), address)
+                }
+            };
+            let end = entries.iter().map(|entry| self.entries[*entry].end).max().unwrap_or(last.end);
+            drafts.push(ChunkDraft {
+                log_level: entries.iter().filter_map(|entry| self.entries[*entry].level).max(),
+                ..ChunkDraft::plain(
+                    self.kind,
+                    first.start + 1,
+                    end + 1,
+                    content,
+                    vec![heading].into_iter().filter(|heading| !heading.is_empty()).collect(),
+                    None,
+                    address,
+                )
+            });
+        }
+    }
+}
+
+fn parse_log_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut entries: Vec<Entry> = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        match timestamp(line) {
+            Some(captures) => entries.push(Entry {
+                start: idx,
+                end: idx,
+                stamp: captures.get(0).map(|stamp| stamp.as_str().to_string()),
+                minute: captures.name("minute").map(|minute| minute.as_str().to_string()),
+                request: request_id_re().captures(line).map(|request| request[1].to_string()),
+                level: log_level(line),
+            }),
+            None => match entries.last_mut() {
+                Some(entry) => entry.end = idx,
+                None => entries.push(Entry {
+                    start: idx,
+                    end: idx,
+                    stamp: None,
+                    minute: None,
+                    request: None,
+                    level: None,
+                }),
+            },
+        }
+    }
+
+    // Leave files that are mostly untimestamped (or not logs at all) to the text chunker.
+    let stamped = entries.iter().filter(|entry| entry.stamp.is_some()).count();
+    let content_lines = lines.iter().filter(|line| !line.trim().is_empty()).count();
+    if stamped < 2 || stamped * 5 < content_lines {
+        return None;
+    }
+
+    let with_request = entries.iter().filter(|entry| entry.request.is_some()).count();
+    let by_request = with_request * 5 >= stamped * 3;
+
+    let mut windows: Vec<Window> = Vec::new();
+    let mut request_windows: HashMap<String, usize> = HashMap::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        if let Some(request) = entry.request.as_ref().filter(|_| by_request) {
+            let window = *request_windows.entry(request.clone()).or_insert_with(|| {
+                windows.push(Window {
+                    request: Some(request.clone()),
+                    entries: Vec::new(),
+                });
+                windows.len() - 1
+            });
+            windows[window].entries.push(idx);
+            continue;
+        }
+        let same_minute = windows.last().is_some_and(|window| {
+            window.request.is_none()
+                && window.entries.last().is_some_and(|last| entries[*last].minute == entry.minute)
+        });
+        if !same_minute {
+            windows.push(Window {
+                request: None,
+                entries: Vec::new(),
+            });
+        }
+        if let Some(window) = windows.last_mut() {
+            window.entries.push(idx);
+        }
+    }
+
+    let log = LogFile {
+        lines,
+        entries,
+        kind,
+        target: options.chunk_target_chars,
+    };
+    // Pack consecutive minutes together while they fit.
+    let mut packed: Vec<Window> = Vec::new();
+    for window in windows {
+        if let Some(previous) = packed.last_mut() {
+            if previous.request.is_none()
+                && window.request.is_none()
+                && log.window_len(previous) + log.window_len(&window) <= log.target
+            {
+                previous.entries.extend(window.entries);
+                continue;
+            }
+        }
+        packed.push(window);
+    }
+
+    let mut drafts = Vec::new();
+    for window in &packed {
+        log.drafts(window, &mut drafts);
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str, chunk_target_chars: usize) -> Vec<crate::Chunk> {
+        let options = IngestOptions {
+            chunk_target_chars,
+            ..IngestOptions::default()
+        };
+        parse_log_chunks("app.log", text, ChunkKind::Text, &options).expect("log should chunk")
+    }
+
+    #[test]
+    fn entries_are_windowed_by_minute_with_repeats_folded() {
+        let text = "\
+2024-05-01T10:03:01Z INFO server listening on :8080
+2024-05-01T10:03:02Z WARN retrying upstream
+2024-05-01T10:03:03Z WARN retrying upstream
+2024-05-01T10:03:04Z WARN retrying upstream
+2024-05-01T10:03:05Z WARN retrying upstream
+2024-05-01T10:04:10Z ERROR upstream unavailable
+Traceback (most recent call last):
+  File \"app.py\", line 3, in <module>
+2024-05-01T10:04:11Z INFO shutting down
+";
+        let whole = chunks(text, 4_000);
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].log_level, Some(LogLevel::Error));
+        assert!(
+            whole[0].content.contains("WARN retrying upstream\n... (previous line repeated 3 more times)"),
+            "{}",
+            whole[0].content
+        );
+        assert_eq!(whole[0].address.as_deref(), Some("2024-05-01T10:03:01Z..2024-05-01T10:04:11Z"));
+
+        let windows = chunks(text, 260);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].heading_path, ["2024-05-01T10:03"]);
+        assert_eq!(windows[0].log_level, Some(LogLevel::Warn));
+        assert_eq!((windows[1].start_line, windows[1].end_line), (6, 9));
+        assert_eq!(windows[1].log_level, Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn interleaved_requests_are_grouped_by_id() {
+        let text = "\
+May  1 10:03:01 web[42]: level=info request_id=a1 GET /users
+May  1 10:03:01 web[42]: level=info request_id=b2 GET /orders
+May  1 10:03:02 web[42]: level=error request_id=b2 db timeout
+May  1 10:03:02 web[42]: level=info request_id=a1 200 OK
+";
+        let chunks = chunks(text, 4_000);
+        let addresses: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["request:a1", "request:b2"]);
+        assert_eq!(chunks[0].content.lines().count(), 2);
+        assert_eq!(chunks[1].log_level, Some(LogLevel::Error));
+        assert_eq!((chunks[1].start_line, chunks[1].end_line), (2, 3));
+    }
+
+    #[test]
+    fn text_without_timestamps_stays_plain_text() {
+        let options = IngestOptions::default();
+        let text = "build started\ncompiling\nfinished in 00:01:05\n";
+        assert!(parse_log_chunks("build.log", text, ChunkKind::Text, &options).is_none());
+    }
+}
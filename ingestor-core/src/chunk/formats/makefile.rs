@@ -0,0 +1,294 @@
+use super::{comment_summary, loose_line_drafts};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+fn just_recipe_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^@?([A-Za-z_][A-Za-z0-9_-]*)([^:]*):([^=].*)?$").expect("just recipe regex")
+    })
+}
+
+fn just_dependency_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"\(\s*([A-Za-z_][A-Za-z0-9_-]*)[^)]*\)|([A-Za-z_][A-Za-z0-9_-]*)|"[^"]*"|'[^']*'"#)
+            .expect("just dependency regex")
+    })
+}
+
+fn include_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"^(?:-?include|sinclude|import\??|mod\??\s+[A-Za-z_][A-Za-z0-9_-]*)\s+['"]?([^'"\s]+)"#)
+            .expect("makefile include regex")
+    })
+}
+
+/// Makefiles and justfiles: one chunk per rule or recipe, named by its first target,
+/// with its prerequisites (or just dependencies) recorded as calls. Assignments and
+/// other top-level lines between recipes are grouped, and included files become
+/// imports.
+pub(crate) struct MakefileAdapter;
+
+impl LanguageAdapter for MakefileAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("make".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(language)) if language == "make" || language == "just")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let just = request.language == Some(LanguageId::Other("just".to_string()));
+        let chunks = parse_recipe_chunks(request.path, request.text, request.kind, request.options, just)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// A rule or recipe header: its name and the targets it depends on.
+struct Recipe {
+    name: String,
+    dependencies: Vec<String>,
+}
+
+/// The `define` or conditional directive a makefile line opens, continues or closes.
+fn make_directive(line: &str) -> Option<&str> {
+    let mut words = line.split_whitespace().skip_while(|word| matches!(*word, "override" | "export"));
+    words
+        .next()
+        .filter(|word| matches!(*word, "define" | "endef" | "ifeq" | "ifneq" | "ifdef" | "ifndef" | "else" | "endif"))
+}
+
+fn make_rule(line: &str) -> Option<Recipe> {
+    let line = line.split('#').next().unwrap_or("");
+    let (targets, rest) = line.split_once(':')?;
+    let prerequisites = rest.strip_prefix(':').unwrap_or(rest);
+    // `VAR := value`, `VAR ::= value` and target-specific variables are assignments.
+    if targets.contains('=') || prerequisites.starts_with('=') || prerequisites.contains('=') {
+        return None;
+    }
+    let name = targets.split_whitespace().next()?;
+    // `.PHONY`, `.SUFFIXES` and other special targets configure make itself.
+    if name.starts_with('.') && name[1..].chars().all(|ch| ch.is_ascii_uppercase() || ch == '_') {
+        return None;
+    }
+    let prerequisites = prerequisites.split(';').next().unwrap_or("");
+    let dependencies = prerequisites
+        .split_whitespace()
+        .filter(|word| *word != "|" && !word.contains(['$', '%']))
+        .map(str::to_string)
+        .collect();
+    Some(Recipe {
+        name: name.to_string(),
+        dependencies,
+    })
+}
+
+fn just_recipe(line: &str) -> Option<Recipe> {
+    let caps = just_recipe_re().captures(line)?;
+    let name = caps[1].to_string();
+    if matches!(name.as_str(), "alias" | "set" | "export" | "import" | "mod") {
+        return None;
+    }
+    let dependencies = just_dependency_re()
+        .captures_iter(caps.get(3).map_or("", |rest| rest.as_str()))
+        .filter_map(|caps| caps.get(1).or(caps.get(2)).map(|name| name.as_str().to_string()))
+        .collect();
+    Some(Recipe { name, dependencies })
+}
+
+fn parse_recipe_chunks(
+    path: &str,
+    text: &str,
+    kind: ChunkKind,
+    options: &IngestOptions,
+    just: bool,
+) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let is_body = |line: &str| if just { line.starts_with([' ', '\t']) } else { line.starts_with('\t') };
+    // Comments and just attributes (`[private]`) directly above a recipe belong to it.
+    let is_preface = |line: &str| line.starts_with('#') || (just && line.starts_with('['));
+
+    // Recipes: first line (with its preface), header line, last line.
+    let mut recipes: Vec<(usize, usize, usize, Recipe)> = Vec::new();
+    // Open `define` blocks, whose lines are variable text rather than rules.
+    let mut defines = 0usize;
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = lines[idx];
+        let directive = if just { None } else { make_directive(line) };
+        match directive {
+            Some("define") => defines += 1,
+            Some("endef") => defines = defines.saturating_sub(1),
+            _ => {}
+        }
+        // Rules inside a conditional still count; the directive lines themselves do not.
+        let skipped = directive.is_some() || defines > 0;
+        let recipe = if skipped || line.trim().is_empty() || is_body(line) || is_preface(line) {
+            None
+        } else if just {
+            just_recipe(line)
+        } else {
+            make_rule(line)
+        };
+        let Some(recipe) = recipe else {
+            idx += 1;
+            continue;
+        };
+        let floor = recipes.last().map_or(0, |(_, _, end, _)| end + 1);
+        let mut start = idx;
+        while start > floor && is_preface(lines[start - 1]) {
+            start -= 1;
+        }
+        let mut end = idx;
+        let mut next = idx + 1;
+        while next < lines.len()
+            && (lines[next].trim().is_empty() || is_body(lines[next]) || lines[next - 1].ends_with('\\'))
+        {
+            if !lines[next].trim().is_empty() {
+                end = next;
+            }
+            next += 1;
+        }
+        recipes.push((start, idx, end, recipe));
+        idx = end + 1;
+    }
+    if recipes.is_empty() {
+        return None;
+    }
+
+    let include = |line: &str| include_re().captures(line).map(|caps| caps[1].to_string());
+    let mut drafts = Vec::new();
+    let mut region_start = 0;
+    for (start, header, end, recipe) in recipes {
+        loose_line_drafts(&lines, (region_start, start), kind, options, include, &mut drafts);
+        let doc_summary = comment_summary(lines[start..header].iter().copied().filter(|line| line.starts_with('#')));
+        drafts.push(ChunkDraft {
+            kind,
+            start_line: start + 1,
+            end_line: end + 1,
+            content: lines[start..=end].join("\n"),
+            heading_path: Vec::new(),
+            symbol: Some(recipe.name.clone()),
+            address: None,
+            ast_kind: Some(AstNodeKind::Function),
+            qualified_name: Some(recipe.name.clone()),
+            signature: Some(lines[header].trim().to_string()),
+            parent_symbol: None,
+            imports: Vec::new(),
+            exports: Vec::new(),
+            calls: recipe.dependencies,
+            method_calls: Vec::new(),
+            type_refs: Vec::new(),
+            doc_summary,
+            symbol_id: None,
+            symbol_tail: Some(recipe.name),
+            module_path: None,
+            visibility: None,
+            log_level: None,
+        });
+        region_start = end + 1;
+    }
+    loose_line_drafts(&lines, (region_start, lines.len()), kind, options, include, &mut drafts);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(path: &str, text: &str, just: bool) -> Vec<crate::Chunk> {
+        parse_recipe_chunks(path, text, ChunkKind::Unknown, &IngestOptions::default(), just).expect("recipes should chunk")
+    }
+
+    #[test]
+    fn make_rules_become_symbols_with_prerequisites_as_calls() {
+        let text = "\
+include config.mk
+CARGO ?= cargo
+.PHONY: build release
+
+build: fmt
+\t$(CARGO) build
+
+# Build the optimised binary for distribution.
+release: build test | dist
+\t$(CARGO) build --release \\
+\t  --locked
+";
+        let chunks = chunks("Makefile", text, false);
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref()).collect();
+        assert_eq!(symbols, [None, Some("build"), Some("release")]);
+        assert_eq!(chunks[0].imports, ["config.mk"]);
+
+        let release = &chunks[2];
+        assert_eq!(release.calls, ["build", "test", "dist"]);
+        assert_eq!((release.start_line, release.end_line), (8, 11));
+        assert_eq!(release.doc_summary.as_deref(), Some("Build the optimised binary for distribution."));
+    }
+
+    #[test]
+    fn define_blocks_and_conditional_directives_are_not_rules() {
+        let text = "\
+define HELP_TEXT
+usage: make [target]
+not: a target
+endef
+export HELP_TEXT
+
+ifeq ($(OS),Windows_NT)
+EXE := .exe
+install: build
+\tcopy app$(EXE) C:\\bin
+else
+install: build
+\tinstall -m 755 app /usr/local/bin
+endif
+
+help:
+\t@echo \"$$HELP_TEXT\"
+";
+        let chunks = chunks("Makefile", text, false);
+        let symbols: Vec<_> = chunks.iter().filter_map(|chunk| chunk.symbol.as_deref()).collect();
+        assert_eq!(symbols, ["install", "install", "help"]);
+        assert!(chunks[0].content.contains("not: a target"));
+        assert!(chunks.iter().all(|chunk| !chunk.calls.iter().any(|call| call == "a" || call == "target")));
+        let windows = &chunks[1];
+        assert_eq!((windows.start_line, windows.end_line), (9, 10));
+        assert_eq!(windows.calls, ["build"]);
+    }
+
+    #[test]
+    fn just_recipes_keep_attributes_and_dependencies() {
+        let text = "\
+set shell := [\"bash\", \"-c\"]
+version := `git describe --tags`
+
+# Publish a tagged release.
+[confirm]
+publish target=\"x86_64\": (build target) && notify
+    cargo publish --target {{target}}
+
+build target:
+    cargo build --release --target {{target}}
+";
+        let chunks = chunks("justfile", text, true);
+        let publish = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("publish")).expect("publish");
+        assert_eq!(publish.calls, ["build", "notify"]);
+        assert_eq!((publish.start_line, publish.end_line), (4, 7));
+        assert!(chunks.iter().any(|chunk| chunk.symbol.as_deref() == Some("build")));
+        assert!(chunks[0].content.starts_with("set shell"));
+    }
+}
@@ -0,0 +1,483 @@
+use super::yaml::{children, documents};
+use super::{partition, split_node, KeyNode};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Path item keys that are operations.
+const METHODS: &[&str] = &["get", "put", "post", "delete", "patch", "options", "head", "trace"];
+
+fn ref_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"["']?\$ref["']?\s*:\s*["']?([^"'\s,}]+)"#).expect("openapi ref regex"))
+}
+
+/// OpenAPI and Swagger specs in YAML or JSON: one chunk per path operation, named by
+/// its `operationId` (or `GET /users/{id}`), and one per schema under
+/// `components.schemas` or `definitions`, addressed by key path. The schemas an
+/// operation or schema `$ref`s are recorded as type references, and the files they
+/// point into as imports. Other top-level keys
+/// are chunked as in plain YAML.
+pub(crate) struct OpenApiAdapter;
+
+impl LanguageAdapter for OpenApiAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        None
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(request.language, Some(LanguageId::Yaml | LanguageId::Json))
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let json = request.language == Some(LanguageId::Json);
+        let chunks = parse_openapi_chunks(request.path, request.text, request.kind, request.options, json)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Tracks lines while walking a JSON document, to key its members by the lines
+/// they span the way the YAML adapter does.
+struct JsonScanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+}
+
+impl JsonScanner<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.bytes.get(self.pos).filter(|byte| byte.is_ascii_whitespace()) {
+            if *byte == b'\n' {
+                self.line += 1;
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// The raw text of the string starting at `pos`, which is left past its closing quote.
+    fn string(&mut self) -> String {
+        let start = self.pos + 1;
+        self.pos = start;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            self.pos += if byte == b'\\' { 2 } else { 1 };
+            if byte == b'"' {
+                break;
+            }
+        }
+        String::from_utf8_lossy(&self.bytes[start..self.pos.saturating_sub(1).max(start)]).into_owned()
+    }
+
+    /// Skip the value at `pos`, returning the members of an object or array under `parent`.
+    fn value(&mut self, parent: &KeyNode) -> Vec<KeyNode> {
+        self.skip_whitespace();
+        let Some(&open) = self.bytes.get(self.pos) else {
+            return Vec::new();
+        };
+        match open {
+            b'{' | b'[' => {
+                let close = if open == b'{' { b'}' } else { b']' };
+                self.pos += 1;
+                let mut nodes: Vec<KeyNode> = Vec::new();
+                let mut index = 0;
+                loop {
+                    self.skip_whitespace();
+                    match self.bytes.get(self.pos) {
+                        None => break,
+                        Some(byte) if *byte == close => {
+                            self.pos += 1;
+                            break;
+                        }
+                        Some(b',') => {
+                            self.pos += 1;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    let start = self.line;
+                    let (path, heading) = if open == b'{' {
+                        let key = self.string();
+                        self.skip_whitespace();
+                        self.pos += 1;
+                        parent.child_path(&key)
+                    } else {
+                        parent.item_path(index)
+                    };
+                    index += 1;
+                    let mut node = KeyNode {
+                        path,
+                        heading,
+                        start,
+                        end: start,
+                        children: Vec::new(),
+                    };
+                    node.children = self.value(&node);
+                    node.end = self.line;
+                    // Members sharing a line cannot be told apart by line; keep the first.
+                    match nodes.last_mut() {
+                        Some(previous) if previous.start == start => previous.end = node.end,
+                        _ => nodes.push(node),
+                    }
+                }
+                nodes
+            }
+            b'"' => {
+                self.string();
+                Vec::new()
+            }
+            _ => {
+                while self.bytes.get(self.pos).is_some_and(|byte| !b",}] \t\r\n".contains(byte)) {
+                    self.pos += 1;
+                }
+                Vec::new()
+            }
+        }
+    }
+}
+
+fn root() -> KeyNode {
+    KeyNode {
+        path: String::new(),
+        heading: Vec::new(),
+        start: 0,
+        end: 0,
+        children: Vec::new(),
+    }
+}
+
+/// The top-level members of a spec, or `None` for any other JSON or YAML file.
+fn spec_nodes(text: &str, lines: &[&str], json: bool) -> Option<Vec<KeyNode>> {
+    if json {
+        let value: serde_json::Value = serde_json::from_str(text).ok()?;
+        let object = value.as_object()?;
+        if !object.contains_key("openapi") && !object.contains_key("swagger") {
+            return None;
+        }
+        let mut scanner = JsonScanner {
+            bytes: text.as_bytes(),
+            pos: 0,
+            line: 0,
+        };
+        return Some(scanner.value(&root()));
+    }
+    let [(start, end)] = documents(lines)[..] else {
+        return None;
+    };
+    let nodes = children(lines, &root(), start, end);
+    let is_spec = nodes.iter().any(|node| node.path == "openapi" || node.path == "swagger");
+    is_spec.then_some(nodes)
+}
+
+fn key(node: &KeyNode) -> &str {
+    node.heading.last().map_or("", String::as_str)
+}
+
+/// The scalar value of `node`'s direct member `name`, from the first line of a
+/// block scalar if it has one.
+fn member_value(lines: &[&str], node: &KeyNode, name: &str) -> Option<String> {
+    let member = node.children.iter().find(|child| key(child) == name)?;
+    let line = (member.start..=node.end).find(|idx| lines[*idx].trim_start().trim_start_matches('"').starts_with(name))?;
+    let (_, value) = lines[line].split_once(':')?;
+    let value = value.trim().trim_end_matches(',').trim_matches(['"', '\'']).trim();
+    let value = if value.is_empty() || value.starts_with(['|', '>']) {
+        lines.get(line + 1)?.trim()
+    } else {
+        value
+    };
+    let sentence = value.split(". ").next().unwrap_or(value).trim();
+    (!sentence.is_empty()).then(|| sentence.chars().take(200).collect())
+}
+
+/// The definitions `$ref`ed in `start..=end`, by their last path segment, and the
+/// other files those refs point into (`common.yaml#/Error`, `./user.yaml`).
+fn refs(lines: &[&str], (start, end): (usize, usize), own: &str) -> (Vec<String>, Vec<String>) {
+    let mut type_refs: Vec<String> = Vec::new();
+    let mut files: Vec<String> = Vec::new();
+    for line in &lines[start..=end] {
+        for caps in ref_re().captures_iter(line) {
+            let (file, pointer) = caps[1].split_once('#').unwrap_or((&caps[1], ""));
+            if !file.is_empty() && !files.iter().any(|existing| existing == file) {
+                files.push(file.to_string());
+            }
+            let name = pointer.rsplit('/').next().unwrap_or(pointer);
+            if !name.is_empty() && name != own && !type_refs.iter().any(|existing| existing == name) {
+                type_refs.push(name.to_string());
+            }
+        }
+    }
+    (type_refs, files)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn definition_draft(
+    lines: &[&str],
+    (start, end): (usize, usize),
+    node: &KeyNode,
+    symbol: String,
+    qualified: String,
+    ast_kind: AstNodeKind,
+    doc_summary: Option<String>,
+    kind: ChunkKind,
+) -> ChunkDraft {
+    let signature = (ast_kind == AstNodeKind::Function).then(|| qualified.clone());
+    let (type_refs, imports) = refs(lines, (start, end), &symbol);
+    ChunkDraft {
+        kind,
+        start_line: start + 1,
+        end_line: end + 1,
+        content: lines[start..=end].join("\n").trim().to_string(),
+        heading_path: node.heading.clone(),
+        symbol: Some(symbol.clone()),
+        address: Some(node.path.clone()),
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified),
+        signature,
+        parent_symbol: None,
+        imports,
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary,
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: None,
+        visibility: None,
+        log_level: None,
+    }
+}
+
+/// One draft per operation of each path item under `paths`. Keys shared by a path
+/// item's operations (`parameters`, `summary`) stay with the operation beside them,
+/// and the item's summary describes operations that have none of their own.
+fn operation_drafts(lines: &[&str], paths: &mut KeyNode, kind: ChunkKind, options: &IngestOptions) -> Vec<ChunkDraft> {
+    let mut drafts = Vec::new();
+    partition(&mut paths.children, paths.start, paths.end);
+    for item in &mut paths.children {
+        partition(&mut item.children, item.start, item.end);
+        let operations: Vec<&KeyNode> = item.children.iter().filter(|child| METHODS.contains(&key(child))).collect();
+        if operations.is_empty() {
+            let key_line = item.start;
+            split_node(lines, item, key_line, &Some("paths".to_string()), kind, options, &mut drafts);
+            continue;
+        }
+        let route = key(item);
+        for (position, operation) in operations.iter().enumerate() {
+            let start = if position == 0 { item.start } else { operation.start };
+            let end = operations.get(position + 1).map_or(item.end, |next| next.start - 1);
+            let qualified = format!("{} {route}", key(operation).to_ascii_uppercase());
+            let symbol = member_value(lines, operation, "operationId").unwrap_or_else(|| qualified.clone());
+            let doc_summary = member_value(lines, operation, "summary")
+                .or_else(|| member_value(lines, operation, "description"))
+                .or_else(|| member_value(lines, item, "summary"));
+            drafts.push(definition_draft(
+                lines,
+                (start, end),
+                operation,
+                symbol,
+                qualified,
+                AstNodeKind::Function,
+                doc_summary,
+                kind,
+            ));
+        }
+    }
+    drafts
+}
+
+/// One draft per schema held by `schemas` (`components.schemas` or `definitions`).
+fn schema_drafts(lines: &[&str], schemas: &mut KeyNode, kind: ChunkKind) -> Vec<ChunkDraft> {
+    partition(&mut schemas.children, schemas.start, schemas.end);
+    schemas
+        .children
+        .iter()
+        .map(|schema| {
+            let name = key(schema).to_string();
+            let doc_summary = member_value(lines, schema, "description").or_else(|| member_value(lines, schema, "title"));
+            let span = (schema.start, schema.end);
+            definition_draft(lines, span, schema, name.clone(), name, AstNodeKind::Type, doc_summary, kind)
+        })
+        .collect()
+}
+
+fn parse_openapi_chunks(
+    path: &str,
+    text: &str,
+    kind: ChunkKind,
+    options: &IngestOptions,
+    json: bool,
+) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut nodes = spec_nodes(text, &lines, json)?;
+    if lines.is_empty() || nodes.is_empty() {
+        return None;
+    }
+    partition(&mut nodes, 0, lines.len() - 1);
+
+    let mut drafts = Vec::new();
+    for node in &mut nodes {
+        let top = Some(key(node).to_string());
+        match key(node) {
+            "paths" if !node.children.is_empty() => drafts.extend(operation_drafts(&lines, node, kind, options)),
+            "definitions" if !node.children.is_empty() => drafts.extend(schema_drafts(&lines, node, kind)),
+            "components" if !node.children.is_empty() => {
+                partition(&mut node.children, node.start, node.end);
+                for child in &mut node.children {
+                    if key(child) == "schemas" && !child.children.is_empty() {
+                        drafts.extend(schema_drafts(&lines, child, kind));
+                    } else {
+                        let key_line = child.start;
+                        split_node(&lines, child, key_line, &top, kind, options, &mut drafts);
+                    }
+                }
+            }
+            _ => {
+                let key_line = node.start;
+                split_node(&lines, node, key_line, &top, kind, options, &mut drafts);
+            }
+        }
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(path: &str, text: &str, json: bool) -> Vec<crate::Chunk> {
+        parse_openapi_chunks(path, text, ChunkKind::Unknown, &IngestOptions::default(), json)
+            .expect("spec should chunk")
+    }
+
+    #[test]
+    fn yaml_operations_and_schemas_become_symbols_with_refs() {
+        let text = "\
+openapi: 3.1.0
+info:
+  title: Users
+  version: 1.0.0
+paths:
+  /users/{id}:
+    parameters:
+      - $ref: '#/components/parameters/UserId'
+    get:
+      operationId: getUser
+      summary: Fetch one user. Admins see more.
+      responses:
+        '200':
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/User'
+    delete:
+      responses:
+        '204':
+          description: Deleted
+components:
+  schemas:
+    User:
+      description: A registered account.
+      properties:
+        address:
+          $ref: '#/components/schemas/Address'
+    Address:
+      type: object
+";
+        let chunks = chunks("api/openapi.yaml", text, false);
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref().unwrap_or("")).collect();
+        assert_eq!(symbols, ["openapi", "info", "getUser", "DELETE /users/{id}", "User", "Address"]);
+
+        let get = &chunks[2];
+        assert_eq!((get.start_line, get.end_line), (5, 17));
+        assert_eq!(get.qualified_name.as_deref(), Some("GET /users/{id}"));
+        assert_eq!(get.address.as_deref(), Some("paths./users/{id}.get"));
+        assert_eq!(get.type_refs, ["UserId", "User"]);
+        assert_eq!(get.doc_summary.as_deref(), Some("Fetch one user"));
+        assert_eq!(get.ast_kind, Some(AstNodeKind::Function));
+
+        let user = &chunks[4];
+        assert_eq!((user.start_line, user.end_line), (22, 28));
+        assert_eq!(user.address.as_deref(), Some("components.schemas.User"));
+        assert_eq!(user.type_refs, ["Address"]);
+        assert_eq!(user.doc_summary.as_deref(), Some("A registered account."));
+    }
+
+    #[test]
+    fn json_specs_are_keyed_by_line() {
+        let text = r##"{
+  "swagger": "2.0",
+  "paths": {
+    "/pets": {
+      "post": {
+        "operationId": "addPet",
+        "parameters": [{ "in": "body", "schema": { "$ref": "#/definitions/Pet" } }]
+      }
+    }
+  },
+  "definitions": {
+    "Pet": { "type": "object" }
+  }
+}
+"##;
+        let chunks = chunks("swagger.json", text, true);
+        let add_pet = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("addPet")).expect("operation");
+        assert_eq!((add_pet.start_line, add_pet.end_line), (3, 10));
+        assert_eq!(add_pet.type_refs, ["Pet"]);
+        let pet = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("Pet")).expect("schema");
+        assert_eq!(pet.address.as_deref(), Some("definitions.Pet"));
+        assert!(parse_openapi_chunks("package.json", "{\"name\": \"x\"}", ChunkKind::Json, &IngestOptions::default(), true)
+            .is_none());
+    }
+    #[test]
+    fn path_item_keys_and_external_refs() {
+        let text = "\
+openapi: 3.0.3
+paths:
+  \"/orders\":
+    summary: Orders placed by customers.
+    servers:
+      - url: https://orders.example.com
+    post:
+      operationId: createOrder
+      requestBody:
+        $ref: './requests.yaml#/CreateOrder'
+      responses:
+        '201':
+          $ref: '#/components/responses/Created'
+        default:
+          $ref: './errors.yaml'
+  /health: {}
+components:
+  responses:
+    Created:
+      description: Created.
+  schemas:
+    Order:
+      allOf:
+        - $ref: 'common.yaml#/components/schemas/Entity'
+";
+        let chunks = chunks("openapi.yaml", text, false);
+        let create = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("createOrder")).expect("operation");
+        assert_eq!(create.qualified_name.as_deref(), Some("POST /orders"));
+        assert_eq!((create.start_line, create.end_line), (2, 15));
+        assert_eq!(create.doc_summary.as_deref(), Some("Orders placed by customers."));
+        assert_eq!(create.type_refs, ["CreateOrder", "Created"]);
+        assert_eq!(create.imports, ["./requests.yaml", "./errors.yaml"]);
+
+        // A path item without operations is chunked as plain YAML.
+        let health = chunks.iter().find(|chunk| chunk.address.as_deref() == Some("paths./health")).expect("health");
+        assert_eq!(health.ast_kind, None);
+
+        let order = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("Order")).expect("schema");
+        assert_eq!(order.type_refs, ["Entity"]);
+        assert_eq!(order.imports, ["common.yaml"]);
+    }
+}
@@ -0,0 +1,317 @@
+use super::{closing_brace, comment_summary, loose_line_drafts};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Field types that name no message or enum.
+const SCALARS: &[&str] = &[
+    "double", "float", "int32", "int64", "uint32", "uint64", "sint32", "sint64", "fixed32", "fixed64", "sfixed32",
+    "sfixed64", "bool", "string", "bytes",
+];
+
+fn definition_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\s*(message|enum|service|extend)\s+\.?([A-Za-z_][A-Za-z0-9_.]*)\s*\{")
+            .expect("proto definition regex")
+    })
+}
+
+fn rpc_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^\s*rpc\s+([A-Za-z_]\w*)\s*\(\s*(?:stream\s+)?\.?([A-Za-z_][\w.]*)\s*\)\s*returns\s*\(\s*(?:stream\s+)?\.?([A-Za-z_][\w.]*)\s*\)",
+        )
+        .expect("proto rpc regex")
+    })
+}
+
+fn field_type_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?:^|[\s;{])(?:(?:repeated|optional|required)\s+)?\.?([A-Za-z_][\w.]*)[ \t]+[A-Za-z_]\w*\s*=\s*\d+")
+            .expect("proto field regex")
+    })
+}
+
+fn map_type_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"map\s*<\s*\.?([\w.]+)\s*,\s*\.?([\w.]+)\s*>").expect("proto map regex"))
+}
+
+fn import_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"^\s*import\s+(?:(?:public|weak)\s+)?"([^"]+)""#).expect("proto import regex"))
+}
+
+/// Protocol Buffers: one chunk per message and enum, and one per rpc of a
+/// service (`UserService.GetUser`), with the message types of fields and rpc
+/// requests and responses recorded as type references.
+pub(crate) struct ProtoAdapter;
+
+impl LanguageAdapter for ProtoAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("proto".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == self.language_id()
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_proto_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+fn is_comment(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("//") || line.starts_with("/*") || line.starts_with('*')
+}
+
+/// Pull `start` up over the comment lines directly above it, but not past `floor`.
+fn with_comments(lines: &[&str], start: usize, floor: usize) -> usize {
+    let mut first = start;
+    while first > floor && is_comment(lines[first - 1]) {
+        first -= 1;
+    }
+    first
+}
+
+fn push_type(type_refs: &mut Vec<String>, name: &str, own: &str) {
+    if name != own && !SCALARS.contains(&name) && !type_refs.iter().any(|existing| existing == name) {
+        type_refs.push(name.to_string());
+    }
+}
+
+/// A draft for a declaration spanning `start..=end`, documented by the comments
+/// in `start..header`.
+#[allow(clippy::too_many_arguments)]
+fn declaration(
+    lines: &[&str],
+    (start, header, end): (usize, usize, usize),
+    kind: ChunkKind,
+    ast_kind: AstNodeKind,
+    name: &str,
+    parent: Option<&str>,
+    type_refs: Vec<String>,
+) -> ChunkDraft {
+    let qualified = parent.map_or_else(|| name.to_string(), |parent| format!("{parent}.{name}"));
+    // A one-line declaration (`enum Role { ROLE_UNSPECIFIED = 0; }`) keeps its body out.
+    let signature = lines[header].split('{').next().unwrap_or("").trim().trim_end_matches(';').trim_end();
+    ChunkDraft {
+        kind,
+        start_line: start + 1,
+        end_line: end + 1,
+        content: lines[start..=end].join("\n").trim().to_string(),
+        heading_path: Vec::new(),
+        symbol: Some(name.to_string()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified),
+        signature: Some(signature.to_string()),
+        parent_symbol: parent.map(str::to_string),
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary: comment_summary(lines[start..header].iter().copied().filter(|line| is_comment(line))),
+        symbol_id: None,
+        symbol_tail: Some(name.to_string()),
+        module_path: None,
+        visibility: None,
+        log_level: None,
+    }
+}
+
+/// One draft per rpc of the service in `start..=end`. The first rpc takes the
+/// service header and the last its closing brace; a service without rpcs stays whole.
+fn rpc_drafts(
+    lines: &[&str],
+    (start, header, end): (usize, usize, usize),
+    service: &str,
+    kind: ChunkKind,
+) -> Vec<ChunkDraft> {
+    let rpcs: Vec<(usize, regex::Captures<'_>)> =
+        (header + 1..end).filter_map(|idx| rpc_re().captures(lines[idx]).map(|caps| (idx, caps))).collect();
+    if rpcs.is_empty() {
+        return vec![declaration(lines, (start, header, end), kind, AstNodeKind::Interface, service, None, Vec::new())];
+    }
+    let starts: Vec<usize> = rpcs
+        .iter()
+        .enumerate()
+        .map(|(position, (idx, _))| match position {
+            0 => start,
+            _ => with_comments(lines, *idx, rpcs[position - 1].0 + 1),
+        })
+        .collect();
+    rpcs.iter()
+        .enumerate()
+        .map(|(position, (idx, caps))| {
+            let rpc_end = starts.get(position + 1).map_or(end, |next| next - 1);
+            let rpc_end = (*idx..=rpc_end).rfind(|line| !lines[*line].trim().is_empty()).unwrap_or(*idx);
+            let mut type_refs = Vec::new();
+            push_type(&mut type_refs, &caps[2], "");
+            push_type(&mut type_refs, &caps[3], "");
+            let doc_start = with_comments(lines, *idx, starts[position]);
+            let span = (doc_start, *idx, rpc_end);
+            let mut draft = declaration(lines, span, kind, AstNodeKind::Method, &caps[1], Some(service), type_refs);
+            draft.start_line = starts[position] + 1;
+            draft.content = lines[starts[position]..=rpc_end].join("\n").trim().to_string();
+            draft
+        })
+        .collect()
+}
+
+fn parse_proto_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let line_at = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+    let import = |line: &str| import_re().captures(line).map(|caps| caps[1].to_string());
+
+    let mut drafts = Vec::new();
+    let mut region_start = 0;
+    let mut idx = 0;
+    while idx < lines.len() {
+        let Some(caps) = definition_re().captures(lines[idx]) else {
+            idx += 1;
+            continue;
+        };
+        let open = line_starts[idx] + caps.get(0).expect("whole match").end() - 1;
+        let Some(close) = closing_brace(text, open, &["//"]) else {
+            break;
+        };
+        let end = line_at(close);
+        let start = with_comments(&lines, idx, region_start);
+        loose_line_drafts(&lines, (region_start, start), kind, options, import, &mut drafts);
+
+        let name = &caps[2];
+        let body = &text[open..close];
+        match &caps[1] {
+            "service" => drafts.extend(rpc_drafts(&lines, (start, idx, end), name, kind)),
+            keyword => {
+                let mut type_refs = Vec::new();
+                for field in field_type_re().captures_iter(body) {
+                    push_type(&mut type_refs, &field[1], name);
+                }
+                for map in map_type_re().captures_iter(body) {
+                    push_type(&mut type_refs, &map[1], name);
+                    push_type(&mut type_refs, &map[2], name);
+                }
+                let span = (start, idx, end);
+                let draft = match keyword {
+                    "message" => declaration(&lines, span, kind, AstNodeKind::Class, name, None, type_refs),
+                    "enum" => declaration(&lines, span, kind, AstNodeKind::Enum, name, None, type_refs),
+                    // `extend Foo { ... }` adds fields to another message.
+                    _ => {
+                        type_refs.insert(0, name.to_string());
+                        let content = lines[start..=end].join("\n").trim().to_string();
+                        ChunkDraft {
+                            type_refs,
+                            ..ChunkDraft::plain(kind, start + 1, end + 1, content, Vec::new(), None, None)
+                        }
+                    }
+                };
+                drafts.push(draft);
+            }
+        }
+        region_start = end + 1;
+        idx = end + 1;
+    }
+    if !drafts.iter().any(|draft| draft.symbol.is_some()) {
+        return None;
+    }
+    loose_line_drafts(&lines, (region_start, lines.len()), kind, options, import, &mut drafts);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_and_rpcs_become_symbols_with_type_refs() {
+        let text = r#"syntax = "proto3";
+package users.v1;
+import "google/protobuf/timestamp.proto";
+
+// A registered account.
+message User {
+  string id = 1;
+  google.protobuf.Timestamp created_at = 2;
+  repeated Role roles = 3;
+  map<string, Profile> profiles = 4;
+}
+
+enum Role {
+  ROLE_UNSPECIFIED = 0;
+  ROLE_ADMIN = 1;
+}
+
+service UserService {
+  // Fetch one user by ID.
+  rpc GetUser(GetUserRequest) returns (User);
+
+  rpc WatchUsers(WatchRequest) returns (stream User) {
+    option idempotency_level = NO_SIDE_EFFECTS;
+  }
+}
+"#;
+        let chunks = parse_proto_chunks("users.proto", text, ChunkKind::Unknown, &IngestOptions::default())
+            .expect("proto should chunk");
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref().unwrap_or("")).collect();
+        assert_eq!(symbols, ["", "User", "Role", "GetUser", "WatchUsers"]);
+        assert_eq!(chunks[0].imports, ["google/protobuf/timestamp.proto"]);
+
+        let user = &chunks[1];
+        assert_eq!((user.start_line, user.end_line), (5, 11));
+        assert_eq!(user.type_refs, ["google.protobuf.Timestamp", "Role", "Profile"]);
+        assert_eq!(user.doc_summary.as_deref(), Some("A registered account."));
+
+        let get_user = &chunks[3];
+        assert_eq!(get_user.qualified_name.as_deref(), Some("UserService.GetUser"));
+        assert_eq!(get_user.parent_symbol.as_deref(), Some("UserService"));
+        assert_eq!(get_user.type_refs, ["GetUserRequest", "User"]);
+        assert_eq!(get_user.doc_summary.as_deref(), Some("Fetch one user by ID."));
+        assert_eq!((get_user.start_line, get_user.end_line), (18, 20));
+
+        let watch = &chunks[4];
+        assert_eq!((watch.start_line, watch.end_line), (22, 25));
+        assert_eq!(watch.signature.as_deref(), Some("rpc WatchUsers(WatchRequest) returns (stream User)"));
+    }
+
+    #[test]
+    fn one_line_declarations_keep_fields_and_short_signatures() {
+        let text = r#"message Contact { oneof channel { string email = 4; Phone phone = 5; } }
+enum Role { ROLE_UNSPECIFIED = 0; }
+service Health {
+  rpc Ping(Empty) returns (Empty) { option deprecated = true; }
+}
+"#;
+        let chunks = parse_proto_chunks("contact.proto", text, ChunkKind::Unknown, &IngestOptions::default())
+            .expect("proto should chunk");
+        let contact = &chunks[0];
+        assert_eq!(contact.type_refs, ["Phone"]);
+        assert_eq!(contact.signature.as_deref(), Some("message Contact"));
+        assert_eq!(chunks[1].signature.as_deref(), Some("enum Role"));
+        assert!(chunks[1].type_refs.is_empty());
+
+        let ping = &chunks[2];
+        assert_eq!(ping.qualified_name.as_deref(), Some("Health.Ping"));
+        assert_eq!(ping.signature.as_deref(), Some("rpc Ping(Empty) returns (Empty)"));
+        assert_eq!(ping.type_refs, ["Empty"]);
+    }
+}
@@ -0,0 +1,421 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::OnceLock;
+
+/// Shell scripts (`.sh`, `.bash`, `.zsh`): one chunk per function definition, with
+/// the top-level script between them kept in chunks of its own.
+pub(crate) struct ShellAdapter;
+
+impl LanguageAdapter for ShellAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Shell)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        // Functions are found lexically; sourced files and calls are bound in `crate::resolve`.
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Shell)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_shell_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// `name() {`, `function name {` and `function name() {`; the body may open on the next line.
+fn function_header_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\s*(?:function\s+([A-Za-z_][\w.:-]*)\s*(?:\(\s*\))?|([A-Za-z_][\w.:-]*)\s*\(\s*\))\s*(.*)$")
+            .expect("shell function regex")
+    })
+}
+
+/// `source path` and `. path` in command position.
+fn source_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"(?:^|[;&|(]|\{\s|\b(?:then|do|else))\s*(?:source|\.)\s+((?:"[^"]*"|'[^']*'|[^\s;&|)"'])+)"#)
+            .expect("shell source regex")
+    })
+}
+
+/// The first word of each simple command, after any `if`/`then`/`!`-style keywords.
+fn command_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?:^|[;&|(`]|\$\(|\{\s)\s*(?:(?:then|do|else|elif|if|while|until|exec|command|time|!)\s+)*([A-Za-z_][\w.:-]*)",
+        )
+        .expect("shell command regex")
+    })
+}
+
+/// A source line with comments and here-document bodies removed, single-quoted
+/// text blanked, and the braces that sit outside of quotes counted.
+struct ScannedLine {
+    code: String,
+    opens: usize,
+    closes: usize,
+}
+
+fn scan_lines(text: &str) -> Vec<ScannedLine> {
+    let mut scanned = Vec::new();
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut heredocs: VecDeque<(String, bool)> = VecDeque::new();
+    for line in text.lines() {
+        if let Some((terminator, indented)) = heredocs.front() {
+            let candidate = if *indented { line.trim_start_matches('\t') } else { line };
+            if candidate.trim_end() == terminator {
+                heredocs.pop_front();
+            }
+            scanned.push(ScannedLine {
+                code: String::new(),
+                opens: 0,
+                closes: 0,
+            });
+            continue;
+        }
+
+        let chars: Vec<char> = line.chars().collect();
+        let mut code = String::with_capacity(line.len());
+        let (mut opens, mut closes) = (0, 0);
+        let mut idx = 0;
+        while idx < chars.len() {
+            let ch = chars[idx];
+            idx += 1;
+            if in_single {
+                if ch == '\'' {
+                    in_single = false;
+                    code.push(ch);
+                } else {
+                    code.push(' ');
+                }
+                continue;
+            }
+            if ch == '\\' {
+                code.push(ch);
+                code.extend(chars.get(idx));
+                idx += 1;
+                continue;
+            }
+            if in_double {
+                in_double = ch != '"';
+                code.push(ch);
+                continue;
+            }
+            match ch {
+                '\'' => in_single = true,
+                '"' => in_double = true,
+                '#' if idx == 1 || chars[idx - 2].is_whitespace() || matches!(chars[idx - 2], ';' | '&' | '|' | '(') => {
+                    break
+                }
+                '{' => opens += 1,
+                '}' => closes += 1,
+                '<' if chars.get(idx) == Some(&'<') && chars.get(idx + 1) != Some(&'<') => {
+                    // `<<EOF`, `<<-EOF`, `<<'EOF'`: the body starts on the next line.
+                    let mut end = idx + 1;
+                    let indented = chars.get(end) == Some(&'-');
+                    if indented {
+                        end += 1;
+                    }
+                    while chars.get(end).is_some_and(|ch| *ch == ' ' || *ch == '\t') {
+                        end += 1;
+                    }
+                    let mut terminator = String::new();
+                    while let Some(&next) = chars.get(end) {
+                        if next.is_whitespace() || matches!(next, ';' | '&' | '|' | '<' | '>' | ')') {
+                            break;
+                        }
+                        if !matches!(next, '\'' | '"' | '\\') {
+                            terminator.push(next);
+                        }
+                        end += 1;
+                    }
+                    code.extend(&chars[idx - 1..end]);
+                    idx = end;
+                    if !terminator.is_empty() {
+                        heredocs.push_back((terminator, indented));
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            code.push(ch);
+        }
+        scanned.push(ScannedLine { code, opens, closes });
+    }
+    scanned
+}
+
+/// A function definition starting at `line`: its name and the line its body opens on.
+fn function_at(scanned: &[ScannedLine], line: usize) -> Option<(String, usize)> {
+    let captures = function_header_re().captures(&scanned[line].code)?;
+    let name = captures.get(1).or_else(|| captures.get(2))?.as_str().to_string();
+    let rest = captures.get(3).map_or("", |rest| rest.as_str()).trim();
+    if rest.starts_with('{') {
+        return Some((name, line));
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    let body = (line + 1..scanned.len()).find(|idx| !scanned[*idx].code.trim().is_empty())?;
+    scanned[body].code.trim_start().starts_with('{').then_some((name, body))
+}
+
+/// The line holding the brace that closes the body opened on `open`.
+fn function_end(scanned: &[ScannedLine], open: usize) -> usize {
+    let mut depth = 0usize;
+    for (idx, line) in scanned.iter().enumerate().skip(open) {
+        depth += line.opens;
+        depth = depth.saturating_sub(line.closes);
+        if depth == 0 {
+            return idx;
+        }
+    }
+    scanned.len() - 1
+}
+
+/// Comment lines written directly above a definition.
+fn leading_comments(lines: &[&str], start: usize) -> usize {
+    let mut first = start;
+    while first > 0 {
+        let line = lines[first - 1].trim_start();
+        if !line.starts_with('#') || line.starts_with("#!") {
+            break;
+        }
+        first -= 1;
+    }
+    first
+}
+
+/// The first sentence of a comment block, capped at 200 chars.
+fn summarize_comments(lines: &[&str]) -> Option<String> {
+    let joined = lines
+        .iter()
+        .map(|line| line.trim().trim_start_matches('#').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let sentence = joined.split(". ").next().unwrap_or(&joined).trim();
+    if sentence.is_empty() {
+        None
+    } else {
+        Some(sentence.chars().take(200).collect())
+    }
+}
+
+/// `source`d paths, unquoted, and calls to the functions `defined` in the file.
+fn references<'s>(
+    code: impl Iterator<Item = &'s str>,
+    defined: &BTreeSet<String>,
+) -> (Vec<String>, Vec<String>) {
+    let (mut imports, mut calls) = (Vec::new(), Vec::new());
+    for line in code {
+        for captures in source_re().captures_iter(line) {
+            let path: String = captures[1].chars().filter(|ch| *ch != '"' && *ch != '\'').collect();
+            if !path.is_empty() && !imports.contains(&path) {
+                imports.push(path);
+            }
+        }
+        for captures in command_re().captures_iter(line) {
+            let name = &captures[1];
+            if defined.contains(name) && !calls.iter().any(|call| call == name) {
+                calls.push(name.to_string());
+            }
+        }
+    }
+    (imports, calls)
+}
+
+struct ScriptContext<'a> {
+    lines: Vec<&'a str>,
+    scanned: Vec<ScannedLine>,
+    defined: BTreeSet<String>,
+    kind: ChunkKind,
+}
+
+impl ScriptContext<'_> {
+    fn draft(&self, first: usize, last: usize) -> ChunkDraft {
+        let (imports, calls) = references(self.scanned[first..=last].iter().map(|line| line.code.as_str()), &self.defined);
+        ChunkDraft {
+            imports,
+            calls,
+            method_calls: Vec::new(),
+            ..ChunkDraft::plain(
+                self.kind,
+                first + 1,
+                last + 1,
+                self.lines[first..=last].join("\n").trim().to_string(),
+                Vec::new(),
+                None,
+                None,
+            )
+        }
+    }
+}
+
+fn parse_shell_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let scanned = scan_lines(text);
+    let defined = (0..scanned.len())
+        .filter_map(|line| function_at(&scanned, line).map(|(name, _)| name))
+        .collect();
+    let context = ScriptContext {
+        lines,
+        scanned,
+        defined,
+        kind,
+    };
+
+    let mut drafts = Vec::new();
+    // Top-level lines not yet emitted, as `first..line`.
+    let mut first = 0;
+    let mut line = 0;
+    let flush = |drafts: &mut Vec<ChunkDraft>, first: usize, end: usize| {
+        if (first..end).any(|idx| !context.lines[idx].trim().is_empty()) {
+            drafts.push(context.draft(first, end - 1));
+        }
+    };
+    while line < context.lines.len() {
+        if let Some((name, open)) = function_at(&context.scanned, line) {
+            let start = leading_comments(&context.lines, line).max(first);
+            flush(&mut drafts, first, start);
+            let end = function_end(&context.scanned, open);
+
+            // The header line's own name is not a call; the rest of it may hold the body.
+            let header = &context.scanned[line].code;
+            let body_code = header.find('{').map_or("", |idx| &header[idx..]);
+            let (imports, mut calls) = references(
+                std::iter::once(body_code).chain(context.scanned[line + 1..=end].iter().map(|line| line.code.as_str())),
+                &context.defined,
+            );
+            calls.retain(|call| *call != name);
+            let signature = header.split('{').next().unwrap_or(header).trim().to_string();
+
+            drafts.push(ChunkDraft {
+                kind,
+                start_line: start + 1,
+                end_line: end + 1,
+                content: context.lines[start..=end].join("\n").trim_end().to_string(),
+                heading_path: Vec::new(),
+                symbol: Some(name.clone()),
+                address: None,
+                ast_kind: Some(AstNodeKind::Function),
+                qualified_name: Some(name.clone()),
+                signature: Some(signature),
+                parent_symbol: None,
+                imports,
+                exports: Vec::new(),
+                calls,
+                method_calls: Vec::new(),
+                type_refs: Vec::new(),
+                doc_summary: summarize_comments(&context.lines[start..line]),
+                symbol_id: None,
+                symbol_tail: Some(name),
+                module_path: None,
+                visibility: None,
+                log_level: None,
+            });
+            line = end + 1;
+            first = line;
+            continue;
+        }
+
+        let pending: usize = context.lines[first..=line].iter().map(|line| line.len() + 1).sum();
+        if context.lines[line].trim().is_empty() && pending >= options.chunk_target_chars {
+            flush(&mut drafts, first, line + 1);
+            first = line + 1;
+        }
+        line += 1;
+    }
+    flush(&mut drafts, first, context.lines.len());
+
+    let structured = drafts
+        .iter()
+        .any(|draft| draft.ast_kind.is_some() || !draft.imports.is_empty());
+    if !structured {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str) -> Vec<crate::Chunk> {
+        parse_shell_chunks("scripts/deploy.sh", text, ChunkKind::Unknown, &IngestOptions::default())
+            .expect("script should chunk")
+    }
+
+    #[test]
+    fn functions_become_symbols_with_calls_and_sourced_files() {
+        let script = r#"#!/usr/bin/env bash
+set -euo pipefail
+source "$(dirname "$0")/lib/common.sh"
+
+# Print usage. Exits non-zero.
+usage() {
+  echo "usage: deploy <env>"
+  exit 1
+}
+
+function build {
+  cat <<EOF
+usage() {
+EOF
+  log "building"
+}
+
+function release() {
+  [ -n "${1:-}" ] || usage
+  if build; then . ./notify.sh; fi
+  echo 'build }'
+}
+
+main() { release "$@"; }
+
+main "$@"
+"#;
+        let chunks = chunks(script);
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref()).collect();
+        assert_eq!(symbols, [None, Some("usage"), Some("build"), Some("release"), Some("main"), None]);
+
+        let usage = &chunks[1];
+        assert_eq!((usage.start_line, usage.end_line), (5, 9));
+        assert_eq!(usage.doc_summary.as_deref(), Some("Print usage"));
+        assert_eq!(usage.signature.as_deref(), Some("usage()"));
+
+        let build = &chunks[2];
+        assert_eq!((build.start_line, build.end_line), (11, 16));
+        assert!(build.calls.is_empty(), "{:?}", build.calls);
+
+        let release = &chunks[3];
+        assert_eq!(release.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(release.calls, ["usage", "build"]);
+        assert_eq!(release.imports, ["./notify.sh"]);
+        assert_eq!(release.end_line, 22);
+
+        assert_eq!(chunks[4].calls, ["release"]);
+        assert_eq!(chunks[0].imports, ["$(dirname $0)/lib/common.sh"]);
+        assert_eq!(chunks[5].calls, ["main"]);
+    }
+
+    #[test]
+    fn scripts_without_functions_or_sources_stay_plain_text() {
+        let text = "#!/bin/sh\necho hello\n";
+        assert!(parse_shell_chunks("hello.sh", text, ChunkKind::Unknown, &IngestOptions::default()).is_none());
+    }
+}
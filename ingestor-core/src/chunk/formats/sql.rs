@@ -0,0 +1,567 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+
+/// SQL scripts and migrations: one chunk per `CREATE` statement, and runs of other
+/// statements grouped up to the target chunk size. Tables a statement touches are
+/// recorded as type references.
+pub(crate) struct SqlAdapter;
+
+impl LanguageAdapter for SqlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Sql)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        // Statements are found lexically; table references are matched by name in `crate::graph`.
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Sql)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_sql_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Words that end a table list or can never be a table name or alias.
+const RESERVED: &[&str] = &[
+    "ALL", "AND", "AS", "BY", "CROSS", "DEFAULT", "DO", "EXCEPT", "EXISTS", "FETCH", "FOR", "FROM", "FULL",
+    "GROUP", "HAVING", "IF", "IN", "INNER", "INTERSECT", "INTO", "JOIN", "LATERAL", "LEFT", "LIMIT", "NATURAL",
+    "NOT", "OFFSET", "ON", "ONLY", "OR", "ORDER", "OUTER", "RETURNING", "RIGHT", "SELECT", "SET", "TABLE",
+    "UNION", "USING", "VALUES", "WHERE", "WINDOW", "WITH",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A bare word, kept as written.
+    Word(String),
+    /// A `"quoted"`, `` `quoted` `` or `[quoted]` identifier, unquoted.
+    Quoted(String),
+    Punct(char),
+}
+
+impl Token {
+    fn is_word(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Identifier text, unless it is a reserved word.
+    fn ident(&self) -> Option<&str> {
+        match self {
+            Token::Word(word) if !RESERVED.iter().any(|reserved| word.eq_ignore_ascii_case(reserved)) => Some(word),
+            Token::Quoted(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// The end of the `$tag$` opening a dollar-quoted string at `start`, and its tag.
+fn dollar_tag(text: &str, start: usize) -> Option<(usize, &str)> {
+    let rest = &text[start + 1..];
+    let len = rest.find('$')?;
+    let tag = &rest[..len];
+    let valid = tag
+        .chars()
+        .enumerate()
+        .all(|(idx, ch)| ch == '_' || ch.is_ascii_alphabetic() || (idx > 0 && ch.is_ascii_digit()));
+    valid.then_some((start + len + 2, tag))
+}
+
+/// Byte ranges of the statements in `text`, each running from its first
+/// non-blank byte through its terminator. Handles `--` and `/* */` comments,
+/// quoted strings and identifiers, `$$` bodies, `BEGIN ... END` routine bodies,
+/// `GO` batch separators and MySQL `DELIMITER` changes.
+fn split_statements(text: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut statements = Vec::new();
+    let mut delimiter = ";".to_string();
+    let mut start: Option<usize> = None;
+    // Words of the current statement, upper-cased, until its kind is known.
+    let mut head: Vec<String> = Vec::new();
+    let mut block_depth = 0usize;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let at_line_start = idx == 0 || bytes[idx - 1] == b'\n';
+        if at_line_start {
+            let line_end = text[idx..].find('\n').map_or(text.len(), |len| idx + len);
+            let line = text[idx..line_end].trim();
+            if start.is_none() {
+                if let Some(new) = line
+                    .get(.."DELIMITER ".len())
+                    .filter(|prefix| prefix.eq_ignore_ascii_case("DELIMITER "))
+                    .map(|_| line["DELIMITER ".len()..].trim())
+                    .filter(|new| !new.is_empty())
+                {
+                    delimiter = new.to_string();
+                    idx = line_end;
+                    continue;
+                }
+            }
+            if line.eq_ignore_ascii_case("GO") || (line == "/" && block_depth > 0) {
+                if let Some(begin) = start.take() {
+                    statements.push((begin, idx));
+                }
+                head.clear();
+                block_depth = 0;
+                idx = line_end;
+                continue;
+            }
+        }
+
+        let ch = bytes[idx];
+        if ch.is_ascii_whitespace() {
+            idx += 1;
+            continue;
+        }
+        let begin = *start.get_or_insert(idx);
+        if text[idx..].starts_with(delimiter.as_str()) && block_depth == 0 {
+            idx += delimiter.len();
+            statements.push((begin, idx));
+            start = None;
+            head.clear();
+            continue;
+        }
+        idx = match ch {
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => text[idx..].find('\n').map_or(text.len(), |len| idx + len),
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => text[idx + 2..].find("*/").map_or(text.len(), |len| idx + len + 4),
+            b'\'' | b'"' | b'`' => text[idx + 1..].find(ch as char).map_or(text.len(), |len| idx + len + 2),
+            b'$' => match dollar_tag(text, idx) {
+                Some((body, tag)) => {
+                    let close = format!("${tag}$");
+                    text[body..].find(&close).map_or(text.len(), |len| body + len + close.len())
+                }
+                None => idx + 1,
+            },
+            _ if ch.is_ascii_alphabetic() || ch == b'_' => {
+                let len = text[idx..]
+                    .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '$'))
+                    .unwrap_or(text.len() - idx);
+                let word = text[idx..idx + len].to_ascii_uppercase();
+                if is_routine(&head) {
+                    let next = text[idx + len..].trim_start();
+                    let closes_statement = !["IF", "LOOP", "WHILE", "REPEAT", "FOR"]
+                        .iter()
+                        .any(|keyword| next.get(..keyword.len()).is_some_and(|word| word.eq_ignore_ascii_case(keyword)));
+                    match word.as_str() {
+                        "BEGIN" | "CASE" => block_depth += 1,
+                        "END" if closes_statement => block_depth = block_depth.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+                if head.len() < 6 {
+                    head.push(word);
+                }
+                idx + len
+            }
+            _ => idx + text[idx..].chars().next().map_or(1, char::len_utf8),
+        };
+    }
+    if let Some(begin) = start {
+        if !text[begin..].trim().is_empty() {
+            statements.push((begin, text.len()));
+        }
+    }
+    statements
+}
+
+/// Whether the statement so far creates a routine whose body may hold `;`.
+fn is_routine(head: &[String]) -> bool {
+    head.first().is_some_and(|word| word == "CREATE")
+        && head
+            .iter()
+            .any(|word| matches!(word.as_str(), "FUNCTION" | "PROCEDURE" | "PROC" | "TRIGGER" | "PACKAGE"))
+}
+
+/// Tokens of one statement, skipping comments and string literals. The bodies of
+/// `$$`-quoted routines are tokenized in line, so their queries count too.
+fn tokenize(text: &str) -> Vec<Token> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let ch = bytes[idx];
+        match ch {
+            _ if ch.is_ascii_whitespace() => idx += 1,
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = text[idx..].find('\n').map_or(text.len(), |len| idx + len);
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = text[idx + 2..].find("*/").map_or(text.len(), |len| idx + len + 4);
+            }
+            b'\'' => idx = text[idx + 1..].find('\'').map_or(text.len(), |len| idx + len + 2),
+            b'"' | b'`' => {
+                let end = text[idx + 1..].find(ch as char).map_or(text.len(), |len| idx + 1 + len);
+                tokens.push(Token::Quoted(text[idx + 1..end].to_string()));
+                idx = (end + 1).min(text.len());
+            }
+            b'[' if bytes.get(idx + 1).is_some_and(|next| next.is_ascii_alphabetic() || *next == b'_') => {
+                let end = text[idx + 1..].find(']').map_or(text.len(), |len| idx + 1 + len);
+                tokens.push(Token::Quoted(text[idx + 1..end].to_string()));
+                idx = (end + 1).min(text.len());
+            }
+            b'$' => match dollar_tag(text, idx) {
+                Some((body, tag)) => {
+                    let close = format!("${tag}$");
+                    let end = text[body..].find(&close).map_or(text.len(), |len| body + len);
+                    tokens.extend(tokenize(&text[body..end]));
+                    idx = (end + close.len()).min(text.len());
+                }
+                None => idx += 1,
+            },
+            _ if ch.is_ascii_alphabetic() || ch == b'_' => {
+                let len = text[idx..]
+                    .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '$'))
+                    .unwrap_or(text.len() - idx);
+                tokens.push(Token::Word(text[idx..idx + len].to_string()));
+                idx += len;
+            }
+            _ if ch.is_ascii() => {
+                tokens.push(Token::Punct(ch as char));
+                idx += 1;
+            }
+            _ => idx += text[idx..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    tokens
+}
+
+/// A possibly schema-qualified name starting at `idx`, and the index after it.
+fn dotted_name(tokens: &[Token], idx: usize) -> Option<(String, usize)> {
+    let mut name = tokens.get(idx)?.ident()?.to_string();
+    let mut next = idx + 1;
+    while tokens.get(next) == Some(&Token::Punct('.')) {
+        let Some(part) = tokens.get(next + 1).and_then(|token| match token {
+            Token::Word(word) => Some(word.as_str()),
+            Token::Quoted(name) => Some(name.as_str()),
+            Token::Punct(_) => None,
+        }) else {
+            break;
+        };
+        name.push('.');
+        name.push_str(part);
+        next += 2;
+    }
+    Some((name, next))
+}
+
+/// What a `CREATE` statement declares.
+struct Definition {
+    kind: AstNodeKind,
+    object: &'static str,
+    name: String,
+}
+
+fn definition(tokens: &[Token]) -> Option<Definition> {
+    if !tokens.first()?.is_word("CREATE") {
+        return None;
+    }
+    let mut idx = 1;
+    while let Some(token) = tokens.get(idx) {
+        let object = ["TABLE", "VIEW", "FUNCTION", "PROCEDURE", "PROC", "INDEX", "TRIGGER"]
+            .into_iter()
+            .find(|object| token.is_word(object));
+        if let Some(object) = object {
+            idx += 1;
+            for skipped in ["CONCURRENTLY", "IF", "NOT", "EXISTS"] {
+                if tokens.get(idx).is_some_and(|token| token.is_word(skipped)) {
+                    idx += 1;
+                }
+            }
+            let (name, _) = dotted_name(tokens, idx)?;
+            let kind = match object {
+                "TABLE" | "VIEW" => AstNodeKind::Table,
+                "INDEX" => AstNodeKind::Other,
+                _ => AstNodeKind::Function,
+            };
+            return Some(Definition { kind, object, name });
+        }
+        // Modifiers such as `OR REPLACE`, `TEMPORARY`, `UNIQUE`, `MATERIALIZED`.
+        if !matches!(token, Token::Word(_)) || idx > 6 {
+            return None;
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Tables named after `FROM`, `JOIN`, `INTO`, `UPDATE`, `TABLE`, `REFERENCES` and
+/// `TRUNCATE`, plus `ON` in index and trigger definitions. Common table
+/// expressions, function-call arguments (`EXTRACT(YEAR FROM ts)`) and the table
+/// being defined are left out.
+fn table_refs(tokens: &[Token], defined: Option<&Definition>) -> Vec<String> {
+    let ctes: Vec<&str> = tokens
+        .windows(3)
+        .filter(|window| window[1].is_word("AS") && window[2] == Token::Punct('('))
+        .filter_map(|window| window[0].ident())
+        .collect();
+    let on_table = defined.is_some_and(|definition| matches!(definition.object, "INDEX" | "TRIGGER"));
+
+    let mut refs: Vec<String> = Vec::new();
+    let mut push = |name: String| {
+        let is_defined = defined.is_some_and(|definition| definition.name.eq_ignore_ascii_case(&name));
+        let is_cte = ctes.iter().any(|cte| cte.eq_ignore_ascii_case(&name));
+        if !is_defined && !is_cte && !refs.iter().any(|existing| existing.eq_ignore_ascii_case(&name)) {
+            refs.push(name);
+        }
+    };
+    // Whether each open parenthesis is a function call's argument list.
+    let mut call_parens: Vec<bool> = Vec::new();
+    for (idx, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('(') => {
+                let is_call = idx > 0 && tokens[idx - 1].ident().is_some();
+                call_parens.push(is_call);
+                continue;
+            }
+            Token::Punct(')') => {
+                call_parens.pop();
+                continue;
+            }
+            Token::Word(_) => {}
+            _ => continue,
+        }
+        let list = token.is_word("FROM") || token.is_word("JOIN");
+        if list && call_parens.last() == Some(&true) {
+            continue;
+        }
+        let single = ["INTO", "UPDATE", "TABLE", "REFERENCES", "TRUNCATE"]
+            .iter()
+            .any(|keyword| token.is_word(keyword))
+            || (on_table && token.is_word("ON"));
+        if !list && !single {
+            continue;
+        }
+
+        let mut next = idx + 1;
+        while tokens
+            .get(next)
+            .is_some_and(|token| ["ONLY", "IF", "EXISTS", "LATERAL", "TABLE"].iter().any(|word| token.is_word(word)))
+        {
+            next += 1;
+        }
+        while let Some((name, after)) = dotted_name(tokens, next) {
+            // A function in table position, such as `FROM generate_series(1, 3)`.
+            if list && tokens.get(after) == Some(&Token::Punct('(')) {
+                break;
+            }
+            push(name);
+            if !list {
+                break;
+            }
+            // Skip an alias, then continue a comma-separated table list.
+            next = after;
+            if tokens.get(next).is_some_and(|token| token.is_word("AS")) {
+                next += 1;
+            }
+            if tokens.get(next).and_then(Token::ident).is_some() {
+                next += 1;
+            }
+            if tokens.get(next) != Some(&Token::Punct(',')) {
+                break;
+            }
+            next += 1;
+        }
+    }
+    refs
+}
+
+/// The declaration header: everything before the column list, body or query.
+fn signature(statement: &str, definition: &Definition) -> String {
+    let collapsed = statement
+        .lines()
+        .map(|line| line.split("--").next().unwrap_or(line).trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let upper = collapsed.to_ascii_uppercase();
+    let stops: &[&str] = match definition.object {
+        "TABLE" => &["(", " AS "],
+        "VIEW" => &[" AS "],
+        "INDEX" => &[" USING ", " WHERE ", ";"],
+        _ => &[" AS ", " IS ", " BEGIN", " LANGUAGE ", " $", ";"],
+    };
+    let end = stops.iter().filter_map(|stop| upper.find(stop)).min().unwrap_or(collapsed.len());
+    collapsed[..end].trim().chars().take(200).collect()
+}
+
+/// The `--` comment lines a statement opens with.
+fn leading_comment(statement: &str) -> Option<String> {
+    let joined = statement
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.starts_with("--"))
+        .map(|line| line.trim_start_matches('-').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let sentence = joined.split(". ").next().unwrap_or(&joined).trim();
+    if sentence.is_empty() {
+        None
+    } else {
+        Some(sentence.chars().take(200).collect())
+    }
+}
+
+fn parse_sql_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let statements = split_statements(text);
+    if statements.is_empty() {
+        return None;
+    }
+    let line_at = |byte: usize| text[..byte].matches('\n').count() + 1;
+    let span = |start: usize, end: usize| {
+        // The line of the statement's last character; a trailing newline ends it.
+        let statement = &text[start..end];
+        let end_line = line_at(start) + statement.strip_suffix('\n').unwrap_or(statement).matches('\n').count();
+        (line_at(start), end_line, statement.trim().to_string())
+    };
+
+    let mut drafts = Vec::new();
+    // Undeclared statements waiting to be grouped: byte range and the tables they touch.
+    let mut group: Option<(usize, usize, Vec<String>)> = None;
+    let flush = |drafts: &mut Vec<ChunkDraft>, group: &mut Option<(usize, usize, Vec<String>)>| {
+        if let Some((start, end, type_refs)) = group.take() {
+            let (start_line, end_line, content) = span(start, end);
+            drafts.push(ChunkDraft {
+                type_refs,
+                ..ChunkDraft::plain(kind, start_line, end_line, content, Vec::new(), None, None)
+            });
+        }
+    };
+    for (start, end) in statements {
+        let statement = &text[start..end];
+        let tokens = tokenize(statement);
+        let defined = definition(&tokens);
+        let type_refs = table_refs(&tokens, defined.as_ref());
+
+        let Some(defined) = defined else {
+            match &mut group {
+                Some((group_start, group_end, refs)) if end - *group_start <= options.chunk_target_chars => {
+                    *group_end = end;
+                    for table in type_refs {
+                        if !refs.iter().any(|existing| existing.eq_ignore_ascii_case(&table)) {
+                            refs.push(table);
+                        }
+                    }
+                }
+                _ => {
+                    flush(&mut drafts, &mut group);
+                    group = Some((start, end, type_refs));
+                }
+            }
+            continue;
+        };
+
+        flush(&mut drafts, &mut group);
+        let (start_line, end_line, content) = span(start, end);
+        let symbol = defined.name.rsplit('.').next().unwrap_or(&defined.name).to_string();
+        drafts.push(ChunkDraft {
+            kind,
+            start_line,
+            end_line,
+            content,
+            heading_path: Vec::new(),
+            symbol: Some(symbol.clone()),
+            address: None,
+            ast_kind: Some(defined.kind),
+            qualified_name: Some(defined.name.clone()),
+            signature: Some(signature(statement, &defined)),
+            parent_symbol: None,
+            imports: Vec::new(),
+            exports: Vec::new(),
+            calls: Vec::new(),
+            method_calls: Vec::new(),
+            type_refs,
+            doc_summary: leading_comment(statement),
+            symbol_id: None,
+            symbol_tail: Some(symbol),
+            module_path: None,
+            visibility: None,
+            log_level: None,
+        });
+    }
+    flush(&mut drafts, &mut group);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str) -> Vec<crate::Chunk> {
+        parse_sql_chunks("db/001_init.sql", text, ChunkKind::Unknown, &IngestOptions::default())
+            .expect("sql should chunk")
+    }
+
+    #[test]
+    fn create_statements_become_symbols_and_tables_become_type_refs() {
+        let text = r#"-- Accounts that can sign in.
+CREATE TABLE IF NOT EXISTS public.users (
+  id serial PRIMARY KEY,
+  org_id int REFERENCES orgs(id),
+  note text DEFAULT 'a; b'
+);
+
+CREATE UNIQUE INDEX users_email_idx ON public.users (lower(email));
+
+CREATE OR REPLACE FUNCTION touch_user(uid int) RETURNS void AS $$
+BEGIN
+  UPDATE users SET seen_at = now() WHERE id = uid;
+  INSERT INTO audit_log (user_id) VALUES (uid);
+END;
+$$ LANGUAGE plpgsql;
+
+INSERT INTO orgs (name) VALUES ('acme');
+WITH recent AS (SELECT * FROM users u WHERE u.seen_at > now())
+SELECT extract(year FROM r.seen_at) FROM recent r, teams t JOIN orgs o ON o.id = r.org_id;
+"#;
+        let chunks = chunks(text);
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.qualified_name.as_deref()).collect();
+        assert_eq!(symbols, [Some("public.users"), Some("users_email_idx"), Some("touch_user"), None]);
+
+        let users = &chunks[0];
+        assert_eq!(users.symbol.as_deref(), Some("users"));
+        assert_eq!(users.ast_kind, Some(AstNodeKind::Table));
+        assert_eq!((users.start_line, users.end_line), (1, 6));
+        assert_eq!(users.doc_summary.as_deref(), Some("Accounts that can sign in."));
+        assert_eq!(users.signature.as_deref(), Some("CREATE TABLE IF NOT EXISTS public.users"));
+        assert_eq!(users.type_refs, ["orgs"]);
+
+        assert_eq!(chunks[1].type_refs, ["public.users"]);
+
+        let touch = &chunks[2];
+        assert_eq!(touch.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(touch.signature.as_deref(), Some("CREATE OR REPLACE FUNCTION touch_user(uid int) RETURNS void"));
+        assert_eq!(touch.type_refs, ["users", "audit_log"]);
+        assert_eq!((touch.start_line, touch.end_line), (10, 15));
+
+        let queries = &chunks[3];
+        assert_eq!((queries.start_line, queries.end_line), (17, 19));
+        assert_eq!(queries.type_refs, ["orgs", "users", "teams"]);
+    }
+
+    #[test]
+    fn routine_bodies_without_dollar_quotes_stay_whole() {
+        let text = "DELIMITER //\nCREATE PROCEDURE archive()\nBEGIN\n  IF 1 THEN\n    DELETE FROM sessions;\n  END IF;\nEND //\nDELIMITER ;\n\nCREATE VIEW active AS SELECT * FROM sessions WHERE größe > 0\nGO\n";
+        let chunks = chunks(text);
+        let names: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref()).collect();
+        assert_eq!(names, [Some("archive"), Some("active")]);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (2, 7));
+        assert_eq!(chunks[0].type_refs, ["sessions"]);
+        assert_eq!(chunks[1].ast_kind, Some(AstNodeKind::Table));
+    }
+    #[test]
+    fn statements_ending_in_non_ascii_text_keep_their_lines() {
+        let chunks = chunks("SELECT 1;\n-- trailing note é");
+        let last = chunks.last().expect("trailing chunk");
+        assert_eq!(last.end_line, 2);
+        assert!(last.content.ends_with('é'));
+        assert_eq!(self::chunks("SELECT 'é'").last().map(|chunk| chunk.end_line), Some(1));
+    }
+}
@@ -0,0 +1,280 @@
+use super::{comment_summary, loose_line_drafts};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+use crate::model::{AstNodeKind, ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+fn block_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"^([A-Za-z_][A-Za-z0-9_-]*)((?:\s+(?:"[^"]*"|[A-Za-z_][A-Za-z0-9_-]*))*)\s*\{"#)
+            .expect("hcl block regex")
+    })
+}
+
+fn label_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#""([^"]*)"|([A-Za-z_][A-Za-z0-9_-]*)"#).expect("hcl label regex"))
+}
+
+fn reference_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\b(?:(?:var|local|module)\.[A-Za-z_][A-Za-z0-9_-]*|data\.[A-Za-z_][A-Za-z0-9_]*\.[A-Za-z_][A-Za-z0-9_-]*)")
+            .expect("hcl reference regex")
+    })
+}
+
+fn depends_on_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?s)\bdepends_on\s*=\s*\[([^\]]*)\]").expect("hcl depends_on regex"))
+}
+
+fn source_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"(?m)^\s*source\s*=\s*"([^"]+)""#).expect("hcl source regex"))
+}
+
+/// Terraform and other HCL files: one chunk per top-level block, named by its
+/// Terraform address (`aws_s3_bucket.logs`, `module.vpc`, `var.region`). Module
+/// sources are recorded as imports; `depends_on` entries and references to
+/// variables, locals, modules and data sources are recorded as calls.
+pub(crate) struct TerraformAdapter;
+
+impl LanguageAdapter for TerraformAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("tf".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(extension)) if extension == "tf" || extension == "hcl")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_terraform_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Byte offset of the `}` closing the brace at `open`, skipping strings (and the
+/// expressions interpolated into them), heredocs and comments.
+fn closing_brace(text: &str, open: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    // Open contexts: `{` for braces, `$` for `${` interpolations, `"` for strings.
+    let mut stack = vec![b'{'];
+    let mut idx = open + 1;
+    while idx < bytes.len() {
+        let byte = bytes[idx];
+        if stack.last() == Some(&b'"') {
+            match byte {
+                b'\\' => idx += 1,
+                b'"' => {
+                    stack.pop();
+                }
+                b'$' | b'%' if bytes.get(idx + 1) == Some(&b'{') => {
+                    stack.push(b'$');
+                    idx += 1;
+                }
+                _ => {}
+            }
+            idx += 1;
+            continue;
+        }
+        match byte {
+            b'"' => stack.push(b'"'),
+            b'{' => stack.push(b'{'),
+            b'}' => {
+                stack.pop();
+                if stack.is_empty() {
+                    return Some(idx);
+                }
+            }
+            b'#' => idx = text[idx..].find('\n').map_or(bytes.len(), |eol| idx + eol),
+            b'/' if bytes.get(idx + 1) == Some(&b'/') => {
+                idx = text[idx..].find('\n').map_or(bytes.len(), |eol| idx + eol);
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = text[idx + 2..].find("*/").map_or(bytes.len(), |close| idx + 2 + close + 1);
+            }
+            b'<' if text[idx..].starts_with("<<") => {
+                let rest = text[idx + 2..].trim_start_matches('-');
+                let marker: String = rest.chars().take_while(|ch| ch.is_ascii_alphanumeric() || *ch == '_').collect();
+                if !marker.is_empty() {
+                    let body = text[idx..].find('\n').map_or(bytes.len(), |eol| idx + eol + 1);
+                    let mut line_start = body;
+                    loop {
+                        let line_end = text[line_start..].find('\n').map_or(bytes.len(), |eol| line_start + eol);
+                        if text[line_start..line_end].trim() == marker || line_end >= bytes.len() {
+                            idx = line_end;
+                            break;
+                        }
+                        line_start = line_end + 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Terraform address and symbol kind for a block type and its labels.
+fn block_symbol(block_type: &str, labels: &[String]) -> (String, AstNodeKind) {
+    let address = |prefix: &str| {
+        let mut parts = vec![prefix.to_string()];
+        parts.extend(labels.iter().cloned());
+        parts.join(".")
+    };
+    match block_type {
+        "resource" => (labels.join("."), AstNodeKind::Class),
+        "data" => (address("data"), AstNodeKind::Class),
+        "module" => (address("module"), AstNodeKind::Module),
+        "variable" => (address("var"), AstNodeKind::Variable),
+        "output" => (address("output"), AstNodeKind::Export),
+        _ => (address(block_type), AstNodeKind::Other),
+    }
+}
+
+/// Blocks and variables a block depends on, in order of first mention.
+fn references(body: &str, symbol: &str) -> Vec<String> {
+    let mut calls: Vec<String> = Vec::new();
+    let depends_on = depends_on_re().captures_iter(body).flat_map(|caps| {
+        caps[1]
+            .split(',')
+            .map(|entry| entry.trim().trim_matches('"').to_string())
+            .filter(|entry| !entry.is_empty())
+            .collect::<Vec<_>>()
+    });
+    let mentioned = reference_re().find_iter(body).map(|found| found.as_str().to_string());
+    for reference in depends_on.chain(mentioned) {
+        if reference != symbol && !calls.contains(&reference) {
+            calls.push(reference);
+        }
+    }
+    calls
+}
+
+fn parse_terraform_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let line_at = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+    let is_comment = |line: &str| line.starts_with('#') || line.starts_with("//");
+
+    let mut drafts = Vec::new();
+    let mut region_start = 0;
+    let mut idx = 0;
+    while idx < lines.len() {
+        let Some(caps) = block_re().captures(lines[idx]) else {
+            idx += 1;
+            continue;
+        };
+        let open = line_starts[idx] + caps.get(0).expect("whole match").end() - 1;
+        let Some(close) = closing_brace(text, open) else {
+            break;
+        };
+        let end = line_at(close);
+        let mut start = idx;
+        while start > region_start && is_comment(lines[start - 1]) {
+            start -= 1;
+        }
+        loose_line_drafts(&lines, (region_start, start), kind, options, |_| None, &mut drafts);
+
+        let labels: Vec<String> = label_re()
+            .captures_iter(&caps[2])
+            .filter_map(|label| label.get(1).or(label.get(2)).map(|label| label.as_str().to_string()))
+            .collect();
+        let (symbol, ast_kind) = block_symbol(&caps[1], &labels);
+        let body = &text[open..close];
+        let imports = if &caps[1] == "module" {
+            source_re().captures(body).map(|source| source[1].to_string()).into_iter().collect()
+        } else {
+            Vec::new()
+        };
+        drafts.push(ChunkDraft {
+            kind,
+            start_line: start + 1,
+            end_line: end + 1,
+            content: lines[start..=end].join("\n"),
+            heading_path: Vec::new(),
+            symbol: Some(symbol.clone()),
+            address: None,
+            ast_kind: Some(ast_kind),
+            qualified_name: Some(symbol.clone()),
+            signature: Some(lines[idx].trim_end_matches('{').trim().to_string()),
+            parent_symbol: None,
+            imports,
+            exports: Vec::new(),
+            calls: references(body, &symbol),
+            method_calls: Vec::new(),
+            type_refs: Vec::new(),
+            doc_summary: comment_summary(lines[start..idx].iter().copied()),
+            symbol_id: None,
+            symbol_tail: labels.last().cloned().or(Some(symbol)),
+            module_path: None,
+            visibility: None,
+            log_level: None,
+        });
+        region_start = end + 1;
+        idx = end + 1;
+    }
+    if drafts.is_empty() {
+        return None;
+    }
+    loose_line_drafts(&lines, (region_start, lines.len()), kind, options, |_| None, &mut drafts);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_become_symbols_with_module_sources_and_dependencies() {
+        let text = r#"variable "region" {
+  type    = string
+  default = "eu-west-1"
+}
+
+# Network shared by every service.
+module "vpc" {
+  source = "terraform-aws-modules/vpc/aws"
+  name   = "main-${var.region}"
+}
+
+resource "aws_s3_bucket" "logs" {
+  bucket = "logs-${var.region}"
+  policy = <<EOF
+{ "Statement": [ {
+EOF
+
+  depends_on = [module.vpc]
+}
+"#;
+        let chunks = parse_terraform_chunks("infra/main.tf", text, ChunkKind::Unknown, &IngestOptions::default())
+            .expect("terraform should chunk");
+        let symbols: Vec<_> = chunks.iter().map(|chunk| chunk.symbol.as_deref().unwrap_or("")).collect();
+        assert_eq!(symbols, ["var.region", "module.vpc", "aws_s3_bucket.logs"]);
+
+        let vpc = &chunks[1];
+        assert_eq!((vpc.start_line, vpc.end_line), (6, 10));
+        assert_eq!(vpc.imports, ["terraform-aws-modules/vpc/aws"]);
+        assert_eq!(vpc.calls, ["var.region"]);
+        assert_eq!(vpc.symbol_tail.as_deref(), Some("vpc"));
+        assert_eq!(vpc.doc_summary.as_deref(), Some("Network shared by every service."));
+
+        let bucket = &chunks[2];
+        assert_eq!((bucket.start_line, bucket.end_line), (12, 19));
+        assert_eq!(bucket.calls, ["module.vpc", "var.region"]);
+    }
+}
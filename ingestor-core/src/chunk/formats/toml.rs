@@ -0,0 +1,285 @@
+use super::{key_node_drafts, with_leading_comments, KeyNode};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::finalize_chunks;
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// TOML: one chunk per table (`[dependencies.serde]`, `[[bin]]` as `bin[0]`) and per
+/// top-level key, addressed by its key path. Chunks keep the file's lines verbatim
+/// and in order, so manifests can still be read back from their chunks.
+pub(crate) struct TomlAdapter;
+
+impl LanguageAdapter for TomlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Toml)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Toml)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_toml_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+const KEY_PATTERN: &str = r#"(?:[A-Za-z0-9_-]+|"[^"]*"|'[^']*')(?:\s*\.\s*(?:[A-Za-z0-9_-]+|"[^"]*"|'[^']*'))*"#;
+
+/// `[table]` and `[[array.of.tables]]` headers.
+fn header_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(r"^\s*(\[\[?)\s*({KEY_PATTERN})\s*\]\]?\s*(?:#.*)?$")).expect("toml header regex")
+    })
+}
+
+/// `key = value` and `dotted.key = value`.
+fn key_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(&format!(r"^\s*({KEY_PATTERN})\s*=")).expect("toml key regex"))
+}
+
+/// `a . "b"` → `a."b"`.
+fn normalize_key(key: &str) -> String {
+    let mut normalized = String::with_capacity(key.len());
+    let mut quote = None;
+    for ch in key.chars() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if ch == open => quote = None,
+            (None, _) if ch.is_whitespace() => continue,
+            _ => {}
+        }
+        normalized.push(ch);
+    }
+    normalized
+}
+
+/// Tracks multi-line strings and arrays, so that only lines at the top level are
+/// read as headers or keys.
+#[derive(Default)]
+struct ValueState {
+    depth: usize,
+    multiline: Option<&'static str>,
+}
+
+impl ValueState {
+    fn at_top(&self) -> bool {
+        self.depth == 0 && self.multiline.is_none()
+    }
+
+    fn scan(&mut self, line: &str) {
+        let mut rest = line;
+        while !rest.is_empty() {
+            if let Some(close) = self.multiline {
+                match rest.find(close) {
+                    Some(idx) => {
+                        rest = &rest[idx + close.len()..];
+                        self.multiline = None;
+                    }
+                    None => return,
+                }
+                continue;
+            }
+            let Some(ch) = rest.chars().next() else {
+                return;
+            };
+            if let Some(delimiter) = ["\"\"\"", "'''"].into_iter().find(|delimiter| rest.starts_with(delimiter)) {
+                self.multiline = Some(delimiter);
+                rest = &rest[3..];
+                continue;
+            }
+            rest = &rest[ch.len_utf8()..];
+            match ch {
+                '#' => return,
+                '"' | '\'' => {
+                    let mut escaped = false;
+                    let close = rest.char_indices().find(|(_, next)| {
+                        let found = *next == ch && !escaped;
+                        escaped = ch == '"' && *next == '\\' && !escaped;
+                        found
+                    });
+                    rest = close.map_or("", |(idx, _)| &rest[idx + 1..]);
+                }
+                '[' | '{' => self.depth += 1,
+                ']' | '}' => self.depth = self.depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn parse_toml_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut nodes: Vec<KeyNode> = Vec::new();
+    let mut array_counts: HashMap<String, usize> = HashMap::new();
+    // Whether `nodes.last()` is a table, which later keys belong to.
+    let mut in_table = false;
+    let mut last_start: Option<usize> = None;
+    let mut state = ValueState::default();
+    for (idx, line) in lines.iter().enumerate() {
+        if state.at_top() {
+            let floor = last_start.map_or(0, |start| start + 1);
+            if let Some(captures) = header_re().captures(line) {
+                let key = normalize_key(&captures[2]);
+                let path = if &captures[1] == "[[" {
+                    let count = array_counts.entry(key.clone()).or_insert(0);
+                    *count += 1;
+                    format!("{key}[{}]", *count - 1)
+                } else {
+                    key
+                };
+                let start = with_leading_comments(&lines, idx, floor);
+                nodes.push(KeyNode {
+                    heading: vec![path.clone()],
+                    path,
+                    start,
+                    end: idx,
+                    children: Vec::new(),
+                });
+                in_table = true;
+                last_start = Some(start);
+                continue;
+            }
+            if let Some(captures) = key_re().captures(line) {
+                let key = normalize_key(&captures[1]);
+                let start = with_leading_comments(&lines, idx, floor);
+                match nodes.last_mut().filter(|_| in_table) {
+                    Some(table) => {
+                        let (path, heading) = table.child_path(&key);
+                        table.children.push(KeyNode {
+                            path,
+                            heading,
+                            start,
+                            end: idx,
+                            children: Vec::new(),
+                        });
+                    }
+                    None => nodes.push(KeyNode {
+                        path: key.clone(),
+                        heading: vec![key],
+                        start,
+                        end: idx,
+                        children: Vec::new(),
+                    }),
+                }
+                last_start = Some(start);
+            }
+        }
+        state.scan(line);
+    }
+    if nodes.is_empty() {
+        return None;
+    }
+
+    let top_key = |node: &KeyNode| {
+        let first = node.path.split(['.', '[']).next()?;
+        Some(first.trim_matches(['"', '\'']).to_string()).filter(|key| !key.is_empty())
+    };
+    let root = KeyNode {
+        path: String::new(),
+        heading: Vec::new(),
+        start: 0,
+        end: lines.len() - 1,
+        children: nodes,
+    };
+    let drafts = key_node_drafts(&lines, vec![root], top_key, kind, options);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str, chunk_target_chars: usize) -> Vec<crate::Chunk> {
+        let options = IngestOptions {
+            chunk_target_chars,
+            ..IngestOptions::default()
+        };
+        parse_toml_chunks("Cargo.toml", text, ChunkKind::Unknown, &options).expect("toml should chunk")
+    }
+
+    #[test]
+    fn tables_and_keys_become_chunks_addressed_by_key_path() {
+        let text = r#"# Workspace member.
+[package]
+name = "app"
+description = """
+[not] a = "header"
+"""
+
+[dependencies]
+anyhow = "1"
+regex = "1"
+tokio = { version = "1", features = [
+  "macros",
+  "rt",
+] }
+
+[dependencies.serde]
+version = "1"
+
+[[bin]]
+name = "server"
+
+[[bin]]
+name = "worker"
+"#;
+        let whole = chunks(text, 4_000);
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].address, None);
+        assert_eq!((whole[0].start_line, whole[0].end_line), (1, 23));
+
+        let tables = chunks(text, 90);
+        let addresses: Vec<_> = tables.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(
+            addresses,
+            ["package", "dependencies.anyhow..dependencies.regex", "dependencies.tokio", "dependencies.serde..bin[1]"]
+        );
+        assert_eq!((tables[0].start_line, tables[0].end_line), (1, 7));
+        assert_eq!(tables[0].symbol.as_deref(), Some("package"));
+        // The small tables at the end share no key, so they pack under the range they cover.
+        assert_eq!((tables[3].start_line, tables[3].end_line), (16, 23));
+        assert_eq!(tables[3].symbol, None);
+        assert!(tables[3].heading_path.is_empty());
+
+        // The chunks still read back as the whole manifest, line for line.
+        let rejoined: Vec<&str> = tables.iter().flat_map(|chunk| chunk.content.lines()).collect();
+        let original: Vec<&str> = text.lines().filter(|line| !line.is_empty()).collect();
+        assert_eq!(rejoined.into_iter().filter(|line| !line.is_empty()).collect::<Vec<_>>(), original);
+
+        let split = chunks(text, 70);
+        let addresses: Vec<_> = split.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(
+            &addresses[..4],
+            ["package.name", "package.description", "dependencies.anyhow..dependencies.regex", "dependencies.tokio"]
+        );
+        assert!(split[2].content.starts_with("[dependencies]\nanyhow"), "{}", split[2].content);
+        assert_eq!(&addresses[4..], ["dependencies.serde..bin[0]", "bin[1]"]);
+    }
+
+    #[test]
+    fn packed_arrays_are_ranges_and_split_tables_keep_their_header() {
+        let text = "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[[bin]]\nname = \"a\"\n\n[[bin]]\nname = \"b\"\n";
+        let packed = chunks(text, 60);
+        let addresses: Vec<_> = packed.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["package", "bin[0..2]"]);
+        assert_eq!(packed[1].symbol.as_deref(), Some("bin"));
+
+        let text = "[dependencies]\nserde = { version = \"1\", features = [\"derive\"] }\nanyhow = \"1\"\n";
+        let split = chunks(text, 20);
+        let addresses: Vec<_> = split.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["dependencies.serde", "dependencies.anyhow"]);
+        assert!(split[0].content.starts_with("[dependencies]\nserde"), "{}", split[0].content);
+    }
+}
@@ -0,0 +1,391 @@
+use super::{key_node_drafts, KeyNode};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::finalize_chunks;
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use std::collections::HashMap;
+
+/// XML and MSBuild project files.
+const XML_EXTENSIONS: &[&str] = &["xml", "csproj", "fsproj", "vbproj"];
+
+/// XML: one chunk per second-level element, addressed by element path
+/// (`project/dependencies`). Large elements split into their children, with
+/// repeated siblings indexed from 1 (`project/dependencies/dependency[3]`).
+/// Namespace prefixes are kept as written.
+pub(crate) struct XmlAdapter;
+
+impl LanguageAdapter for XmlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Other("xml".to_string()))
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(extension)) if XML_EXTENSIONS.contains(&extension.as_str()))
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_xml_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// An element and the 0-based lines from its start tag (or the comment directly
+/// above it) to its end tag.
+struct Element {
+    name: String,
+    start: usize,
+    end: usize,
+    children: Vec<Element>,
+}
+
+/// Byte offset just past the `>` closing the tag that opens at `from`, skipping
+/// quoted attribute values and bracketed DOCTYPE subsets.
+fn tag_end(bytes: &[u8], from: usize) -> Option<usize> {
+    let mut quote: Option<u8> = None;
+    let mut depth = 0usize;
+    for (idx, byte) in bytes.iter().enumerate().skip(from + 1) {
+        match (quote, byte) {
+            (Some(open), _) if *byte == open => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(*byte),
+            (None, b'[') => depth += 1,
+            (None, b']') => depth = depth.saturating_sub(1),
+            (None, b'>') if depth == 0 => return Some(idx + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn find(text: &str, from: usize, needle: &str) -> Option<usize> {
+    text[from..].find(needle).map(|idx| from + idx + needle.len())
+}
+
+/// The root elements of the document, or `None` if a comment, CDATA section or
+/// tag is left unterminated.
+fn parse_elements(text: &str) -> Option<Vec<Element>> {
+    let bytes = text.as_bytes();
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let line_at = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+
+    let mut roots = Vec::new();
+    let mut open: Vec<Element> = Vec::new();
+    // Start of the comment run directly above the next tag, if any.
+    let mut comment: Option<usize> = None;
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find('<') {
+        let lt = pos + offset;
+        if !text[pos..lt].trim().is_empty() {
+            comment = None;
+        }
+        let rest = &text[lt..];
+        if rest.starts_with("<!--") {
+            comment.get_or_insert(lt);
+            pos = find(text, lt + 4, "-->")?;
+            continue;
+        }
+        if rest.starts_with("<![CDATA[") {
+            comment = None;
+            pos = find(text, lt, "]]>")?;
+            continue;
+        }
+        if rest.starts_with("<?") {
+            pos = find(text, lt, "?>")?;
+            continue;
+        }
+        let gt = tag_end(bytes, lt)?;
+        pos = gt;
+        if rest.starts_with("<!") {
+            continue;
+        }
+        if let Some(closing) = rest.strip_prefix("</") {
+            comment = None;
+            let name = closing[..gt - lt - 3].trim();
+            // Tolerate unclosed elements by closing everything up to the match.
+            if !open.iter().any(|element| element.name == name) {
+                continue;
+            }
+            while let Some(mut element) = open.pop() {
+                let matched = element.name == name;
+                element.end = line_at(gt - 1);
+                match open.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => roots.push(element),
+                }
+                if matched {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        let tag = &text[lt + 1..gt - 1];
+        let name_len = tag.find(|c: char| c.is_whitespace() || c == '/' || c == '>').unwrap_or(tag.len());
+        let element = Element {
+            name: tag[..name_len].to_string(),
+            start: line_at(comment.take().unwrap_or(lt)),
+            end: line_at(gt - 1),
+            children: Vec::new(),
+        };
+        if element.name.is_empty() {
+            continue;
+        }
+        if tag.ends_with('/') {
+            match open.last_mut() {
+                Some(parent) => parent.children.push(element),
+                None => roots.push(element),
+            }
+        } else {
+            open.push(element);
+        }
+    }
+    let last_line = line_at(text.len().saturating_sub(1));
+    while let Some(mut element) = open.pop() {
+        element.end = last_line;
+        match open.last_mut() {
+            Some(parent) => parent.children.push(element),
+            None => roots.push(element),
+        }
+    }
+    Some(roots)
+}
+
+/// Key nodes for the children of the element at `parent`. Children on the
+/// parent's start or closing line are left to the parent, and siblings sharing a
+/// line fold into one node addressed by the parent's path, so spans stay ordered
+/// and disjoint. The parent's start and end tags lead and trail as nodes of their
+/// own, addressed by the parent's path, so a split never files them under the
+/// first or last child.
+fn key_nodes(parent: &KeyNode, parent_end: usize, elements: Vec<Element>) -> Vec<KeyNode> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for element in &elements {
+        *counts.entry(element.name.clone()).or_default() += 1;
+    }
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut nodes: Vec<KeyNode> = Vec::new();
+    for element in elements {
+        let index = seen.entry(element.name.clone()).or_default();
+        *index += 1;
+        if element.start <= parent.start || element.start >= parent_end {
+            continue;
+        }
+        if let Some(previous) = nodes.last_mut() {
+            if element.start <= previous.end {
+                previous.path.clone_from(&parent.path);
+                previous.heading.clone_from(&parent.heading);
+                previous.end = previous.end.max(element.end);
+                previous.children.clear();
+                continue;
+            }
+        }
+        let segment = if counts[&element.name] > 1 {
+            format!("{}[{index}]", element.name)
+        } else {
+            element.name.clone()
+        };
+        let mut heading = parent.heading.clone();
+        heading.push(segment.clone());
+        let mut node = KeyNode {
+            path: format!("{}/{segment}", parent.path),
+            heading,
+            start: element.start,
+            end: element.end,
+            children: Vec::new(),
+        };
+        node.children = key_nodes(&node, element.end, element.children);
+        nodes.push(node);
+    }
+    if let Some(first) = nodes.first().filter(|first| first.start > parent.start) {
+        let header = KeyNode {
+            path: parent.path.clone(),
+            heading: parent.heading.clone(),
+            start: parent.start,
+            end: first.start - 1,
+            children: Vec::new(),
+        };
+        nodes.insert(0, header);
+    }
+    if let Some(last) = nodes.last().filter(|last| last.end < parent_end) {
+        let trailer = KeyNode {
+            path: parent.path.clone(),
+            heading: parent.heading.clone(),
+            start: last.end + 1,
+            end: parent_end,
+            children: Vec::new(),
+        };
+        nodes.push(trailer);
+    }
+    nodes
+}
+
+fn parse_xml_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let mut roots = parse_elements(text)?;
+    if roots.len() != 1 {
+        return None;
+    }
+    let root = roots.remove(0);
+    let mut root_node = KeyNode {
+        path: root.name.clone(),
+        heading: vec![root.name.clone()],
+        start: root.start,
+        end: root.end,
+        children: Vec::new(),
+    };
+    root_node.children = key_nodes(&root_node, root.end, root.children);
+    if root_node.children.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<&str> = text.lines().collect();
+    let second_level = |node: &KeyNode| {
+        let name = node.heading.get(1)?;
+        Some(name.split('[').next().unwrap_or(name).to_string())
+    };
+    let drafts = key_node_drafts(&lines, vec![root_node], second_level, kind, options);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <artifactId>ingest</artifactId>
+  <!-- Runtime dependencies. -->
+  <dependencies>
+    <dependency>
+      <groupId>com.fasterxml.jackson.core</groupId>
+      <artifactId>jackson-databind</artifactId>
+    </dependency>
+    <dependency>
+      <groupId>org.slf4j</groupId>
+      <artifactId>slf4j-api</artifactId>
+    </dependency>
+    <dependency>
+      <groupId>org.junit.jupiter</groupId>
+      <artifactId>junit-jupiter</artifactId>
+      <scope>test</scope>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+    fn chunks(text: &str, chunk_target_chars: usize) -> Vec<crate::Chunk> {
+        let options = IngestOptions {
+            chunk_target_chars,
+            ..IngestOptions::default()
+        };
+        parse_xml_chunks("pom.xml", text, ChunkKind::Text, &options).expect("xml should chunk")
+    }
+
+    fn addresses(chunks: &[crate::Chunk]) -> Vec<&str> {
+        chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect()
+    }
+
+    #[test]
+    fn second_level_elements_become_chunks() {
+        assert_eq!(addresses(&chunks(POM, 4_000)), ["project"]);
+
+        let chunks = chunks(POM, 500);
+        assert_eq!(addresses(&chunks), ["project/modelVersion..project/artifactId", "project/dependencies"]);
+        assert!(chunks[0].content.starts_with("<?xml"));
+        assert!(chunks[0].content.contains("<artifactId>ingest</artifactId>"));
+        let dependencies = &chunks[1];
+        assert_eq!((dependencies.start_line, dependencies.end_line), (5, 21));
+        assert!(dependencies.content.starts_with("<!-- Runtime dependencies. -->"));
+        assert_eq!(dependencies.heading_path, ["project", "dependencies"]);
+        assert_eq!(dependencies.symbol.as_deref(), Some("dependencies"));
+    }
+
+    #[test]
+    fn large_elements_split_into_indexed_children() {
+        let chunks = chunks(POM, 200);
+        let third = chunks
+            .iter()
+            .find(|chunk| chunk.address.as_deref() == Some("project/dependencies/dependency[3]"))
+            .expect("third dependency");
+        assert_eq!(third.heading_path, ["project", "dependencies", "dependency[3]"]);
+        assert!(third.content.contains("junit-jupiter"));
+        assert!(!third.content.contains("slf4j"));
+    }
+
+    #[test]
+    fn split_elements_keep_their_start_tag_out_of_the_first_child() {
+        let chunks = chunks(POM, 150);
+        let header = chunks
+            .iter()
+            .find(|chunk| chunk.content.contains("<dependencies>"))
+            .expect("dependencies start tag");
+        assert_eq!(header.address.as_deref(), Some("project/dependencies"));
+        assert_eq!((header.start_line, header.end_line), (5, 6));
+
+        let first = chunks
+            .iter()
+            .find(|chunk| chunk.content.contains("jackson-databind"))
+            .expect("first dependency");
+        assert_eq!(first.address.as_deref(), Some("project/dependencies/dependency[1]"));
+        assert_eq!((first.start_line, first.end_line), (7, 10));
+        assert!(first.content.starts_with("<dependency>"));
+        let prolog = chunks.iter().find(|chunk| chunk.content.contains("<?xml")).expect("prolog");
+        assert_eq!(prolog.address.as_deref(), Some("project/modelVersion"));
+    }
+
+    #[test]
+    fn namespace_prefixes_are_kept() {
+        let manifest = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <uses-permission android:name="android.permission.INTERNET" />
+    <application android:label="Example">
+        <activity android:name=".MainActivity" android:exported="true" />
+    </application>
+</manifest>
+"#;
+        let chunks = chunks(manifest, 150);
+        assert_eq!(addresses(&chunks), ["manifest", "manifest/uses-permission", "manifest/application"]);
+        assert!(chunks[2].content.contains("android:name=\".MainActivity\""));
+
+        let xsd = "<xs:schema xmlns:xs=\"http://www.w3.org/2001/XMLSchema\">\n  <xs:element name=\"order\"/>\n</xs:schema>\n";
+        assert_eq!(addresses(&self::chunks(xsd, 60)), ["xs:schema", "xs:schema/xs:element"]);
+    }
+
+    #[test]
+    fn same_line_children_and_closing_tags_stay_with_their_parent() {
+        let pom = r#"<project>
+  <dependencies>
+    <dependency><groupId>com.fasterxml.jackson.core</groupId><artifactId>jackson-databind</artifactId></dependency>
+    <dependency>
+      <groupId>org.slf4j</groupId><artifactId>slf4j-api</artifactId>
+      <scope>runtime</scope>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+        let chunks = chunks(pom, 90);
+        assert_eq!(
+            addresses(&chunks),
+            [
+                "project",
+                "project/dependencies",
+                "project/dependencies/dependency[1]",
+                "project/dependencies/dependency[2]",
+                "project/dependencies/dependency[2]/scope",
+                "project/dependencies",
+                "project",
+            ]
+        );
+        // The groupId and artifactId sharing a line are filed under their dependency.
+        assert_eq!((chunks[3].start_line, chunks[3].end_line), (4, 5));
+        assert!(chunks[3].content.ends_with("<artifactId>slf4j-api</artifactId>"));
+        assert_eq!(chunks[5].content, "</dependencies>");
+        assert_eq!(chunks[6].content, "</project>");
+    }
+}
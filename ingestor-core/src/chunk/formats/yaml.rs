@@ -0,0 +1,296 @@
+use super::{key_node_drafts, with_leading_comments, KeyNode};
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::chunk::legacy::finalize_chunks;
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+
+/// YAML: one chunk per top-level key of each document, addressed by key path
+/// (`jobs.build.steps`). Multi-document files prefix the path with the document
+/// index (`[1].spec`).
+pub(crate) struct YamlAdapter;
+
+impl LanguageAdapter for YamlAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Yaml)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Yaml)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_yaml_chunks(request.path, request.text, request.kind, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_content(line: &str) -> bool {
+    let trimmed = line.trim();
+    !trimmed.is_empty() && !trimmed.starts_with('#')
+}
+
+fn is_item(rest: &str) -> bool {
+    rest == "-" || rest.starts_with("- ")
+}
+
+/// `---` and `...` lines that separate or end documents.
+fn is_document_marker(line: &str) -> bool {
+    let marker = |prefix: &str| {
+        line.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+    };
+    marker("---") || marker("...")
+}
+
+/// The key of a `key: value` line (with its indentation removed) and the value text.
+fn mapping_key(rest: &str) -> Option<(String, &str)> {
+    let (key, after) = match rest.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let close = rest[1..].find(quote)? + 1;
+            (rest[1..close].to_string(), rest[close + 1..].trim_start().strip_prefix(':')?)
+        }
+        '#' | '{' | '[' | '&' | '*' | '!' | '|' | '>' | '%' | '@' | '`' | '?' | '-' => return None,
+        _ => {
+            let colon = rest
+                .char_indices()
+                .find(|(idx, ch)| *ch == ':' && rest[idx + 1..].chars().next().is_none_or(char::is_whitespace))?
+                .0;
+            let key = rest[..colon].trim_end();
+            if key.contains(" #") {
+                return None;
+            }
+            (key.to_string(), &rest[colon + 1..])
+        }
+    };
+    if !(after.is_empty() || after.starts_with(char::is_whitespace)) {
+        return None;
+    }
+    Some((key, after.trim()))
+}
+
+/// Whether a key's value continues on the following lines as a nested mapping or
+/// sequence (rather than a scalar or a `|`/`>` block of text).
+fn opens_collection(value: &str) -> bool {
+    let value = match value.find(" #") {
+        Some(idx) => value[..idx].trim(),
+        None if value.starts_with('#') => "",
+        None => value,
+    };
+    value
+        .split_whitespace()
+        .all(|token| token.starts_with('&') || token.starts_with('!'))
+}
+
+/// The entries of the mapping or sequence held in `start..=end` under `parent`.
+pub(super) fn children(lines: &[&str], parent: &KeyNode, start: usize, end: usize) -> Vec<KeyNode> {
+    let Some(first) = (start..=end).find(|idx| is_content(lines[*idx])) else {
+        return Vec::new();
+    };
+    let child_indent = indent(lines[first]);
+    let sequence = is_item(lines[first].trim_start());
+
+    let mut nodes: Vec<KeyNode> = Vec::new();
+    let mut opens = Vec::new();
+    for idx in first..=end {
+        let line = lines[idx];
+        if !is_content(line) || indent(line) != child_indent {
+            continue;
+        }
+        let rest = line.trim_start();
+        let (path, heading, collection) = if sequence {
+            if !is_item(rest) {
+                continue;
+            }
+            let (path, heading) = parent.item_path(nodes.len());
+            (path, heading, false)
+        } else {
+            let Some((key, value)) = mapping_key(rest) else {
+                continue;
+            };
+            let (path, heading) = parent.child_path(&key);
+            (path, heading, opens_collection(value))
+        };
+        let floor = nodes.last().map_or(start, |previous| previous.start + 1);
+        nodes.push(KeyNode {
+            path,
+            heading,
+            start: with_leading_comments(lines, idx, floor),
+            end: idx,
+            children: Vec::new(),
+        });
+        opens.push((idx, collection));
+    }
+
+    for position in 0..nodes.len() {
+        let (header, collection) = opens[position];
+        let body_end = nodes.get(position + 1).map_or(end, |next| next.start - 1);
+        if collection && header < body_end {
+            let nested = children(lines, &nodes[position], header + 1, body_end);
+            nodes[position].children = nested;
+        }
+    }
+    nodes
+}
+
+/// Line ranges of the documents in a stream, without their `---`/`...` markers.
+pub(super) fn documents(lines: &[&str]) -> Vec<(usize, usize)> {
+    let mut documents = Vec::new();
+    let mut start = 0;
+    for idx in 0..=lines.len() {
+        if idx == lines.len() || is_document_marker(lines[idx]) {
+            if idx > start && (start..idx).any(|line| is_content(lines[line])) {
+                documents.push((start, idx - 1));
+            }
+            start = idx + 1;
+        }
+    }
+    documents
+}
+
+fn parse_yaml_chunks(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let documents = documents(&lines);
+    let multiple = documents.len() > 1;
+
+    let mut roots = Vec::new();
+    // Where the next document's lines begin, `---` marker included.
+    let mut region = 0;
+    for (index, (start, end)) in documents.into_iter().enumerate() {
+        let mut root = if multiple {
+            KeyNode {
+                path: format!("[{index}]"),
+                heading: vec![format!("[{index}]")],
+                start: region,
+                end,
+                children: Vec::new(),
+            }
+        } else {
+            KeyNode {
+                path: String::new(),
+                heading: Vec::new(),
+                start: region,
+                end,
+                children: Vec::new(),
+            }
+        };
+        root.children = children(&lines, &root, start, end);
+        roots.push(root);
+        region = end + 1;
+    }
+    if roots.iter().all(|root| root.children.is_empty()) {
+        return None;
+    }
+
+    let top_key = |node: &KeyNode| {
+        let key = node.heading.last()?;
+        Some(key.split('[').next().unwrap_or(key).to_string()).filter(|key| !key.is_empty())
+    };
+    let drafts = key_node_drafts(&lines, roots, top_key, kind, options);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str, chunk_target_chars: usize) -> Vec<crate::Chunk> {
+        let options = IngestOptions {
+            chunk_target_chars,
+            ..IngestOptions::default()
+        };
+        parse_yaml_chunks("ci.yml", text, ChunkKind::Unknown, &options).expect("yaml should chunk")
+    }
+
+    fn addresses(chunks: &[crate::Chunk]) -> Vec<&str> {
+        chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect()
+    }
+
+    #[test]
+    fn top_level_keys_become_chunks_and_large_ones_split_by_key_path() {
+        let text = "\
+name: CI
+# Run on every push.
+on: [push]
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+    - uses: actions/checkout@v4
+    - name: Test
+      run: |
+        cargo test --workspace --all-features
+        cargo clippy --workspace -- -D warnings
+  lint:
+    runs-on: ubuntu-latest
+";
+        let whole = chunks(text, 4_000);
+        assert_eq!(addresses(&whole), [""]);
+        assert_eq!((whole[0].start_line, whole[0].end_line), (1, 15));
+
+        let split = chunks(text, 180);
+        assert_eq!(addresses(&split), ["name..on", "jobs.build.runs-on", "jobs.build.steps", "jobs.lint"]);
+        assert_eq!((split[0].start_line, split[0].end_line), (1, 4));
+        assert_eq!(split[0].symbol, None);
+        let steps = &split[2];
+        assert_eq!((steps.start_line, steps.end_line), (8, 13));
+        assert_eq!(steps.heading_path, ["jobs", "build", "steps"]);
+        assert_eq!(steps.symbol.as_deref(), Some("jobs"));
+        assert!(split[1].content.starts_with("jobs:\n  build:\n    runs-on"), "{}", split[2].content);
+    }
+
+    #[test]
+    fn documents_of_a_stream_are_indexed() {
+        let text = "\
+apiVersion: v1
+kind: Service
+---
+apiVersion: apps/v1
+kind: Deployment
+spec:
+  replicas: 2
+";
+        let whole = chunks(text, 4_000);
+        assert_eq!(addresses(&whole), ["[0]", "[1]"]);
+        assert!(whole[1].content.starts_with("---"));
+
+        let split = chunks(text, 30);
+        assert_eq!(addresses(&split), ["[0]", "[1].apiVersion", "[1].kind", "[1].spec"]);
+        assert_eq!(split[3].heading_path, ["[1]", "spec"]);
+        assert_eq!(split[3].symbol.as_deref(), Some("spec"));
+        assert!(split[1].content.starts_with("---"));
+    }
+
+    #[test]
+    fn small_top_level_keys_are_packed_together() {
+        let text = "\
+apiVersion: v1
+kind: Pod
+metadata:
+  name: web
+spec:
+  containers:
+  - name: web
+    image: nginx:1.27
+    ports:
+    - containerPort: 80
+  - name: sidecar
+    image: envoyproxy/envoy:v1.31
+";
+        let chunks = chunks(text, 120);
+        assert_eq!(addresses(&chunks), ["apiVersion..metadata", "spec.containers[0]", "spec.containers[1]"]);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 4));
+        assert!(chunks[0].content.contains("kind: Pod\nmetadata:"));
+        assert!(chunks[1].content.starts_with("spec:\n  containers:\n  - name: web"), "{}", chunks[1].content);
+    }
+}
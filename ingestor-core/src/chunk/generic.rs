@@ -109,12 +109,14 @@ fn draft_from_node(
         imports: Vec::new(),
         exports: Vec::new(),
         calls: Vec::new(),
+        method_calls: Vec::new(),
         type_refs: Vec::new(),
         doc_summary,
         symbol_id: None,
         symbol_tail,
         module_path,
         visibility,
+        log_level: None,
     })
 }
 
@@ -225,19 +227,27 @@ fn select_language(path: &str) -> Option<tree_sitter::Language> {
 
 #[cfg(all(test, feature = "treesitter"))]
 mod tests {
-    use crate::chunk::chunk_file;
-    use crate::{AstNodeKind, ChunkKind};
+    use super::*;
+    use crate::{AstNodeKind, ChunkKind, IngestOptions};
+
+    fn parse_generic(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: crate::util::detect_language(path),
+        };
+        GenericTreeSitterAdapter.parse(&request).expect("generic parse result")
+    }
 
     #[test]
     fn rust_function_is_structurally_chunked() {
-        let chunks = chunk_file(
-            "src/exec.rs",
-            "pub fn codex_exec(input: &str) -> bool { !input.is_empty() }",
-            ChunkKind::Unknown,
-            &crate::IngestOptions::default(),
-        );
+        let result = parse_generic("src/exec.rs", "pub fn codex_exec(input: &str) -> bool { !input.is_empty() }");
 
-        let chunk = chunks
+        let chunk = result
+            .chunks
             .iter()
             .find(|chunk| chunk.symbol.as_deref() == Some("codex_exec"))
             .expect("codex_exec chunk");
@@ -245,19 +255,15 @@ mod tests {
         assert_eq!(chunk.ast_kind, Some(AstNodeKind::Function));
         assert_eq!(chunk.qualified_name.as_deref(), Some("codex_exec"));
         assert_eq!(chunk.visibility, Some(crate::Visibility::Pub));
-        assert_eq!(chunk.resolution_tier, crate::ResolutionTier::GenericTreeSitter);
+        assert_eq!(result.resolution_tier, crate::ResolutionTier::GenericTreeSitter);
     }
 
     #[test]
     fn rust_impl_methods_get_parent_scope() {
-        let chunks = chunk_file(
-            "src/auth.rs",
-            "struct Auth; impl Auth { fn login(&self) {} }",
-            ChunkKind::Unknown,
-            &crate::IngestOptions::default(),
-        );
+        let result = parse_generic("src/auth.rs", "struct Auth; impl Auth { fn login(&self) {} }");
 
-        let method = chunks
+        let method = result
+            .chunks
             .iter()
             .find(|chunk| chunk.symbol.as_deref() == Some("login"))
             .expect("login chunk");
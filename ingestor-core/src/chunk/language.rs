@@ -19,3 +19,32 @@ pub(crate) trait LanguageAdapter {
     fn supports(&self, request: &ParseRequest<'_>) -> bool;
     fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult>;
 }
+
+/// File extension of the adapter for a language as named in notebook kernels, cell
+/// magics and Markdown fence tags (`python3`, `c++`, `ts`).
+pub(crate) fn language_extension(language: &str) -> Option<&'static str> {
+    let extension = match language.trim().to_ascii_lowercase().as_str() {
+        "python" | "python3" | "ipython" | "ipython3" | "py" => "py",
+        "javascript" | "js" | "node" | "nodejs" | "mjs" | "cjs" => "js",
+        "jsx" => "jsx",
+        "typescript" | "ts" => "ts",
+        "tsx" => "tsx",
+        "rust" | "rs" => "rs",
+        "go" | "golang" => "go",
+        "java" => "java",
+        "c" | "h" => "c",
+        "c++" | "cpp" | "cxx" | "cc" | "hpp" | "c++17" | "c++20" => "cpp",
+        "c#" | "csharp" | "cs" => "cs",
+        "bash" | "sh" | "shell" | "zsh" => "sh",
+        "sql" | "postgresql" | "postgres" | "mysql" | "sqlite" | "plpgsql" => "sql",
+        "ruby" | "rb" => "rb",
+        "html" => "html",
+        "css" => "css",
+        "scss" => "scss",
+        "less" => "less",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+        _ => return None,
+    };
+    Some(extension)
+}
@@ -1,5 +1,6 @@
 use crate::model::{Chunk, ChunkKind, IngestOptions};
-use crate::util::{estimate_tokens, sha256_hex, short_id, slugify};
+use super::language::language_extension;
+use crate::util::{detect_kind, estimate_tokens, sha256_hex, short_id, slugify};
 use regex::Regex;
 use std::collections::BTreeMap;
 use std::sync::OnceLock;
@@ -21,6 +22,18 @@ fn html_tag_re() -> &'static Regex {
     RE.get_or_init(|| Regex::new(r"<[^>]+>").expect("html tag regex"))
 }
 
+fn html_embed_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)<(script|style)\b([^>]*)>").expect("html embed regex"))
+}
+
+fn html_attr_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"([A-Za-z_:][-A-Za-z0-9_:.]*)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).expect("html attribute regex")
+    })
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct ChunkDraft {
     pub(crate) kind: ChunkKind,
@@ -38,12 +51,14 @@ pub(crate) struct ChunkDraft {
     pub(crate) imports: Vec<String>,
     pub(crate) exports: Vec<String>,
     pub(crate) calls: Vec<String>,
+    pub(crate) method_calls: Vec<String>,
     pub(crate) type_refs: Vec<String>,
     pub(crate) doc_summary: Option<String>,
     pub(crate) symbol_id: Option<String>,
     pub(crate) symbol_tail: Option<String>,
     pub(crate) module_path: Option<String>,
     pub(crate) visibility: Option<crate::Visibility>,
+    pub(crate) log_level: Option<crate::LogLevel>,
 }
 
 impl ChunkDraft {
@@ -52,18 +67,36 @@ impl ChunkDraft {
         Self {
             kind, start_line, end_line, content, heading_path, symbol, address,
             ast_kind: None, qualified_name: None, signature: None, parent_symbol: None,
-            imports: Vec::new(), exports: Vec::new(), calls: Vec::new(), type_refs: Vec::new(),
+            imports: Vec::new(), exports: Vec::new(), calls: Vec::new(), method_calls: Vec::new(),
+            type_refs: Vec::new(),
             doc_summary: None, symbol_id: None, symbol_tail: None, module_path: None, visibility: None,
+            log_level: None,
+        }
+    }
+}
+
+impl From<Chunk> for ChunkDraft {
+    /// Re-open a finished chunk, e.g. one parsed from an embedded document, so it can be
+    /// placed and finalized under another file.
+    fn from(chunk: Chunk) -> Self {
+        Self {
+            kind: chunk.kind, start_line: chunk.start_line, end_line: chunk.end_line, content: chunk.content,
+            heading_path: chunk.heading_path, symbol: chunk.symbol, address: chunk.address,
+            ast_kind: chunk.ast_kind, qualified_name: chunk.qualified_name, signature: chunk.signature,
+            parent_symbol: chunk.parent_symbol, imports: chunk.imports, exports: chunk.exports, calls: chunk.calls,
+            method_calls: chunk.method_calls, type_refs: chunk.type_refs, doc_summary: chunk.doc_summary,
+            symbol_id: chunk.symbol_id, symbol_tail: chunk.symbol_tail, module_path: chunk.module_path,
+            visibility: chunk.visibility, log_level: chunk.log_level,
         }
     }
 }
 
 pub(crate) fn chunk_file(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Vec<Chunk> {
     let drafts = match kind {
-        ChunkKind::Markdown => chunk_markdown(text, options),
+        ChunkKind::Markdown => chunk_markdown(path, text, options),
         ChunkKind::Json => chunk_json(text, options),
         ChunkKind::JavaScript => chunk_javascript(path, text, options),
-        ChunkKind::Html => chunk_html(text, options),
+        ChunkKind::Html => chunk_html(path, text, options),
         ChunkKind::Image => chunk_image(path),
         ChunkKind::Text | ChunkKind::Unknown => chunk_text(text, options),
     };
@@ -114,6 +147,7 @@ pub(crate) fn finalize_chunks(path: &str, drafts: Vec<ChunkDraft>) -> Vec<Chunk>
             is_generated: false,
             quality_score: None,
             resolution_tier: crate::ResolutionTier::TextOnly,
+            log_level: draft.log_level,
             // Phase 7: structural metadata from tree-sitter enrichment
             ast_kind: draft.ast_kind,
             qualified_name: draft.qualified_name,
@@ -126,6 +160,7 @@ pub(crate) fn finalize_chunks(path: &str, drafts: Vec<ChunkDraft>) -> Vec<Chunk>
             imports: draft.imports,
             exports: draft.exports,
             calls: draft.calls,
+            method_calls: draft.method_calls,
             type_refs: draft.type_refs,
             doc_summary: draft.doc_summary,
         });
@@ -147,13 +182,15 @@ fn chunk_image(path: &str) -> Vec<ChunkDraft> {
     )]
 }
 
-fn chunk_markdown(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
+pub(crate) fn chunk_markdown(path: &str, text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
     let mut drafts = Vec::new();
     let mut buf: Vec<String> = Vec::new();
     let mut heading_stack: Vec<String> = Vec::new();
     let mut current_heading = heading_stack.clone();
     let mut start_line = 1;
     let mut in_fence = false;
+    // Open fence: the adapter extension for its tag, its line and its index in `buf`.
+    let mut fence: Option<(Option<&'static str>, usize, usize)> = None;
     let heading_re = markdown_heading_re();
 
     for (idx, line) in text.lines().enumerate() {
@@ -161,6 +198,11 @@ fn chunk_markdown(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
         let trimmed = line.trim();
         if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
             in_fence = !in_fence;
+            if in_fence {
+                let tag = trimmed.trim_start_matches(['`', '~']).trim_start_matches(['{', '.']);
+                let tag = tag.split(|ch: char| ch.is_whitespace() || ch == ',' || ch == '}').next().unwrap_or("");
+                fence = Some((language_extension(tag), line_no, buf.len()));
+            }
         }
 
         if !in_fence {
@@ -184,6 +226,26 @@ fn chunk_markdown(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
         }
 
         buf.push(line.to_string());
+        if let Some((Some(extension), fence_line, fence_at)) = fence.take_if(|_| !in_fence) {
+            let code = buf[fence_at + 1..buf.len() - 1].join("\n");
+            let code_drafts = fenced_code_drafts(path, &code, extension, fence_line, &current_heading, options);
+            if !code_drafts.is_empty() {
+                buf.truncate(fence_at);
+                if buf.iter().all(|line| line.trim().is_empty()) {
+                    buf.clear();
+                }
+                flush_chunk(&mut drafts, &mut buf, &current_heading, ChunkFlushParams {
+                    start_line,
+                    end_line: fence_line - 1,
+                    kind: ChunkKind::Markdown,
+                    symbol: None,
+                    address: None,
+                });
+                drafts.extend(code_drafts);
+                start_line = line_no + 1;
+                continue;
+            }
+        }
         if !in_fence && buffer_len(&buf) >= options.chunk_max_chars {
             flush_chunk(&mut drafts, &mut buf, &current_heading, ChunkFlushParams {
                 start_line,
@@ -206,7 +268,49 @@ fn chunk_markdown(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
     drafts
 }
 
-fn chunk_text(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
+/// A fenced code block parsed by the adapter for its language tag, placed at the
+/// fence's lines under the section heading. Empty when the block declares nothing,
+/// so that plain usage snippets stay with the surrounding prose.
+fn fenced_code_drafts(
+    path: &str,
+    code: &str,
+    extension: &str,
+    fence_line: usize,
+    heading_path: &[String],
+    options: &IngestOptions,
+) -> Vec<ChunkDraft> {
+    let drafts = embedded_code_drafts(path, code, extension, fence_line, heading_path, options);
+    if !drafts.iter().any(|draft| draft.ast_kind.is_some()) {
+        return Vec::new();
+    }
+    drafts
+}
+
+/// Code embedded in another document, parsed as a file of its own language next to
+/// it (`page.html` → `page.js`) and shifted down by `line_offset` lines.
+pub(crate) fn embedded_code_drafts(
+    path: &str,
+    code: &str,
+    extension: &str,
+    line_offset: usize,
+    heading_path: &[String],
+    options: &IngestOptions,
+) -> Vec<ChunkDraft> {
+    let code_path = std::path::Path::new(path).with_extension(extension);
+    let code_path = code_path.to_string_lossy();
+    super::chunk_file(&code_path, code, detect_kind(&code_path), options)
+        .into_iter()
+        .map(|chunk| ChunkDraft {
+            start_line: chunk.start_line + line_offset,
+            end_line: chunk.end_line + line_offset,
+            heading_path: heading_path.to_vec(),
+            module_path: None,
+            ..ChunkDraft::from(chunk)
+        })
+        .collect()
+}
+
+pub(crate) fn chunk_text(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
     let mut drafts = Vec::new();
     let mut buf: Vec<String> = Vec::new();
     let mut start_line = 1;
@@ -399,7 +503,84 @@ fn should_skip_html_line(line: &str) -> bool {
     false
 }
 
-fn chunk_html(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
+/// An inline `<script>` or `<style>` element of an HTML page.
+pub(crate) struct EmbeddedBlock {
+    pub(crate) tag: String,
+    pub(crate) attrs: String,
+    /// Lines of the opening and closing tags, 1-based.
+    pub(crate) open_line: usize,
+    pub(crate) close_line: usize,
+    /// Text between the tags, and the number of page lines above its first line.
+    pub(crate) body: String,
+    pub(crate) line_offset: usize,
+    /// Adapter extension for the body; `None` for data blocks such as JSON-LD.
+    pub(crate) extension: Option<&'static str>,
+    pub(crate) src: Option<String>,
+}
+
+fn html_attr(attrs: &str, name: &str) -> Option<String> {
+    html_attr_re().captures_iter(attrs).find_map(|caps| {
+        if !caps[1].eq_ignore_ascii_case(name) {
+            return None;
+        }
+        let value = caps.get(2).or(caps.get(3)).or(caps.get(4))?;
+        Some(value.as_str().trim().to_string())
+    })
+}
+
+pub(crate) fn embedded_blocks(text: &str) -> Vec<EmbeddedBlock> {
+    let lower = text.to_ascii_lowercase();
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let line_at = |byte: usize| line_starts.partition_point(|start| *start <= byte);
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some(caps) = html_embed_re().captures_at(text, pos) {
+        let open = caps.get(0).unwrap();
+        let tag = caps[1].to_ascii_lowercase();
+        let attrs = caps.get(2).map_or("", |attrs| attrs.as_str());
+        let body_start = open.end();
+        let (body_end, close_end) = match lower[body_start..].find(&format!("</{tag}")) {
+            Some(offset) => {
+                let body_end = body_start + offset;
+                let close_end = lower[body_end..].find('>').map_or(text.len(), |gt| body_end + gt + 1);
+                (body_end, close_end)
+            }
+            None => (text.len(), text.len()),
+        };
+        let lang = html_attr(attrs, "lang").and_then(|lang| language_extension(&lang));
+        let extension = if tag == "style" {
+            Some(lang.unwrap_or("css"))
+        } else {
+            match html_attr(attrs, "type").map(|kind| kind.to_ascii_lowercase()).as_deref() {
+                None | Some("" | "module" | "text/javascript" | "application/javascript" | "text/babel") => {
+                    Some(lang.unwrap_or("js"))
+                }
+                Some("text/typescript" | "application/typescript") => Some("ts"),
+                _ => None,
+            }
+        };
+        blocks.push(EmbeddedBlock {
+            src: html_attr(attrs, "src").filter(|_| tag == "script"),
+            tag,
+            attrs: attrs.trim().to_string(),
+            open_line: line_at(open.start()),
+            close_line: line_at(close_end.max(body_start + 1) - 1),
+            body: text[body_start..body_end].to_string(),
+            line_offset: line_at(body_start) - 1,
+            extension,
+        });
+        pos = close_end.max(body_start);
+    }
+    blocks
+}
+
+/// Visible text chunked by heading. Inline scripts and styles are parsed by their
+/// language's adapter in place, under the section they appear in, and `src=`
+/// script references become imports of that section's text chunk, or of a chunk
+/// of their own tags when the section has no text, as a `<head>` often does.
+fn chunk_html(path: &str, text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
     let mut drafts = Vec::new();
     let mut buf: Vec<String> = Vec::new();
     let mut heading_stack: Vec<String> = Vec::new();
@@ -407,30 +588,48 @@ fn chunk_html(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
     let mut start_line = 1;
     let heading_re = html_heading_re();
     let tag_re = html_tag_re();
-    let mut in_script = false;
-    let mut in_style = false;
+    let blocks = embedded_blocks(text);
+    let mut next_block = 0;
+    // `src=` references, the section they appear in, and the lines of their tags.
+    let mut script_srcs: Vec<(String, Vec<String>, usize, usize)> = Vec::new();
 
     for (idx, raw_line) in text.lines().enumerate() {
         let line_no = idx + 1;
-        let mut line = raw_line.to_string();
-        let lower = line.to_ascii_lowercase();
-        if lower.contains("<script") {
-            in_script = true;
-        }
-        if lower.contains("</script>") {
-            in_script = false;
-            continue;
-        }
-        if lower.contains("<style") {
-            in_style = true;
-        }
-        if lower.contains("</style>") {
-            in_style = false;
-            continue;
+        let mut in_block = false;
+        while let Some(block) = blocks.get(next_block).filter(|block| block.open_line <= line_no) {
+            in_block = true;
+            if block.open_line == line_no {
+                flush_chunk(&mut drafts, &mut buf, &current_heading, ChunkFlushParams {
+                    start_line,
+                    end_line: line_no - 1,
+                    kind: ChunkKind::Html,
+                    symbol: None,
+                    address: None,
+                });
+                if let Some(src) = &block.src {
+                    script_srcs.push((src.clone(), current_heading.clone(), block.open_line, block.close_line));
+                }
+                if let Some(extension) = block.extension.filter(|_| !block.body.trim().is_empty()) {
+                    drafts.extend(embedded_code_drafts(
+                        path,
+                        &block.body,
+                        extension,
+                        block.line_offset,
+                        &current_heading,
+                        options,
+                    ));
+                }
+            }
+            start_line = line_no + 1;
+            if block.close_line > line_no {
+                break;
+            }
+            next_block += 1;
         }
-        if in_script || in_style {
+        if in_block {
             continue;
         }
+        let mut line = raw_line.to_string();
 
         if let Some(caps) = heading_re.captures(&line) {
             flush_chunk(&mut drafts, &mut buf, &current_heading, ChunkFlushParams {
@@ -476,6 +675,41 @@ fn chunk_html(text: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
         symbol: None,
         address: None,
     });
+
+    let lines: Vec<&str> = text.lines().collect();
+    let mut tag_drafts: Vec<ChunkDraft> = Vec::new();
+    for (src, heading, open_line, close_line) in script_srcs {
+        let section = |draft: &ChunkDraft| draft.kind == ChunkKind::Html && draft.heading_path == heading;
+        // The section's chunk at or above the tag, else its first one.
+        let target = drafts
+            .iter()
+            .rposition(|draft| section(draft) && draft.start_line <= open_line)
+            .or_else(|| drafts.iter().position(section));
+        let draft = match target {
+            Some(target) => &mut drafts[target],
+            None => {
+                let tags = lines[open_line - 1..close_line.min(lines.len())].join("\n");
+                match tag_drafts.last_mut().filter(|draft| draft.heading_path == heading) {
+                    Some(draft) => {
+                        draft.end_line = close_line;
+                        draft.content.push('\n');
+                        draft.content.push_str(tags.trim());
+                    }
+                    None => tag_drafts.push(ChunkDraft::plain(
+                        ChunkKind::Html, open_line, close_line, tags.trim().to_string(), heading, None, None,
+                    )),
+                }
+                tag_drafts.last_mut().unwrap()
+            }
+        };
+        if !draft.imports.contains(&src) {
+            draft.imports.push(src);
+        }
+    }
+    for draft in tag_drafts {
+        let at = drafts.partition_point(|other| other.start_line <= draft.start_line);
+        drafts.insert(at, draft);
+    }
     drafts
 }
 
@@ -690,7 +924,7 @@ fn enriched_draft_from_node(
     text: &str,
     node: Node,
     parent_name: Option<&str>,
-    file_imports: &[String],
+    file_imports: &[(String, String)],
 ) -> Option<ChunkDraft> {
     use crate::model::AstNodeKind;
 
@@ -732,7 +966,7 @@ fn enriched_draft_from_node(
     let signature = extract_signature(effective_node, text);
 
     // Extract calls within this node
-    let calls = extract_calls(effective_node, text);
+    let (calls, method_calls) = extract_calls(effective_node, text);
 
     // Extract type references (TS type annotations, generic params)
     let type_refs = extract_type_refs(effective_node, text);
@@ -749,11 +983,12 @@ fn enriched_draft_from_node(
 
     // Filter file-level imports to identifiers actually referenced in this AST node.
     let referenced_identifiers = extract_identifier_refs(effective_node, text);
-    let imports: Vec<String> = file_imports
-        .iter()
-        .filter(|imp| referenced_identifiers.contains(imp.as_str()))
-        .cloned()
-        .collect();
+    let mut imports: Vec<String> = Vec::new();
+    for (local, path) in file_imports {
+        if referenced_identifiers.contains(local.as_str()) && !imports.contains(path) {
+            imports.push(path.clone());
+        }
+    }
 
     // For classes, recurse into members
     if matches!(ast_kind, AstNodeKind::Class | AstNodeKind::Interface) {
@@ -776,12 +1011,14 @@ fn enriched_draft_from_node(
         imports,
         exports,
         calls,
+        method_calls,
         type_refs,
         doc_summary,
         symbol_id: None,
         symbol_tail: None,
         module_path: None,
         visibility: None,
+        log_level: None,
     })
 }
 
@@ -790,7 +1027,7 @@ fn enriched_draft_from_node(
 fn collect_class_member_drafts(
     node: Node,
     text: &str,
-    file_imports: &[String],
+    file_imports: &[(String, String)],
     drafts: &mut Vec<ChunkDraft>,
 ) {
     let effective_node = if node.kind() == "export_statement" {
@@ -958,13 +1195,16 @@ fn extract_signature(node: Node, text: &str) -> Option<String> {
     }
 }
 
-/// Walk a node tree and collect all call_expression callee names.
+/// Walk a node tree and collect all call_expression callee names, plus the ones only
+/// ever called on a receiver.
 #[cfg(feature = "treesitter")]
-fn extract_calls(node: Node, text: &str) -> Vec<String> {
+fn extract_calls(node: Node, text: &str) -> (Vec<String>, Vec<String>) {
     let mut calls = Vec::new();
+    let mut free = std::collections::HashSet::new();
     let mut seen = std::collections::HashSet::new();
-    collect_calls_recursive(node, text, &mut calls, &mut seen);
-    calls
+    collect_calls_recursive(node, text, &mut calls, &mut free, &mut seen);
+    let methods = calls.iter().filter(|call| !free.contains(*call)).cloned().collect();
+    (calls, methods)
 }
 
 #[cfg(feature = "treesitter")]
@@ -972,6 +1212,7 @@ fn collect_calls_recursive(
     node: Node,
     text: &str,
     calls: &mut Vec<String>,
+    free: &mut std::collections::HashSet<String>,
     seen: &mut std::collections::HashSet<String>,
 ) {
     if node.kind() == "call_expression" {
@@ -981,6 +1222,9 @@ fn collect_calls_recursive(
                 .trim();
             // Normalize: take the last segment for member expressions
             let name = callee_text.rsplit('.').next().unwrap_or(callee_text);
+            if callee.kind() != "member_expression" {
+                free.insert(name.to_string());
+            }
             if !name.is_empty() && name.len() < 100 && seen.insert(name.to_string()) {
                 calls.push(name.to_string());
             }
@@ -988,7 +1232,7 @@ fn collect_calls_recursive(
     }
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        collect_calls_recursive(child, text, calls, seen);
+        collect_calls_recursive(child, text, calls, free, seen);
     }
 }
 
@@ -1006,7 +1250,7 @@ fn collect_identifier_refs_recursive(
     text: &str,
     refs: &mut std::collections::HashSet<String>,
 ) {
-    if node.kind() == "identifier" {
+    if matches!(node.kind(), "identifier" | "shorthand_property_identifier") {
         if let Some(name) = text.get(node.start_byte()..node.end_byte()) {
             if !name.is_empty() {
                 refs.insert(name.to_string());
@@ -1093,9 +1337,10 @@ fn extract_doc_comment(node: Node, text: &str) -> Option<String> {
     }
 }
 
-/// Collect all import specifiers at file scope.
+/// Collect all import bindings at file scope as `(local name, specifier#name)`.
+/// Default imports bind `specifier#default`, namespace imports `specifier#*`.
 #[cfg(feature = "treesitter")]
-fn collect_file_imports(root: Node, text: &str) -> Vec<String> {
+fn collect_file_imports(root: Node, text: &str) -> Vec<(String, String)> {
     let mut imports = Vec::new();
     let mut cursor = root.walk();
     for child in root.children(&mut cursor) {
@@ -1115,9 +1360,21 @@ fn collect_file_imports(root: Node, text: &str) -> Vec<String> {
     imports
 }
 
-/// Extract imported names from an import statement node.
+/// Extract imported bindings from an import statement node.
 #[cfg(feature = "treesitter")]
-fn collect_import_names(node: Node, text: &str, imports: &mut Vec<String>) {
+fn collect_import_names(node: Node, text: &str, imports: &mut Vec<(String, String)>) {
+    let Some(specifier) = node
+        .child_by_field_name("source")
+        .and_then(|source| text.get(source.start_byte()..source.end_byte()))
+        .map(|source| source.trim_matches(|ch| matches!(ch, '"' | '\'' | '`')))
+    else {
+        return;
+    };
+    let mut bind = |local: Node, name: &str| {
+        if let Some(local) = text.get(local.start_byte()..local.end_byte()) {
+            imports.push((local.to_string(), format!("{specifier}#{name}")));
+        }
+    };
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
@@ -1125,32 +1382,30 @@ fn collect_import_names(node: Node, text: &str, imports: &mut Vec<String>) {
                 let mut clause_cursor = child.walk();
                 for cc in child.children(&mut clause_cursor) {
                     match cc.kind() {
-                        "identifier" => {
-                            if let Some(name) = text.get(cc.start_byte()..cc.end_byte()) {
-                                imports.push(name.to_string());
-                            }
-                        }
+                        "identifier" => bind(cc, "default"),
                         "named_imports" => {
                             let mut named_cursor = cc.walk();
                             for spec in cc.children(&mut named_cursor) {
                                 if spec.kind() == "import_specifier" {
+                                    let Some(name_node) = spec.child_by_field_name("name") else {
+                                        continue;
+                                    };
+                                    let Some(name) = text.get(name_node.start_byte()..name_node.end_byte()) else {
+                                        continue;
+                                    };
                                     // Use alias if present, otherwise the name
-                                    let alias = spec.child_by_field_name("alias")
-                                        .or_else(|| spec.child_by_field_name("name"));
-                                    if let Some(name_node) = alias {
-                                        if let Some(name) = text.get(name_node.start_byte()..name_node.end_byte()) {
-                                            imports.push(name.to_string());
-                                        }
-                                    }
+                                    bind(spec.child_by_field_name("alias").unwrap_or(name_node), name);
                                 }
                             }
                         }
                         "namespace_import" => {
                             // import * as Foo
-                            if let Some(name_node) = cc.child_by_field_name("name") {
-                                if let Some(name) = text.get(name_node.start_byte()..name_node.end_byte()) {
-                                    imports.push(name.to_string());
-                                }
+                            let mut namespace_cursor = cc.walk();
+                            let local = cc
+                                .child_by_field_name("name")
+                                .or_else(|| cc.named_children(&mut namespace_cursor).find(|n| n.kind() == "identifier"));
+                            if let Some(local) = local {
+                                bind(local, "*");
                             }
                         }
                         _ => {}
@@ -1454,12 +1709,12 @@ export const handleRequest = (req: Request) => {
             .expect("Should find handleRequest chunk");
 
         assert!(
-            handler_chunk.imports.iter().any(|name| name == "parseBody"),
+            handler_chunk.imports.iter().any(|name| name == "./helpers#parseBody"),
             "Should attribute exact imported identifier: {:?}",
             handler_chunk.imports
         );
         assert!(
-            !handler_chunk.imports.iter().any(|name| name == "it"),
+            !handler_chunk.imports.iter().any(|name| name == "./helpers#it"),
             "Should not attribute substring-only import matches: {:?}",
             handler_chunk.imports
         );
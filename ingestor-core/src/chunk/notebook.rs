@@ -0,0 +1,220 @@
+use super::language::{language_extension, LanguageAdapter, ParseRequest, ParseResult};
+use super::legacy::{chunk_markdown, chunk_text, finalize_chunks, ChunkDraft};
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use crate::util::detect_kind;
+use serde_json::Value;
+
+/// Jupyter notebooks: one or more chunks per cell, addressed `cell[12]` by the cell's
+/// index in the notebook. Code cells are parsed by the adapter for the kernel's
+/// language (or the cell's `%%bash`-style magic); markdown cells keep their heading
+/// path, which carries over to the cells below it. Outputs are dropped unless
+/// `IngestOptions::notebook_outputs` is set.
+pub(crate) struct NotebookAdapter;
+
+impl LanguageAdapter for NotebookAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        None
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        // Cells share one file, so scope rules keyed by path cannot bind their imports.
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Other("ipynb".to_string()))
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_notebook_chunks(request.path, request.text, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// `source` and output `text` fields are either a string or a list of lines.
+fn joined(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(lines)) => lines.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+/// The language a cell magic on the first line switches to, e.g. `%%bash`.
+fn cell_magic(source: &str) -> Option<&'static str> {
+    let first = source.lines().next()?.trim();
+    let magic = first.strip_prefix("%%")?;
+    let mut words = magic.split_whitespace();
+    match words.next()? {
+        "script" => language_extension(words.next()?),
+        name => language_extension(name),
+    }
+}
+
+/// The notebook's default cell language, from its kernel metadata.
+fn kernel_extension(notebook: &Value) -> &'static str {
+    let metadata = &notebook["metadata"];
+    [&metadata["kernelspec"]["language"], &metadata["language_info"]["name"]]
+        .into_iter()
+        .filter_map(Value::as_str)
+        .find_map(language_extension)
+        .unwrap_or("py")
+}
+
+/// Text of a code cell's outputs: streams, plain-text results and errors.
+fn output_text(cell: &Value) -> String {
+    let mut parts = Vec::new();
+    for output in cell["outputs"].as_array().into_iter().flatten() {
+        let text = match output["output_type"].as_str() {
+            Some("stream") => joined(output.get("text")),
+            Some("execute_result" | "display_data") => joined(output["data"].get("text/plain")),
+            Some("error") => format!(
+                "{}: {}",
+                output["ename"].as_str().unwrap_or("Error"),
+                output["evalue"].as_str().unwrap_or("")
+            ),
+            _ => String::new(),
+        };
+        if !text.trim().is_empty() {
+            parts.push(text.trim_end().to_string());
+        }
+    }
+    parts.join("\n")
+}
+
+/// Code cells parse as a file of their own language next to the notebook
+/// (`analysis.ipynb` → `analysis.py`), then take the notebook's place again.
+fn code_cell_drafts(path: &str, source: &str, extension: &str, options: &IngestOptions) -> Vec<ChunkDraft> {
+    let stem = path.strip_suffix(".ipynb").unwrap_or(path);
+    let cell_path = format!("{stem}.{extension}");
+    super::chunk_file(&cell_path, source, detect_kind(&cell_path), options)
+        .into_iter()
+        .map(ChunkDraft::from)
+        .collect()
+}
+
+fn parse_notebook_chunks(path: &str, text: &str, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let notebook: Value = serde_json::from_str(text).ok()?;
+    let cells = notebook["cells"].as_array()?;
+    let kernel = kernel_extension(&notebook);
+
+    let mut drafts = Vec::new();
+    // Heading path of the last markdown heading, inherited by the cells after it.
+    let mut section: Vec<String> = Vec::new();
+    for (index, cell) in cells.iter().enumerate() {
+        let source = joined(cell.get("source"));
+        if source.trim().is_empty() {
+            continue;
+        }
+        let mut cell_drafts = match cell["cell_type"].as_str() {
+            Some("markdown") => chunk_markdown(path, &source, options),
+            Some("code") => {
+                let vscode_language = cell["metadata"]["vscode"]["languageId"].as_str().and_then(language_extension);
+                match cell_magic(&source) {
+                    Some(extension) => {
+                        // Parse the body without the magic line, keeping cell line numbers.
+                        let body = source.split_once('\n').map_or("", |(_, body)| body);
+                        let mut drafts = code_cell_drafts(path, body, extension, options);
+                        for draft in &mut drafts {
+                            draft.start_line += 1;
+                            draft.end_line += 1;
+                        }
+                        drafts
+                    }
+                    None => code_cell_drafts(path, &source, vscode_language.unwrap_or(kernel), options),
+                }
+            }
+            _ => chunk_text(&source, options),
+        };
+
+        let address = format!("cell[{index}]");
+        for draft in &mut cell_drafts {
+            if draft.kind == ChunkKind::Markdown && !draft.heading_path.is_empty() {
+                section = draft.heading_path.clone();
+            } else if draft.heading_path.is_empty() {
+                draft.heading_path = section.clone();
+            }
+            draft.address = Some(address.clone());
+            draft.module_path = None;
+        }
+        drafts.extend(cell_drafts);
+
+        if options.notebook_outputs {
+            let outputs = output_text(cell);
+            if !outputs.is_empty() {
+                for mut draft in chunk_text(&outputs, options) {
+                    draft.heading_path = section.clone();
+                    draft.address = Some(format!("{address}.outputs"));
+                    drafts.push(draft);
+                }
+            }
+        }
+    }
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTEBOOK: &str = r##"{
+ "metadata": {"kernelspec": {"language": "python", "name": "python3"}},
+ "nbformat": 4,
+ "cells": [
+  {"cell_type": "markdown", "metadata": {}, "source": ["# Setup\n", "Fetch the data first."]},
+  {"cell_type": "code", "metadata": {}, "execution_count": 1, "outputs": [],
+   "source": ["%%bash\n", "fetch() {\n", "  curl -sO \"$1\"\n", "}\n", "fetch https://example.com/data.csv\n"]},
+  {"cell_type": "code", "metadata": {}, "source": []},
+  {"cell_type": "code", "metadata": {}, "execution_count": 2,
+   "outputs": [{"output_type": "stream", "name": "stdout", "text": ["rows: 120\n"]}],
+   "source": "import csv\nprint('rows:', sum(1 for _ in open('data.csv')))"}
+ ]
+}"##;
+
+    fn chunks(options: &IngestOptions) -> Vec<crate::Chunk> {
+        parse_notebook_chunks("eda.ipynb", NOTEBOOK, options).expect("notebook should chunk")
+    }
+
+    #[test]
+    fn cells_become_chunks_addressed_by_index() {
+        let chunks = chunks(&IngestOptions::default());
+        let addresses: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(addresses, ["cell[0]", "cell[1]", "cell[1]", "cell[3]"]);
+        assert!(chunks.iter().all(|chunk| chunk.path == "eda.ipynb"));
+
+        assert_eq!(chunks[0].kind, ChunkKind::Markdown);
+        assert_eq!(chunks[0].heading_path, ["Setup"]);
+
+        // `%%bash` cells go through the shell adapter, with lines counted from the magic.
+        let function = &chunks[1];
+        assert_eq!(function.symbol.as_deref(), Some("fetch"));
+        assert_eq!((function.start_line, function.end_line), (2, 4));
+        assert_eq!(function.heading_path, ["Setup"]);
+
+        assert!(chunks[3].content.starts_with("import csv"));
+        assert!(!chunks.iter().any(|chunk| chunk.content.contains("rows: 120")));
+    }
+
+    #[test]
+    fn outputs_are_kept_on_request() {
+        let options = IngestOptions {
+            notebook_outputs: true,
+            ..IngestOptions::default()
+        };
+        let chunks = chunks(&options);
+        let output = chunks.last().expect("output chunk");
+        assert_eq!(output.address.as_deref(), Some("cell[3].outputs"));
+        assert_eq!(output.content, "rows: 120");
+    }
+
+    #[test]
+    fn invalid_notebooks_are_left_to_the_text_chunker() {
+        assert!(parse_notebook_chunks("broken.ipynb", "{\"cells\": [", &IngestOptions::default()).is_none());
+    }
+}
@@ -0,0 +1,160 @@
+//! Dedicated language adapters backed by compiled query packs (Parser Tier 2).
+//!
+//! Each submodule owns one language: definitions come from a structural walk of
+//! the syntax tree, while imports, calls and type references come from the
+//! `.scm` packs loaded by [`super::query_loader`]. Rust and TypeScript output is
+//! additionally bound by the scope resolver in `crate::resolve` (Parser Tier 1).
+
+mod cpp;
+mod csharp;
+mod go;
+mod java;
+mod python;
+mod rust;
+mod typescript;
+
+pub(crate) use cpp::CppAdapter;
+pub(crate) use csharp::CSharpAdapter;
+pub(crate) use go::{GoAdapter, GoModules};
+pub(crate) use java::JavaAdapter;
+pub(crate) use python::PythonAdapter;
+pub(crate) use rust::RustAdapter;
+pub(crate) use typescript::TypeScriptAdapter;
+
+#[cfg(feature = "treesitter")]
+use std::collections::HashSet;
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// An import-introduced binding: the local name in scope and the full path it stands for.
+#[cfg(feature = "treesitter")]
+struct ImportBinding {
+    local: String,
+    path: String,
+}
+
+/// Paths of the file-level imports whose local name is referenced inside `node`.
+#[cfg(feature = "treesitter")]
+fn referenced_imports(bindings: &[ImportBinding], node: Node, text: &str, kinds: &[&str]) -> Vec<String> {
+    let referenced = identifier_refs(node, text, kinds);
+    let mut imports = Vec::new();
+    for binding in bindings {
+        if referenced.contains(&binding.local) {
+            push_unique(&mut imports, &binding.path);
+        }
+    }
+    imports
+}
+
+/// Return the source text covered by `node`.
+#[cfg(feature = "treesitter")]
+fn node_text<'a>(node: Node, text: &'a str) -> Option<&'a str> {
+    text.get(node.start_byte()..node.end_byte())
+}
+
+/// Push `value` unless it is empty, oversized, or already present.
+#[cfg(feature = "treesitter")]
+fn push_unique(values: &mut Vec<String>, value: &str) {
+    let value = value.trim();
+    if !value.is_empty() && value.len() < 100 && !values.iter().any(|existing| existing == value) {
+        values.push(value.to_string());
+    }
+}
+
+/// Collect the text of every descendant of `node` whose kind is in `kinds`.
+#[cfg(feature = "treesitter")]
+fn identifier_refs(node: Node, text: &str, kinds: &[&str]) -> HashSet<String> {
+    let mut refs = HashSet::new();
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        if kinds.contains(&current.kind()) {
+            if let Some(name) = node_text(current, text) {
+                refs.insert(name.to_string());
+            }
+        }
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor));
+    }
+    refs
+}
+
+/// Reduce doc-comment lines to their first sentence, capped at 200 chars.
+#[cfg(feature = "treesitter")]
+fn summarize_doc(lines: &[String]) -> Option<String> {
+    let joined = lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let sentence = joined.split(". ").next().unwrap_or(&joined).trim();
+    if sentence.is_empty() {
+        None
+    } else {
+        Some(sentence.chars().take(200).collect())
+    }
+}
+
+/// Summarize the comments stacked directly above `node` (no blank line in between).
+#[cfg(feature = "treesitter")]
+fn leading_doc_comment(node: Node, text: &str, comment_kinds: &[&str]) -> Option<String> {
+    let mut comments = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut cursor = node.prev_sibling();
+    while let Some(prev) = cursor {
+        if !comment_kinds.contains(&prev.kind()) || prev.end_position().row + 1 < expected_row {
+            break;
+        }
+        comments.push(node_text(prev, text)?);
+        expected_row = prev.start_position().row;
+        cursor = prev.prev_sibling();
+    }
+    let lines: Vec<String> = comments.iter().rev().flat_map(|raw| comment_lines(raw)).collect();
+    summarize_doc(&lines)
+}
+
+/// Strip comment markers (`///`, `/** */`, leading `*`), XML doc tags and `@tag` lines.
+#[cfg(feature = "treesitter")]
+fn comment_lines(raw: &str) -> Vec<String> {
+    let raw = raw.trim();
+    let body = raw
+        .strip_prefix("/*")
+        .map(|inner| inner.trim_end_matches("*/"))
+        .unwrap_or(raw);
+    body.lines()
+        .map(|line| line.trim().trim_start_matches('/').trim_start_matches('*').trim())
+        .filter(|line| !line.starts_with('@'))
+        .map(|line| {
+            let mut cleaned = String::with_capacity(line.len());
+            let mut in_tag = false;
+            for ch in line.chars() {
+                match ch {
+                    '<' => in_tag = true,
+                    '>' if in_tag => in_tag = false,
+                    _ if !in_tag => cleaned.push(ch),
+                    _ => {}
+                }
+            }
+            cleaned.trim().to_string()
+        })
+        .collect()
+}
+
+/// Collapse a declaration header to a single line, stopping before `body_start`.
+#[cfg(feature = "treesitter")]
+fn header_signature(node: Node, text: &str, body_start: Option<usize>) -> Option<String> {
+    let end = body_start.unwrap_or_else(|| node.end_byte());
+    let raw = text.get(node.start_byte()..end)?;
+    let raw = if body_start.is_some() {
+        raw
+    } else {
+        raw.lines().find(|line| !line.trim().is_empty()).unwrap_or("")
+    };
+    let collapsed = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    let trimmed = collapsed.trim_end_matches(['{', ';']).trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
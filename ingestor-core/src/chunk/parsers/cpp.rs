@@ -0,0 +1,843 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, leading_doc_comment, node_text, push_unique};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for C and C++: namespaces, classes, out-of-line members, namespace-scope
+/// variables and includes.
+///
+/// Declarations and definitions of the same function share a `symbol_id`, so a prototype
+/// in a header links to its implementation in the `.c`/`.cpp` file.
+pub(crate) struct CppAdapter;
+
+impl LanguageAdapter for CppAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Cpp)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(request.language, Some(LanguageId::C | LanguageId::Cpp))
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_cpp_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_cpp_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_cpp_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::Cpp)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_cpp::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let context = FileContext { path, text, kind, pack };
+    let mut drafts = Vec::new();
+    drafts.extend(include_draft(&context, root));
+    collect_items(root, &context, &Scope::default(), &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    path: &'a str,
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone, Default)]
+struct Scope {
+    /// Enclosing namespace names, outermost first.
+    namespaces: Vec<String>,
+    /// Enclosing class/struct names, outermost first.
+    classes: Vec<String>,
+    /// Access level in effect inside the innermost class body.
+    access: Option<Visibility>,
+    generics: Vec<String>,
+    /// Inside an anonymous namespace, where every name has internal linkage.
+    internal: bool,
+}
+
+/// Walk a translation unit, namespace or class body. Preprocessor conditionals and
+/// `extern "C"` blocks are transparent.
+#[cfg(feature = "treesitter")]
+fn collect_items(node: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let mut scope = scope.clone();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "preproc_ifdef" | "preproc_if" | "preproc_else" | "preproc_elif" => {
+                collect_items(child, context, &scope, drafts);
+            }
+            "linkage_specification" => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    if body.kind() == "declaration_list" {
+                        collect_items(body, context, &scope, drafts);
+                    } else {
+                        collect_item(child, body, context, &scope, drafts);
+                    }
+                }
+            }
+            "namespace_definition" => {
+                let mut inner = scope.clone();
+                match child.child_by_field_name("name").and_then(|name| node_text(name, context.text)) {
+                    Some(name) => {
+                        let segments = split_scope(name);
+                        drafts.extend(namespace_draft(child, &segments, context, &scope));
+                        inner.namespaces.extend(segments);
+                    }
+                    None => inner.internal = true,
+                }
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_items(body, context, &inner, drafts);
+                }
+            }
+            "access_specifier" => {
+                scope.access = node_text(child, context.text).map(access_visibility);
+            }
+            "template_declaration" => {
+                let mut inner = scope.clone();
+                inner.generics.extend(template_parameter_names(child, context.text));
+                let mut template_cursor = child.walk();
+                let item = child
+                    .named_children(&mut template_cursor)
+                    .filter(|item| item.kind() != "template_parameter_list")
+                    .last();
+                if let Some(item) = item {
+                    collect_item(child, item, context, &inner, drafts);
+                }
+            }
+            _ => collect_item(child, child, context, &scope, drafts),
+        }
+    }
+}
+
+/// Emit drafts for one item. `outer` is the node whose span becomes the chunk (it includes
+/// any `template <...>` prefix); `item` is the declaration itself.
+#[cfg(feature = "treesitter")]
+fn collect_item(outer: Node, item: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    match item.kind() {
+        "function_definition" => {
+            if let Some(declarator) = item.child_by_field_name("declarator").and_then(function_declarator) {
+                drafts.extend(function_draft(outer, item, declarator, context, scope));
+            }
+        }
+        "declaration" | "field_declaration" => {
+            if let Some(declarator) = item.child_by_field_name("declarator").and_then(function_declarator) {
+                drafts.extend(function_draft(outer, item, declarator, context, scope));
+            } else {
+                if let Some(specifier) = item.child_by_field_name("type") {
+                    collect_type(outer, specifier, context, scope, drafts);
+                }
+                if item.kind() == "declaration" && scope.classes.is_empty() {
+                    drafts.extend(variable_draft(outer, item, context, scope));
+                }
+            }
+        }
+        "class_specifier" | "struct_specifier" | "union_specifier" | "enum_specifier" => {
+            collect_type(outer, item, context, scope, drafts);
+        }
+        "type_definition" => {
+            let Some(specifier) = item.child_by_field_name("type") else {
+                return;
+            };
+            if specifier.child_by_field_name("body").is_none() {
+                return;
+            }
+            // `typedef struct tag { ... } Name;` is referred to by its declarator.
+            let name = item
+                .child_by_field_name("declarator")
+                .filter(|declarator| declarator.kind() == "type_identifier")
+                .or_else(|| specifier.child_by_field_name("name"))
+                .and_then(|name| node_text(name, context.text))
+                .map(str::to_string);
+            if let Some(name) = name {
+                drafts.extend(type_draft(outer, specifier, &name, AstNodeKind::Type, context, scope));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Emit a draft for a class/struct/union/enum with a body, then walk its members.
+#[cfg(feature = "treesitter")]
+fn collect_type(outer: Node, specifier: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let ast_kind = match specifier.kind() {
+        "class_specifier" => AstNodeKind::Class,
+        "struct_specifier" | "union_specifier" => AstNodeKind::Type,
+        "enum_specifier" => AstNodeKind::Enum,
+        _ => return,
+    };
+    let Some(body) = specifier.child_by_field_name("body") else {
+        return;
+    };
+    let Some(name) = specifier
+        .child_by_field_name("name")
+        .and_then(|name| node_text(name, context.text))
+        .and_then(|name| split_scope(name).pop())
+    else {
+        return;
+    };
+    drafts.extend(type_draft(outer, specifier, &name, ast_kind, context, scope));
+    if ast_kind == AstNodeKind::Enum {
+        return;
+    }
+
+    let mut inner = scope.clone();
+    inner.classes.push(name);
+    // Class members default to private, struct and union members to public.
+    inner.access = Some(if ast_kind == AstNodeKind::Class {
+        Visibility::Private
+    } else {
+        Visibility::Pub
+    });
+    collect_items(body, context, &inner, drafts);
+}
+
+#[cfg(feature = "treesitter")]
+fn type_draft(
+    outer: Node,
+    specifier: Node,
+    name: &str,
+    ast_kind: AstNodeKind,
+    context: &FileContext,
+    scope: &Scope,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let content = node_text(outer, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+    let parents = scope.namespaces.iter().chain(scope.classes.iter());
+    let qualified_name = parents
+        .map(String::as_str)
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join("::");
+    let mut type_refs = extract_type_refs(outer, context, &scope.generics);
+    type_refs.retain(|type_ref| type_ref != name);
+    let body_start = specifier.child_by_field_name("body").map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row + 1,
+        end_line: outer.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(name.to_string()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(outer, text, body_start),
+        parent_symbol: scope.classes.last().cloned(),
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary: leading_doc_comment(outer, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: Some(name.to_string()),
+        module_path: module_path(scope),
+        visibility: Some(scope.access.unwrap_or(Visibility::Pub)),
+        log_level: None,
+    })
+}
+
+/// Draft a function definition or prototype. `Foo::bar` declarators name a member of
+/// `Foo` even outside the class body.
+#[cfg(feature = "treesitter")]
+fn function_draft(
+    outer: Node,
+    item: Node,
+    declarator: Node,
+    context: &FileContext,
+    scope: &Scope,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let name_node = declarator.child_by_field_name("declarator")?;
+    let mut segments = split_scope(node_text(name_node, text)?);
+    let symbol = segments.pop()?;
+    let content = node_text(outer, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let qualified_name = scope
+        .namespaces
+        .iter()
+        .chain(scope.classes.iter())
+        .chain(segments.iter())
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join("::");
+    let parent_symbol = segments.last().or(scope.classes.last()).cloned();
+    let ast_kind = if parent_symbol.is_some() {
+        AstNodeKind::Method
+    } else {
+        AstNodeKind::Function
+    };
+    let is_static = has_storage_class(item, text, "static");
+    let visibility = if let Some(access) = scope.access {
+        Some(access)
+    } else if is_static || scope.internal {
+        Some(Visibility::Private)
+    } else if segments.is_empty() {
+        Some(Visibility::Pub)
+    } else {
+        // Access of an out-of-line member is declared in the class, not here.
+        None
+    };
+    // Internal-linkage functions only link within their own translation unit.
+    let symbol_id = if scope.internal || (is_static && scope.access.is_none()) {
+        format!("cxx {}:{qualified_name}()", context.path)
+    } else {
+        format!("cxx {qualified_name}()")
+    };
+    let body_start = item.child_by_field_name("body").map(|body| body.start_byte()).or(Some(item.end_byte()));
+    let owner = parent_symbol.as_deref().map(|parent| {
+        let mut path = segments.clone();
+        if path.is_empty() {
+            path.push(parent.to_string());
+        }
+        path.join("::")
+    });
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row + 1,
+        end_line: outer.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(outer, text, body_start),
+        parent_symbol,
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls: extract_calls(item, context, owner.as_deref()),
+        method_calls: Vec::new(),
+        type_refs: extract_type_refs(outer, context, &scope.generics),
+        doc_summary: leading_doc_comment(outer, text, &["comment"]),
+        symbol_id: Some(symbol_id),
+        symbol_tail: Some(symbol),
+        module_path: module_path(scope),
+        visibility,
+        log_level: None,
+    })
+}
+
+/// Draft a named namespace; its members are drafted separately.
+#[cfg(feature = "treesitter")]
+fn namespace_draft(node: Node, segments: &[String], context: &FileContext, scope: &Scope) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = segments.last()?.clone();
+    let content = node_text(node, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+    let qualified_name = scope.namespaces.iter().chain(segments).cloned().collect::<Vec<_>>().join("::");
+    let body_start = node.child_by_field_name("body").map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(AstNodeKind::Module),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(node, text, body_start),
+        parent_symbol: None,
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs: Vec::new(),
+        doc_summary: leading_doc_comment(node, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: module_path(scope),
+        visibility: Some(Visibility::Pub),
+        log_level: None,
+    })
+}
+
+/// Draft a namespace-scope variable or constant, named by its first declarator.
+#[cfg(feature = "treesitter")]
+fn variable_draft(outer: Node, item: Node, context: &FileContext, scope: &Scope) -> Option<ChunkDraft> {
+    let text = context.text;
+    let name_node = item.child_by_field_name("declarator").and_then(variable_declarator)?;
+    let symbol = split_scope(node_text(name_node, text)?).pop()?;
+    let content = node_text(outer, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let qualified_name = scope
+        .namespaces
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join("::");
+    let mut cursor = item.walk();
+    let is_constant = item
+        .children(&mut cursor)
+        .any(|child| matches!(node_text(child, text), Some("const" | "constexpr")));
+    let ast_kind = if is_constant {
+        AstNodeKind::Constant
+    } else {
+        AstNodeKind::Variable
+    };
+    let visibility = if scope.internal || has_storage_class(item, text, "static") {
+        Visibility::Private
+    } else {
+        Visibility::Pub
+    };
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row + 1,
+        end_line: outer.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(outer, text, None),
+        parent_symbol: None,
+        imports: Vec::new(),
+        exports: Vec::new(),
+        calls: extract_calls(item, context, None),
+        method_calls: Vec::new(),
+        type_refs: extract_type_refs(outer, context, &scope.generics),
+        doc_summary: leading_doc_comment(outer, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: module_path(scope),
+        visibility: Some(visibility),
+        log_level: None,
+    })
+}
+
+/// Descend through initializer, pointer, array and reference wrappers to the declared name.
+#[cfg(feature = "treesitter")]
+fn variable_declarator(node: Node) -> Option<Node> {
+    let mut current = node;
+    loop {
+        match current.kind() {
+            "identifier" | "qualified_identifier" => return Some(current),
+            "init_declarator" | "pointer_declarator" | "array_declarator" | "reference_declarator"
+            | "attributed_declarator" => {
+                current = current.child_by_field_name("declarator").or_else(|| current.named_child(0))?;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Descend through pointer/reference wrappers to the `function_declarator`, if any.
+#[cfg(feature = "treesitter")]
+fn function_declarator(node: Node) -> Option<Node> {
+    let mut current = node;
+    loop {
+        match current.kind() {
+            "function_declarator" => return Some(current),
+            "pointer_declarator" | "reference_declarator" | "attributed_declarator" => {
+                current = current.child_by_field_name("declarator")?;
+            }
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn has_storage_class(node: Node, text: &str, keyword: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|child| child.kind() == "storage_class_specifier" && node_text(child, text) == Some(keyword));
+    found
+}
+
+#[cfg(feature = "treesitter")]
+fn access_visibility(keyword: &str) -> Visibility {
+    match keyword.trim_end_matches(':').trim() {
+        "public" => Visibility::Pub,
+        "protected" => Visibility::Crate,
+        _ => Visibility::Private,
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn module_path(scope: &Scope) -> Option<String> {
+    (!scope.namespaces.is_empty()).then(|| scope.namespaces.join("::"))
+}
+
+/// Split `ns::Foo<T>::bar` into `["ns", "Foo", "bar"]`, dropping template arguments.
+#[cfg(feature = "treesitter")]
+fn split_scope(name: &str) -> Vec<String> {
+    let mut plain = String::with_capacity(name.len());
+    let mut depth = 0usize;
+    for ch in name.chars() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && !ch.is_whitespace() => plain.push(ch),
+            _ => {}
+        }
+    }
+    plain
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(feature = "treesitter")]
+fn template_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    let names = params
+        .named_children(&mut cursor)
+        .filter_map(|param| {
+            let mut param_cursor = param.walk();
+            let name = param
+                .named_children(&mut param_cursor)
+                .filter(|child| child.kind() == "type_identifier")
+                .last();
+            name.and_then(|name| node_text(name, text)).map(str::to_string)
+        })
+        .collect();
+    names
+}
+
+/// One `Import` chunk spanning the file's `#include` lines. `#include "auth/token.h"` and
+/// `#include <vector>` both record the bare header path.
+#[cfg(feature = "treesitter")]
+fn include_draft(context: &FileContext, root: Node) -> Option<ChunkDraft> {
+    let text = context.text;
+    let mut includes = Vec::new();
+    let mut span: Option<(Node, Node)> = None;
+    for (_, path) in captures(&context.pack.imports, root, text) {
+        if let Some(name) = node_text(path, text) {
+            push_unique(&mut includes, name.trim_matches(|ch| matches!(ch, '"' | '<' | '>')));
+        }
+        let directive = path.parent().unwrap_or(path);
+        span = Some((span.map_or(directive, |(first, _)| first), directive));
+    }
+    let (first, last) = span?;
+    // Directives own their trailing newline, so the end line comes from the trimmed text.
+    let content = text.get(first.start_byte()..last.end_byte())?.trim().to_string();
+    let start_line = first.start_position().row + 1;
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line,
+        end_line: start_line + content.matches('\n').count(),
+        content,
+        heading_path: Vec::new(),
+        symbol: None,
+        address: None,
+        ast_kind: Some(AstNodeKind::Import),
+        qualified_name: None,
+        signature: None,
+        parent_symbol: None,
+        imports: includes,
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs: Vec::new(),
+        doc_summary: None,
+        symbol_id: None,
+        symbol_tail: None,
+        module_path: None,
+        visibility: None,
+        log_level: None,
+    })
+}
+
+/// Free calls by name, qualified calls as written, and `this->method()` or unqualified
+/// calls inside a member as `Class::method`.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, owner: Option<&str>) -> Vec<String> {
+    let text = context.text;
+    let mut calls = Vec::new();
+    for (_, callee) in captures(&context.pack.calls, node, text) {
+        let path = match callee.kind() {
+            "identifier" => {
+                let Some(name) = node_text(callee, text) else {
+                    continue;
+                };
+                name.to_string()
+            }
+            "qualified_identifier" | "template_function" => match node_text(callee, text) {
+                Some(name) => split_scope(name).join("::"),
+                None => continue,
+            },
+            "field_expression" => {
+                let Some(field) = callee.child_by_field_name("field").and_then(|field| node_text(field, text)) else {
+                    continue;
+                };
+                let receiver = callee.child_by_field_name("argument").map(|argument| argument.kind());
+                match (receiver, owner) {
+                    (Some("this"), Some(owner)) => format!("{owner}::{field}"),
+                    _ => field.to_string(),
+                }
+            }
+            _ => continue,
+        };
+        push_unique(&mut calls, &path);
+    }
+    calls
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String]) -> Vec<String> {
+    let text = context.text;
+    let mut refs = Vec::new();
+    for (_, type_node) in captures(&context.pack.types, node, text) {
+        // Segments of `a::b::Type` are covered by the outermost qualified capture.
+        if type_node
+            .parent()
+            .is_some_and(|parent| parent.kind() == "qualified_identifier")
+        {
+            continue;
+        }
+        let Some(name) = node_text(type_node, text) else {
+            continue;
+        };
+        let name = split_scope(name).join("::");
+        // Standard library types carry no project relationship.
+        if name.starts_with("std::") || generics.iter().any(|generic| generic == &name) {
+            continue;
+        }
+        push_unique(&mut refs, &name);
+    }
+    refs
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str, language: LanguageId) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(language),
+        };
+        CppAdapter.parse(&request).expect("cpp parse result")
+    }
+
+    #[test]
+    fn header_declarations_share_symbol_ids_with_definitions() {
+        let header = r#"#pragma once
+#include <string>
+#include "store/token_store.h"
+
+namespace acme::auth {
+
+/// Issues and verifies tokens.
+class AuthService : public BaseService {
+public:
+    Claims login(const std::string& user);
+protected:
+    void validate(const std::string& user);
+private:
+    TokenStore store_;
+};
+
+}
+"#;
+        let source = r#"#include "auth/auth_service.h"
+
+namespace acme::auth {
+
+Claims AuthService::login(const std::string& user) {
+    this->validate(user);
+    return Claims::from(store_.find(user));
+}
+
+static int helper() { return 1; }
+
+}
+"#;
+        let header_result = parse("include/auth/auth_service.hpp", header, LanguageId::Cpp);
+        assert_eq!(header_result.resolution_tier, ResolutionTier::QueryPack);
+        let includes = &header_result.chunks[0];
+        assert_eq!(includes.ast_kind, Some(AstNodeKind::Import));
+        assert_eq!((includes.start_line, includes.end_line), (2, 3));
+        assert_eq!(includes.imports, vec!["string", "store/token_store.h"]);
+        assert_eq!(header_result.chunks.iter().filter(|c| !c.imports.is_empty()).count(), 1);
+        let class = header_result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("AuthService"))
+            .expect("class");
+        assert_eq!(class.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(class.qualified_name.as_deref(), Some("acme::auth::AuthService"));
+        assert_eq!(class.module_path.as_deref(), Some("acme::auth"));
+        assert_eq!(class.doc_summary.as_deref(), Some("Issues and verifies tokens."));
+        assert!(class.imports.is_empty());
+        assert!(class.type_refs.contains(&"BaseService".to_string()));
+        assert!(class.type_refs.contains(&"TokenStore".to_string()));
+
+        let declared = header_result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("login"))
+            .expect("login declaration");
+        assert_eq!(declared.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(declared.parent_symbol.as_deref(), Some("AuthService"));
+        assert_eq!(declared.visibility, Some(Visibility::Pub));
+        assert_eq!(declared.signature.as_deref(), Some("Claims login(const std::string& user)"));
+        let validate = header_result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("validate"))
+            .expect("validate declaration");
+        assert_eq!(validate.visibility, Some(Visibility::Crate));
+
+        let source_result = parse("src/auth/auth_service.cpp", source, LanguageId::Cpp);
+        let defined = source_result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("login"))
+            .expect("login definition");
+        assert_eq!(defined.qualified_name.as_deref(), Some("acme::auth::AuthService::login"));
+        assert_eq!(defined.parent_symbol.as_deref(), Some("AuthService"));
+        assert_eq!(defined.symbol_id, declared.symbol_id);
+        assert!(defined.symbol_id.is_some());
+        assert_eq!(defined.calls, vec!["AuthService::validate", "Claims::from", "find"]);
+        assert!(defined.imports.is_empty());
+        assert_eq!(source_result.chunks[0].imports, vec!["auth/auth_service.h"]);
+        assert_eq!(source_result.chunks[0].content, r#"#include "auth/auth_service.h""#);
+
+        let helper = source_result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("helper"))
+            .expect("helper");
+        assert_eq!(helper.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(helper.visibility, Some(Visibility::Private));
+        assert_eq!(helper.symbol_id.as_deref(), Some("cxx src/auth/auth_service.cpp:acme::auth::helper()"));
+    }
+
+    #[test]
+    fn namespace_scope_variables_and_anonymous_namespaces_are_kept() {
+        let source = r#"namespace acme {
+
+/// Retry budget for token refreshes.
+constexpr int kMaxRetries = 3;
+static TokenStore* g_store = nullptr;
+
+namespace {
+int helper() { return kMaxRetries; }
+}
+
+}
+"#;
+        let result = parse("src/acme/retry.cpp", source, LanguageId::Cpp);
+        let namespace = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("acme")).expect("namespace");
+        assert_eq!(namespace.ast_kind, Some(AstNodeKind::Module));
+        assert_eq!((namespace.start_line, namespace.end_line), (1, 11));
+
+        let retries = result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("kMaxRetries"))
+            .expect("constant");
+        assert_eq!(retries.ast_kind, Some(AstNodeKind::Constant));
+        assert_eq!(retries.qualified_name.as_deref(), Some("acme::kMaxRetries"));
+        assert_eq!(retries.doc_summary.as_deref(), Some("Retry budget for token refreshes."));
+        assert_eq!(retries.visibility, Some(Visibility::Pub));
+
+        let store = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("g_store")).expect("variable");
+        assert_eq!(store.ast_kind, Some(AstNodeKind::Variable));
+        assert_eq!(store.visibility, Some(Visibility::Private));
+        assert_eq!(store.type_refs, vec!["TokenStore"]);
+
+        let helper = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("helper")).expect("helper");
+        assert_eq!(helper.visibility, Some(Visibility::Private));
+        assert_eq!(helper.symbol_id.as_deref(), Some("cxx src/acme/retry.cpp:acme::helper()"));
+    }
+
+    #[test]
+    fn c_prototypes_and_typedefs_are_symbols() {
+        let header = r#"#ifndef TOKEN_H
+#define TOKEN_H
+
+typedef struct {
+    int id;
+} token_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Parse a raw token. */
+int token_parse(const char *raw, token_t *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
+"#;
+        let result = parse("include/token.h", header, LanguageId::C);
+        let typedef = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("token_t")).expect("typedef");
+        assert_eq!(typedef.ast_kind, Some(AstNodeKind::Type));
+
+        let prototype = result
+            .chunks
+            .iter()
+            .find(|c| c.symbol.as_deref() == Some("token_parse"))
+            .expect("prototype");
+        assert_eq!(prototype.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(prototype.symbol_id.as_deref(), Some("cxx token_parse()"));
+        assert_eq!(prototype.doc_summary.as_deref(), Some("Parse a raw token."));
+        assert_eq!(prototype.type_refs, vec!["token_t"]);
+    }
+}
@@ -0,0 +1,526 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, leading_doc_comment, node_text, push_unique, referenced_imports, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for C#: namespaces, nested types, members and attributes.
+pub(crate) struct CSharpAdapter;
+
+impl LanguageAdapter for CSharpAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::CSharp)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::CSharp)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_csharp_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_csharp_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_csharp_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::CSharp)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_c_sharp::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let (aliases, namespaces) = collect_file_imports(pack, root, text);
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        aliases,
+        namespaces,
+    };
+    let mut drafts = Vec::new();
+    collect_declarations(root, &context, &Scope::default(), &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    /// `using Alias = Some.Type;` bindings.
+    aliases: Vec<ImportBinding>,
+    /// Plain `using Some.Namespace;` directives, attached to top-level types.
+    namespaces: Vec<String>,
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone, Default)]
+struct Scope {
+    namespace: Option<String>,
+    /// Enclosing type names, outermost first.
+    types: Vec<String>,
+    in_interface: bool,
+    generics: Vec<String>,
+}
+
+#[cfg(feature = "treesitter")]
+fn collect_declarations(node: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let text = context.text;
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "namespace_declaration" | "file_scoped_namespace_declaration" => {
+                let Some(name) = child.child_by_field_name("name").and_then(|name| node_text(name, text)) else {
+                    continue;
+                };
+                let mut inner = scope.clone();
+                inner.namespace = Some(match &scope.namespace {
+                    Some(outer) => format!("{outer}.{name}"),
+                    None => name.to_string(),
+                });
+                // File-scoped namespaces own the declarations that follow them directly.
+                let body = child.child_by_field_name("body").unwrap_or(child);
+                collect_declarations(body, context, &inner, drafts);
+            }
+            "class_declaration" | "record_declaration" | "record_struct_declaration" | "struct_declaration"
+            | "interface_declaration" | "enum_declaration" => {
+                let ast_kind = match child.kind() {
+                    "interface_declaration" => AstNodeKind::Interface,
+                    "enum_declaration" => AstNodeKind::Enum,
+                    "struct_declaration" | "record_struct_declaration" => AstNodeKind::Type,
+                    _ => AstNodeKind::Class,
+                };
+                let Some(draft) = draft_from_declaration(child, ast_kind, context, scope) else {
+                    continue;
+                };
+                let mut inner = scope.clone();
+                inner.types.push(draft.symbol.clone().unwrap_or_default());
+                inner.in_interface = ast_kind == AstNodeKind::Interface;
+                inner.generics.extend(type_parameter_names(child, text));
+                drafts.push(draft);
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_declarations(body, context, &inner, drafts);
+                }
+            }
+            "method_declaration" | "constructor_declaration" if !scope.types.is_empty() => {
+                let kind = if has_test_attribute(child, text) {
+                    AstNodeKind::Test
+                } else {
+                    AstNodeKind::Method
+                };
+                drafts.extend(draft_from_declaration(child, kind, context, scope));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_declaration(
+    node: Node,
+    ast_kind: AstNodeKind,
+    context: &FileContext,
+    scope: &Scope,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = node_text(node.child_by_field_name("name")?, text)?.to_string();
+    let content = node_text(node, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let is_type = !matches!(ast_kind, AstNodeKind::Method | AstNodeKind::Test);
+    let qualified_name = scope
+        .types
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut generics = scope.generics.clone();
+    generics.extend(type_parameter_names(node, text));
+    let mut type_refs = extract_type_refs(node, context, &generics);
+    if is_type {
+        type_refs.retain(|type_ref| type_ref != &symbol);
+    }
+
+    let mut imports = Vec::new();
+    if is_type && scope.types.is_empty() {
+        for namespace in &context.namespaces {
+            push_unique(&mut imports, namespace);
+        }
+    }
+    for path in referenced_imports(&context.aliases, node, text, &["identifier"]) {
+        push_unique(&mut imports, &path);
+    }
+
+    let body_start = node
+        .child_by_field_name("body")
+        .or_else(|| {
+            let mut cursor = node.walk();
+            let body = node
+                .named_children(&mut cursor)
+                .find(|child| matches!(child.kind(), "block" | "arrow_expression_clause"));
+            body
+        })
+        .map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(node, text, body_start),
+        parent_symbol: scope.types.last().cloned(),
+        imports,
+        exports: Vec::new(),
+        calls: extract_calls(node, context, scope.types.last().map(String::as_str)),
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary: leading_doc_comment(node, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: scope.namespace.clone(),
+        visibility: Some(extract_visibility(node, text, scope, is_type)),
+        log_level: None,
+    })
+}
+
+/// `public` → Pub, `internal`/`protected` → Crate, `private` → Private; unmarked members
+/// default to private and unmarked top-level types to internal.
+#[cfg(feature = "treesitter")]
+fn extract_visibility(node: Node, text: &str, scope: &Scope, is_type: bool) -> Visibility {
+    let mut cursor = node.walk();
+    let modifiers: Vec<&str> = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "modifier")
+        .filter_map(|modifier| node_text(modifier, text))
+        .collect();
+    let has = |keyword: &str| modifiers.contains(&keyword);
+    if has("public") || (scope.in_interface && !has("private")) {
+        Visibility::Pub
+    } else if has("internal") || has("protected") {
+        Visibility::Crate
+    } else if has("private") || !(is_type && scope.types.is_empty()) {
+        Visibility::Private
+    } else {
+        Visibility::Crate
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn has_test_attribute(node: Node, text: &str) -> bool {
+    let mut cursor = node.walk();
+    let lists: Vec<Node> = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "attribute_list")
+        .collect();
+    lists.into_iter().any(|list| {
+        let mut cursor = list.walk();
+        let found = list.named_children(&mut cursor).any(|attribute| {
+            attribute
+                .child_by_field_name("name")
+                .and_then(|name| node_text(name, text))
+                .map(|name| {
+                    let name = name.rsplit('.').next().unwrap_or(name);
+                    name.strip_suffix("Attribute").unwrap_or(name)
+                })
+                .is_some_and(|name| matches!(name, "Fact" | "Theory" | "Test" | "TestMethod" | "TestCase"))
+        });
+        found
+    })
+}
+
+#[cfg(feature = "treesitter")]
+fn type_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    let names = params
+        .named_children(&mut cursor)
+        .filter_map(|param| param.child_by_field_name("name"))
+        .filter_map(|name| node_text(name, text))
+        .map(str::to_string)
+        .collect();
+    names
+}
+
+/// Split `using` directives into alias bindings and plain namespace imports.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> (Vec<ImportBinding>, Vec<String>) {
+    let mut aliases = Vec::new();
+    let mut namespaces = Vec::new();
+    for (_, directive) in captures(&pack.imports, root, text) {
+        let mut cursor = directive.walk();
+        let children: Vec<Node> = directive.named_children(&mut cursor).collect();
+        let alias = children
+            .iter()
+            .find(|child| child.kind() == "name_equals")
+            .and_then(|name_equals| name_equals.named_child(0))
+            .and_then(|alias| node_text(alias, text));
+        let Some(path) = children
+            .iter()
+            .rev()
+            .find(|child| child.kind() != "name_equals")
+            .and_then(|name| node_text(*name, text))
+            .map(|name| name.split_whitespace().collect::<String>())
+        else {
+            continue;
+        };
+        match alias {
+            Some(alias) => aliases.push(ImportBinding {
+                local: alias.to_string(),
+                path,
+            }),
+            None => namespaces.push(path),
+        }
+    }
+    (aliases, namespaces)
+}
+
+/// Invocations as `Type.Method` for `this`/static receivers and unqualified calls inside a
+/// type, else the bare method name; `new T()` as the constructed type.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, class: Option<&str>) -> Vec<String> {
+    let text = context.text;
+    let mut calls = Vec::new();
+    for (capture, callee) in captures(&context.pack.calls, node, text) {
+        let path = match (capture, callee.kind()) {
+            ("construct", _) => match type_names(callee, text).into_iter().next() {
+                Some(name) => name,
+                None => continue,
+            },
+            ("call", "identifier" | "generic_name") => {
+                let Some(name) = simple_name(callee, text) else {
+                    continue;
+                };
+                match class {
+                    Some(class) => format!("{class}.{name}"),
+                    None => name,
+                }
+            }
+            ("call", "member_access_expression") => {
+                let Some(name) = callee.child_by_field_name("name").and_then(|name| simple_name(name, text)) else {
+                    continue;
+                };
+                let receiver = callee.child_by_field_name("expression");
+                match (receiver.map(|receiver| receiver.kind()), receiver.and_then(|receiver| node_text(receiver, text))) {
+                    (Some("this_expression"), _) => match class {
+                        Some(class) => format!("{class}.{name}"),
+                        None => name,
+                    },
+                    (Some("identifier"), Some(receiver)) if receiver.starts_with(char::is_uppercase) => {
+                        format!("{receiver}.{name}")
+                    }
+                    _ => name,
+                }
+            }
+            _ => continue,
+        };
+        push_unique(&mut calls, &path);
+    }
+    calls
+}
+
+/// Identifier text of a name node, without generic arguments.
+#[cfg(feature = "treesitter")]
+fn simple_name(node: Node, text: &str) -> Option<String> {
+    let name = match node.kind() {
+        "generic_name" => node.named_child(0)?,
+        _ => node,
+    };
+    node_text(name, text).map(str::to_string)
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String]) -> Vec<String> {
+    let mut refs = Vec::new();
+    for (_, type_node) in captures(&context.pack.types, node, context.text) {
+        for name in type_names(type_node, context.text) {
+            if !generics.contains(&name) {
+                push_unique(&mut refs, &name);
+            }
+        }
+    }
+    refs
+}
+
+/// Named types inside a type expression (`List<Token>?` → `List`, `Token`).
+#[cfg(feature = "treesitter")]
+fn type_names(node: Node, text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        match current.kind() {
+            "identifier" | "qualified_name" => {
+                if let Some(name) = node_text(current, text) {
+                    names.push(name.split_whitespace().collect());
+                }
+            }
+            "generic_name" => {
+                let mut cursor = current.walk();
+                let children: Vec<Node> = current.named_children(&mut cursor).collect();
+                if let Some(name) = children.first().and_then(|name| node_text(*name, text)) {
+                    names.push(name.to_string());
+                }
+                stack.extend(children.into_iter().skip(1).rev());
+            }
+            "predefined_type" => {}
+            _ => {
+                let mut cursor = current.walk();
+                let children: Vec<Node> = current.named_children(&mut cursor).collect();
+                stack.extend(children.into_iter().rev());
+            }
+        }
+    }
+    names
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(LanguageId::CSharp),
+        };
+        CSharpAdapter.parse(&request).expect("csharp parse result")
+    }
+
+    #[test]
+    fn namespaces_members_and_tests_are_enriched() {
+        let source = r#"using System.Text;
+using Store = Acme.Storage.TokenStore;
+
+namespace Acme.Auth
+{
+    /// <summary>Issues and verifies tokens.</summary>
+    public class AuthService : BaseService, IVerifier
+    {
+        private readonly Store store;
+
+        internal Claims Login(string user, List<Scope> scopes)
+        {
+            Validate(user);
+            return Claims.From(store.Find(user));
+        }
+
+        void Validate(string user) { }
+
+        [Fact]
+        public void LoginWorks()
+        {
+            var service = new AuthService();
+        }
+    }
+
+    interface IVerifier
+    {
+        bool Verify(string token);
+    }
+}
+"#;
+        let result = parse("src/Auth/AuthService.cs", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+
+        let class = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("AuthService")).expect("class");
+        assert_eq!(class.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(class.module_path.as_deref(), Some("Acme.Auth"));
+        assert_eq!(class.visibility, Some(Visibility::Pub));
+        assert_eq!(class.doc_summary.as_deref(), Some("Issues and verifies tokens."));
+        assert!(class.type_refs.starts_with(&["BaseService".to_string(), "IVerifier".to_string()]));
+        assert_eq!(class.imports, vec!["System.Text", "Acme.Storage.TokenStore"]);
+
+        let login = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Login")).expect("Login");
+        assert_eq!(login.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(login.qualified_name.as_deref(), Some("AuthService.Login"));
+        assert_eq!(login.visibility, Some(Visibility::Crate));
+        assert_eq!(login.signature.as_deref(), Some("internal Claims Login(string user, List<Scope> scopes)"));
+        assert_eq!(login.calls, vec!["AuthService.Validate", "Claims.From", "Find"]);
+        assert_eq!(login.type_refs, vec!["Claims", "List", "Scope"]);
+
+        let validate = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Validate")).expect("Validate");
+        assert_eq!(validate.visibility, Some(Visibility::Private));
+
+        let test = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("LoginWorks")).expect("test");
+        assert_eq!(test.ast_kind, Some(AstNodeKind::Test));
+        assert_eq!(test.calls, vec!["AuthService"]);
+
+        let verifier = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("IVerifier")).expect("interface");
+        assert_eq!(verifier.ast_kind, Some(AstNodeKind::Interface));
+        assert_eq!(verifier.visibility, Some(Visibility::Crate));
+        let verify = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Verify")).expect("Verify");
+        assert_eq!(verify.visibility, Some(Visibility::Pub));
+    }
+    #[test]
+    fn file_scoped_namespaces_cover_the_rest_of_the_file() {
+        let source = r#"using Acme.Core;
+
+namespace Acme.Billing;
+
+/// <summary>A charge against an account.</summary>
+public record Invoice(string Id, decimal Total);
+
+public sealed class InvoiceService<TStore> where TStore : IStore
+{
+    public Invoice Issue(TStore store) => store.Save(new Invoice("1", 0m));
+}
+"#;
+        let result = parse("src/Billing/InvoiceService.cs", source);
+
+        let invoice = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Invoice")).expect("record");
+        assert_eq!(invoice.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(invoice.module_path.as_deref(), Some("Acme.Billing"));
+        assert_eq!(invoice.doc_summary.as_deref(), Some("A charge against an account."));
+        assert_eq!(invoice.imports, vec!["Acme.Core"]);
+
+        let service = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("InvoiceService")).expect("class");
+        assert_eq!(service.module_path.as_deref(), Some("Acme.Billing"));
+        // Constraint types are referenced; the type parameters themselves are not.
+        assert_eq!(service.type_refs, vec!["IStore", "Invoice"]);
+
+        // Expression-bodied members stop their signature at the arrow.
+        let issue = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Issue")).expect("Issue");
+        assert_eq!(issue.qualified_name.as_deref(), Some("InvoiceService.Issue"));
+        assert_eq!(issue.module_path.as_deref(), Some("Acme.Billing"));
+        assert_eq!(issue.signature.as_deref(), Some("public Invoice Issue(TStore store)"));
+        assert_eq!(issue.type_refs, vec!["Invoice"]);
+    }
+}
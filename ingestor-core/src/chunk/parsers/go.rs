@@ -0,0 +1,613 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, FileInput, LanguageId, ResolutionTier};
+use std::collections::HashMap;
+use std::path::Path;
+#[cfg(feature = "treesitter")]
+use super::{header_signature, node_text, push_unique, referenced_imports, summarize_doc, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for Go: package-scoped functions, receiver-qualified methods and types.
+pub(crate) struct GoAdapter;
+
+impl LanguageAdapter for GoAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Go)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Go)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_go_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// The package's directory, or the package name itself for files at the root. The
+/// adapter sees one file at a time, so ingestion replaces this with the import path
+/// from the nearest `go.mod` ([`GoModules`]) when there is one.
+pub(crate) fn go_module_path(path: &str, package: &str) -> String {
+    let normalized = path.replace('\\', "/");
+    match normalized.rsplit_once('/') {
+        Some((dir, _)) if !dir.is_empty() => dir.trim_start_matches("./").to_string(),
+        _ => package.to_string(),
+    }
+}
+
+/// Module paths declared by `go.mod` files, keyed by the directory holding each
+/// (`None` where a directory was found to have none). The walk doesn't index
+/// `go.mod`, so the ones above each Go file are read from disk under the root,
+/// unless passed in among the files.
+#[derive(Default)]
+pub(crate) struct GoModules {
+    dirs: HashMap<String, Option<String>>,
+}
+
+impl GoModules {
+    pub(crate) fn collect(files: &[FileInput], root_path: &str) -> Self {
+        let mut modules = Self::default();
+        for file in files {
+            let path = relative(&file.path, root_path);
+            if let Some(dir) = path.strip_suffix("go.mod").filter(|dir| dir.is_empty() || dir.ends_with('/')) {
+                let module = module_line(&String::from_utf8_lossy(&file.data));
+                modules.dirs.insert(dir.trim_end_matches('/').to_string(), module);
+            }
+        }
+        if root_path.is_empty() {
+            return modules;
+        }
+        for file in files.iter().filter(|file| file.path.ends_with(".go")) {
+            let mut dir = parent_dir(relative(&file.path, root_path));
+            // A directory already looked at had its parents looked at too.
+            while !modules.dirs.contains_key(dir) {
+                let text = std::fs::read_to_string(Path::new(root_path).join(dir).join("go.mod")).ok();
+                let found = text.is_some();
+                modules.dirs.insert(dir.to_string(), text.as_deref().and_then(module_line));
+                if found || dir.is_empty() {
+                    break;
+                }
+                dir = parent_dir(dir);
+            }
+        }
+        modules
+    }
+
+    /// Import path of the package holding `path`: the nearest module's path joined
+    /// with the package's directory below it.
+    pub(crate) fn import_path(&self, path: &str, root_path: &str) -> Option<String> {
+        let package_dir = parent_dir(relative(path, root_path));
+        let mut dir = package_dir;
+        loop {
+            if let Some(Some(module)) = self.dirs.get(dir) {
+                let below = package_dir[dir.len()..].trim_start_matches('/');
+                return Some(if below.is_empty() { module.clone() } else { format!("{module}/{below}") });
+            }
+            if dir.is_empty() {
+                return None;
+            }
+            dir = parent_dir(dir);
+        }
+    }
+}
+
+fn relative<'p>(path: &'p str, root_path: &str) -> &'p str {
+    let path = match path.strip_prefix(root_path) {
+        Some(rest) if !root_path.is_empty() && rest.starts_with('/') => &rest[1..],
+        _ => path,
+    };
+    path.trim_start_matches("./")
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// The path on a `go.mod` file's `module` line.
+fn module_line(text: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("module")?;
+        let module = rest.split("//").next()?.trim().trim_matches('"');
+        (rest.starts_with(char::is_whitespace) && !module.is_empty()).then(|| module.to_string())
+    })
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_go_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_go_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::Go)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_go::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let mut cursor = root.walk();
+    let package = root
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "package_clause")
+        .and_then(|clause| clause.named_child(0))
+        .and_then(|name| node_text(name, text))
+        .unwrap_or("main")
+        .to_string();
+
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        imports: collect_file_imports(pack, root, text),
+        module_path: go_module_path(path, &package),
+        is_test_file: path.ends_with("_test.go"),
+    };
+
+    let mut drafts = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "function_declaration" | "method_declaration" => {
+                if let Some(draft) = draft_from_function(child, &context) {
+                    drafts.push(draft);
+                }
+            }
+            "type_declaration" => {
+                let mut spec_cursor = child.walk();
+                let specs: Vec<Node> = child
+                    .named_children(&mut spec_cursor)
+                    .filter(|spec| spec.kind() == "type_spec")
+                    .collect();
+                // A lone spec keeps its `type` keyword and doc comment; grouped specs split.
+                if let [spec] = specs.as_slice() {
+                    drafts.extend(draft_from_type(child, *spec, &context));
+                } else {
+                    drafts.extend(specs.iter().filter_map(|spec| draft_from_type(*spec, *spec, &context)));
+                }
+            }
+            _ => {}
+        }
+    }
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    imports: Vec<ImportBinding>,
+    module_path: String,
+    is_test_file: bool,
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_function(node: Node, context: &FileContext) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = node_text(node.child_by_field_name("name")?, text)?.to_string();
+    let receiver = node
+        .child_by_field_name("receiver")
+        .and_then(|receiver| receiver_binding(receiver, text));
+    let receiver_type = receiver.as_ref().map(|(_, ty)| ty.clone());
+
+    let ast_kind = if context.is_test_file
+        && ["Test", "Benchmark", "Fuzz", "Example"]
+            .iter()
+            .any(|prefix| symbol.starts_with(prefix))
+    {
+        AstNodeKind::Test
+    } else if receiver_type.is_some() {
+        AstNodeKind::Method
+    } else {
+        AstNodeKind::Function
+    };
+    let qualified_name = match &receiver_type {
+        Some(receiver_type) => format!("{receiver_type}.{symbol}"),
+        None => symbol.clone(),
+    };
+
+    let mut generics = type_parameter_names(node, text);
+    if let Some(receiver) = node.child_by_field_name("receiver") {
+        generics.extend(receiver_type_arguments(receiver, text));
+    }
+    let body_start = node.child_by_field_name("body").map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(node, text, body_start),
+        parent_symbol: receiver_type,
+        calls: extract_calls(node, context, receiver.as_ref()),
+        method_calls: Vec::new(),
+        type_refs: extract_type_refs(node, context, &generics, None),
+        ..base_draft(node, node, symbol, context)?
+    })
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_type(outer: Node, spec: Node, context: &FileContext) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = node_text(spec.child_by_field_name("name")?, text)?.to_string();
+    let ast_kind = match spec.child_by_field_name("type").map(|ty| ty.kind()) {
+        Some("interface_type") => AstNodeKind::Interface,
+        _ => AstNodeKind::Type,
+    };
+    let generics = type_parameter_names(spec, text);
+    let body_start = spec
+        .child_by_field_name("type")
+        .filter(|ty| matches!(ty.kind(), "struct_type" | "interface_type"))
+        .and_then(|ty| ty.child(1))
+        .map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(symbol.clone()),
+        signature: header_signature(outer, text, body_start),
+        parent_symbol: None,
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs: extract_type_refs(spec, context, &generics, Some(&symbol)),
+        ..base_draft(outer, spec, symbol, context)?
+    })
+}
+
+/// Fields shared by every Go draft; callers override the structural ones.
+#[cfg(feature = "treesitter")]
+fn base_draft(outer: Node, inner: Node, symbol: String, context: &FileContext) -> Option<ChunkDraft> {
+    let text = context.text;
+    let content = node_text(outer, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+    let exported = symbol.chars().next().is_some_and(char::is_uppercase);
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row + 1,
+        end_line: outer.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: None,
+        qualified_name: None,
+        signature: None,
+        parent_symbol: None,
+        imports: referenced_imports(&context.imports, inner, text, &["identifier", "package_identifier"]),
+        exports: Vec::new(),
+        calls: Vec::new(),
+        method_calls: Vec::new(),
+        type_refs: Vec::new(),
+        doc_summary: extract_doc_comment(outer, text),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: Some(context.module_path.clone()),
+        visibility: Some(if exported { Visibility::Pub } else { Visibility::Private }),
+        log_level: None,
+    })
+}
+
+/// Receiver variable name and base type (`(s *Server[T])` → `("s", "Server")`).
+#[cfg(feature = "treesitter")]
+fn receiver_binding(receiver: Node, text: &str) -> Option<(Option<String>, String)> {
+    let param = receiver.named_child(0)?;
+    let name = param
+        .child_by_field_name("name")
+        .and_then(|name| node_text(name, text))
+        .map(str::to_string);
+    let raw = node_text(param.child_by_field_name("type")?, text)?;
+    let base = raw
+        .trim_start_matches('*')
+        .split('[')
+        .next()
+        .unwrap_or(raw)
+        .trim();
+    if base.is_empty() {
+        None
+    } else {
+        Some((name, base.to_string()))
+    }
+}
+
+/// Type parameter names bound by a generic receiver (`(l *List[T])` → `T`).
+#[cfg(feature = "treesitter")]
+fn receiver_type_arguments(receiver: Node, text: &str) -> Vec<String> {
+    let Some(raw) = receiver
+        .named_child(0)
+        .and_then(|param| param.child_by_field_name("type"))
+        .and_then(|ty| node_text(ty, text))
+    else {
+        return Vec::new();
+    };
+    raw.split_once('[')
+        .map(|(_, args)| {
+            args.trim_end_matches(']')
+                .split(',')
+                .map(|arg| arg.trim().to_string())
+                .filter(|arg| !arg.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "treesitter")]
+fn type_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        let mut name_cursor = param.walk();
+        for name in param.children_by_field_name("name", &mut name_cursor) {
+            if let Some(name) = node_text(name, text) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Contiguous `//` or `/* */` comments directly above the declaration.
+#[cfg(feature = "treesitter")]
+fn extract_doc_comment(node: Node, text: &str) -> Option<String> {
+    let mut docs = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut cursor = node.prev_sibling();
+    while let Some(prev) = cursor {
+        if prev.kind() != "comment" || prev.end_position().row + 1 != expected_row {
+            break;
+        }
+        let raw = node_text(prev, text)?.trim();
+        let cleaned = raw
+            .trim_start_matches("//")
+            .trim_start_matches("/*")
+            .trim_end_matches("*/")
+            .trim();
+        docs.push(cleaned.to_string());
+        expected_row = prev.start_position().row;
+        cursor = prev.prev_sibling();
+    }
+    docs.reverse();
+    summarize_doc(&docs)
+}
+
+/// Import specs keyed by alias, or by the last path segment when unaliased.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> Vec<ImportBinding> {
+    let mut bindings = Vec::new();
+    for (_, spec) in captures(&pack.imports, root, text) {
+        let Some(path) = spec
+            .child_by_field_name("path")
+            .and_then(|path| node_text(path, text))
+            .map(|path| path.trim_matches(|c| c == '"' || c == '`').to_string())
+        else {
+            continue;
+        };
+        let local = match spec.child_by_field_name("name").and_then(|name| node_text(name, text)) {
+            Some("_") | Some(".") => continue,
+            Some(alias) => alias.to_string(),
+            None => path.rsplit('/').next().unwrap_or(&path).to_string(),
+        };
+        bindings.push(ImportBinding { local, path });
+    }
+    bindings
+}
+
+/// Callees as `pkg.Func`, `Receiver.Method` for calls through the receiver, or the
+/// bare method name for calls on other values.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, receiver: Option<&(Option<String>, String)>) -> Vec<String> {
+    let text = context.text;
+    let mut calls = Vec::new();
+    for (_, callee) in captures(&context.pack.calls, node, text) {
+        if callee.kind() == "identifier" {
+            push_unique(&mut calls, node_text(callee, text).unwrap_or(""));
+            continue;
+        }
+        let Some(field) = callee.child_by_field_name("field").and_then(|field| node_text(field, text)) else {
+            continue;
+        };
+        let operand = callee
+            .child_by_field_name("operand")
+            .filter(|operand| operand.kind() == "identifier")
+            .and_then(|operand| node_text(operand, text));
+        let path = match (operand, receiver) {
+            (Some(operand), Some((Some(name), receiver_type))) if operand == name => {
+                format!("{receiver_type}.{field}")
+            }
+            (Some(operand), _) if context.imports.iter().any(|binding| binding.local == operand) => {
+                format!("{operand}.{field}")
+            }
+            _ => field.to_string(),
+        };
+        push_unique(&mut calls, &path);
+    }
+    calls
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String], own_name: Option<&str>) -> Vec<String> {
+    let text = context.text;
+    let mut refs = Vec::new();
+    for (capture, type_node) in captures(&context.pack.types, node, text) {
+        if capture == "type" && type_node.parent().is_some_and(|parent| parent.kind() == "qualified_type") {
+            continue;
+        }
+        let Some(name) = node_text(type_node, text) else {
+            continue;
+        };
+        if is_builtin_type(name) || own_name == Some(name) || generics.iter().any(|generic| generic == name) {
+            continue;
+        }
+        push_unique(&mut refs, name);
+    }
+    refs
+}
+
+#[cfg(feature = "treesitter")]
+fn is_builtin_type(name: &str) -> bool {
+    matches!(
+        name,
+        "bool" | "byte" | "rune" | "string" | "error" | "any" | "comparable" | "int" | "int8" | "int16"
+            | "int32" | "int64" | "uint" | "uint8" | "uint16" | "uint32" | "uint64" | "uintptr"
+            | "float32" | "float64" | "complex64" | "complex128"
+    )
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(LanguageId::Go),
+        };
+        GoAdapter.parse(&request).expect("go parse result")
+    }
+
+    #[test]
+    fn methods_are_qualified_by_receiver() {
+        let source = r#"package server
+
+import (
+	"net/http"
+	cfg "example.com/app/internal/config"
+)
+
+// Server serves HTTP traffic.
+type Server struct {
+	conf *cfg.Config
+	mux  *http.ServeMux
+}
+
+// Start starts listening. It blocks.
+func (s *Server) Start(addr string) error {
+	s.routes()
+	return http.ListenAndServe(addr, s.mux)
+}
+
+func (s *Server) routes() {
+	s.mux.HandleFunc("/", nil)
+}
+"#;
+        let result = parse("internal/server/server.go", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+
+        let server = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Server")).expect("Server");
+        assert_eq!(server.ast_kind, Some(AstNodeKind::Type));
+        assert_eq!(server.doc_summary.as_deref(), Some("Server serves HTTP traffic."));
+        assert_eq!(server.module_path.as_deref(), Some("internal/server"));
+        assert_eq!(server.type_refs, vec!["cfg.Config", "http.ServeMux"]);
+        assert_eq!(server.imports, vec!["net/http", "example.com/app/internal/config"]);
+
+        let start = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Start")).expect("Start");
+        assert_eq!(start.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(start.qualified_name.as_deref(), Some("Server.Start"));
+        assert_eq!(start.parent_symbol.as_deref(), Some("Server"));
+        assert_eq!(start.visibility, Some(Visibility::Pub));
+        assert_eq!(start.signature.as_deref(), Some("func (s *Server) Start(addr string) error"));
+        assert_eq!(start.calls, vec!["Server.routes", "http.ListenAndServe"]);
+        assert_eq!(start.imports, vec!["net/http"]);
+
+        let routes = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("routes")).expect("routes");
+        assert_eq!(routes.visibility, Some(Visibility::Private));
+        assert_eq!(routes.calls, vec!["HandleFunc"]);
+    }
+
+    #[test]
+    fn generic_receivers_bind_their_base_type_and_type_parameters() {
+        let source = r#"package list
+
+type (
+	List[T any] struct {
+		items []T
+	}
+	Pair[K comparable, V any] struct {
+		Key   K
+		Value V
+	}
+)
+
+func (l *List[T]) Push(item T) {
+	l.grow(1)
+	l.items = append(l.items, item)
+}
+
+func (l *List[T]) grow(n int) {}
+
+func (p Pair[K, V]) Swap() Pair[V, K] {
+	return Pair[V, K]{Key: p.Value, Value: p.Key}
+}
+
+func (List[T]) Kind() string { return "list" }
+
+func Map[T, U any](items []T, f func(T) U) *List[U] {
+	return nil
+}
+"#;
+        let result = parse("pkg/list/list.go", source);
+        let chunk = |name: &str| result.chunks.iter().find(|c| c.symbol.as_deref() == Some(name)).expect(name);
+
+        // Grouped specs split, each keeping its own type parameters out of its refs.
+        let pair = chunk("Pair");
+        assert_eq!(pair.ast_kind, Some(AstNodeKind::Type));
+        assert!(pair.content.starts_with("Pair[K comparable, V any]"), "{}", pair.content);
+        assert!(pair.type_refs.is_empty(), "{:?}", pair.type_refs);
+
+        let push = chunk("Push");
+        assert_eq!(push.qualified_name.as_deref(), Some("List.Push"));
+        assert_eq!(push.parent_symbol.as_deref(), Some("List"));
+        assert_eq!(push.calls, vec!["List.grow", "append"]);
+        assert_eq!(push.type_refs, vec!["List"]);
+
+        let swap = chunk("Swap");
+        assert_eq!(swap.qualified_name.as_deref(), Some("Pair.Swap"));
+        assert_eq!(swap.type_refs, vec!["Pair"]);
+
+        // A receiver without a name still qualifies the method.
+        let kind = chunk("Kind");
+        assert_eq!(kind.qualified_name.as_deref(), Some("List.Kind"));
+        assert_eq!(kind.ast_kind, Some(AstNodeKind::Method));
+
+        let map = chunk("Map");
+        assert_eq!(map.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(map.signature.as_deref(), Some("func Map[T, U any](items []T, f func(T) U) *List[U]"));
+        assert_eq!(map.type_refs, vec!["List"]);
+    }
+
+    #[test]
+    fn test_functions_in_test_files_are_tests() {
+        let source = "package server\n\nimport \"testing\"\n\nfunc TestStart(t *testing.T) {\n\tNewServer()\n}\n";
+        let result = parse("server_test.go", source);
+        let test = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("TestStart")).expect("test");
+        assert_eq!(test.ast_kind, Some(AstNodeKind::Test));
+        assert_eq!(test.module_path.as_deref(), Some("server"));
+        assert_eq!(test.type_refs, vec!["testing.T"]);
+    }
+}
@@ -0,0 +1,425 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, leading_doc_comment, node_text, push_unique, referenced_imports, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for Java: packages, nested types, members and annotations.
+pub(crate) struct JavaAdapter;
+
+impl LanguageAdapter for JavaAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Java)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Java)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_java_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_java_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_java_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::Java)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_java::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let package = package_name(root, text);
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        imports: collect_file_imports(pack, root, text),
+        package,
+    };
+    let mut drafts = Vec::new();
+    collect_declarations(root, &context, &Scope::default(), &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+fn package_name(root: Node, text: &str) -> Option<String> {
+    let mut cursor = root.walk();
+    let declaration = root
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "package_declaration")?;
+    let mut cursor = declaration.walk();
+    let name = declaration
+        .named_children(&mut cursor)
+        .find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))?;
+    node_text(name, text).map(|name| name.split_whitespace().collect())
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    imports: Vec<ImportBinding>,
+    package: Option<String>,
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone, Default)]
+struct Scope {
+    /// Enclosing type names, outermost first.
+    types: Vec<String>,
+    in_interface: bool,
+    generics: Vec<String>,
+}
+
+#[cfg(feature = "treesitter")]
+fn collect_declarations(node: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let type_kind = match child.kind() {
+            "class_declaration" | "record_declaration" => Some(AstNodeKind::Class),
+            "interface_declaration" | "annotation_type_declaration" => Some(AstNodeKind::Interface),
+            "enum_declaration" => Some(AstNodeKind::Enum),
+            _ => None,
+        };
+        if let Some(type_kind) = type_kind {
+            let Some(draft) = draft_from_declaration(child, type_kind, context, scope) else {
+                continue;
+            };
+            let mut inner = scope.clone();
+            inner.types.push(draft.symbol.clone().unwrap_or_default());
+            inner.in_interface = type_kind == AstNodeKind::Interface;
+            inner.generics.extend(type_parameter_names(child, context.text));
+            drafts.push(draft);
+            if let Some(body) = child.child_by_field_name("body") {
+                collect_declarations(body, context, &inner, drafts);
+            }
+            continue;
+        }
+
+        match child.kind() {
+            "method_declaration" | "constructor_declaration" if !scope.types.is_empty() => {
+                let kind = if has_test_annotation(child, context.text) {
+                    AstNodeKind::Test
+                } else {
+                    AstNodeKind::Method
+                };
+                drafts.extend(draft_from_declaration(child, kind, context, scope));
+            }
+            // Enum constants with bodies and other wrappers keep their members one level down.
+            "enum_body_declarations" => collect_declarations(child, context, scope, drafts),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_declaration(
+    node: Node,
+    ast_kind: AstNodeKind,
+    context: &FileContext,
+    scope: &Scope,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = node_text(node.child_by_field_name("name")?, text)?.to_string();
+    let content = node_text(node, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let is_type = matches!(ast_kind, AstNodeKind::Class | AstNodeKind::Interface | AstNodeKind::Enum);
+    let qualified_name = scope
+        .types
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut generics = scope.generics.clone();
+    generics.extend(type_parameter_names(node, text));
+    let mut type_refs = extract_type_refs(node, context, &generics);
+    if is_type {
+        type_refs.retain(|type_ref| type_ref != &symbol);
+    }
+    let body_start = node.child_by_field_name("body").map(|body| body.start_byte());
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(node, text, body_start),
+        parent_symbol: scope.types.last().cloned(),
+        imports: referenced_imports(&context.imports, node, text, &["identifier", "type_identifier"]),
+        exports: Vec::new(),
+        calls: extract_calls(node, context, scope.types.last().map(String::as_str)),
+        method_calls: Vec::new(),
+        type_refs,
+        doc_summary: leading_doc_comment(node, text, &["block_comment", "line_comment"]),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: context.package.clone(),
+        visibility: Some(extract_visibility(node, text, scope)),
+        log_level: None,
+    })
+}
+
+#[cfg(feature = "treesitter")]
+fn modifiers(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let found = node.named_children(&mut cursor).find(|child| child.kind() == "modifiers");
+    found
+}
+
+/// `public` → Pub, `protected` and package-private → Crate, `private` → Private.
+#[cfg(feature = "treesitter")]
+fn extract_visibility(node: Node, text: &str, scope: &Scope) -> Visibility {
+    let keywords = modifiers(node)
+        .and_then(|modifiers| node_text(modifiers, text))
+        .unwrap_or("");
+    let has = |keyword: &str| keywords.split_whitespace().any(|word| word == keyword);
+    if has("public") || (scope.in_interface && !has("private")) {
+        Visibility::Pub
+    } else if has("private") {
+        Visibility::Private
+    } else {
+        Visibility::Crate
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn has_test_annotation(node: Node, text: &str) -> bool {
+    let Some(modifiers) = modifiers(node) else {
+        return false;
+    };
+    let mut cursor = modifiers.walk();
+    let found = modifiers.named_children(&mut cursor).any(|annotation| {
+        matches!(annotation.kind(), "marker_annotation" | "annotation")
+            && annotation
+                .child_by_field_name("name")
+                .and_then(|name| node_text(name, text))
+                .map(|name| name.rsplit('.').next().unwrap_or(name))
+                .is_some_and(|name| matches!(name, "Test" | "ParameterizedTest" | "RepeatedTest" | "TestFactory"))
+    });
+    found
+}
+
+#[cfg(feature = "treesitter")]
+fn type_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    let names = params
+        .named_children(&mut cursor)
+        .filter(|param| param.kind() == "type_parameter")
+        .filter_map(|param| {
+            let mut param_cursor = param.walk();
+            let name = param
+                .named_children(&mut param_cursor)
+                .find(|child| child.kind() == "type_identifier");
+            name.and_then(|name| node_text(name, text)).map(str::to_string)
+        })
+        .collect();
+    names
+}
+
+/// Single-type and static imports bind their last segment; on-demand imports bind nothing.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> Vec<ImportBinding> {
+    let mut bindings = Vec::new();
+    for (_, import) in captures(&pack.imports, root, text) {
+        let mut cursor = import.walk();
+        if import.named_children(&mut cursor).any(|child| child.kind() == "asterisk") {
+            continue;
+        }
+        let mut cursor = import.walk();
+        let Some(path) = import
+            .named_children(&mut cursor)
+            .find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))
+            .and_then(|name| node_text(name, text))
+            .map(|name| name.split_whitespace().collect::<String>())
+        else {
+            continue;
+        };
+        let local = path.rsplit('.').next().unwrap_or(&path).to_string();
+        bindings.push(ImportBinding { local, path });
+    }
+    bindings
+}
+
+/// Invocations as `Type.method` for `this`/static receivers, else the bare method name;
+/// constructor calls as the constructed type.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, class: Option<&str>) -> Vec<String> {
+    let text = context.text;
+    let mut calls = Vec::new();
+    for (capture, callee) in captures(&context.pack.calls, node, text) {
+        let path = match capture {
+            "call" => {
+                let Some(name) = callee.child_by_field_name("name").and_then(|name| node_text(name, text)) else {
+                    continue;
+                };
+                let object = callee.child_by_field_name("object");
+                match (object.map(|object| object.kind()), object.and_then(|object| node_text(object, text))) {
+                    (None, _) | (Some("this"), _) => match class {
+                        Some(class) => format!("{class}.{name}"),
+                        None => name.to_string(),
+                    },
+                    (Some("identifier"), Some(receiver)) if receiver.starts_with(char::is_uppercase) => {
+                        format!("{receiver}.{name}")
+                    }
+                    _ => name.to_string(),
+                }
+            }
+            "construct" => match node_text(callee, text) {
+                Some(ty) => ty.split('<').next().unwrap_or(ty).trim().to_string(),
+                None => continue,
+            },
+            _ => continue,
+        };
+        push_unique(&mut calls, &path);
+    }
+    calls
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String]) -> Vec<String> {
+    let text = context.text;
+    let mut refs = Vec::new();
+    for (_, type_node) in captures(&context.pack.types, node, text) {
+        // Segments of `a.b.Type` are covered by the enclosing scoped capture.
+        if type_node
+            .parent()
+            .is_some_and(|parent| parent.kind() == "scoped_type_identifier")
+        {
+            continue;
+        }
+        let Some(name) = node_text(type_node, text) else {
+            continue;
+        };
+        let name: String = name.split_whitespace().collect();
+        // `java.lang` types every file mentions carry no project relationship.
+        if matches!(
+            name.as_str(),
+            "String" | "Object" | "Integer" | "Long" | "Short" | "Byte" | "Double" | "Float" | "Boolean"
+                | "Character" | "Void"
+        ) || generics.iter().any(|generic| generic == &name)
+        {
+            continue;
+        }
+        push_unique(&mut refs, &name);
+    }
+    refs
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(LanguageId::Java),
+        };
+        JavaAdapter.parse(&request).expect("java parse result")
+    }
+
+    #[test]
+    fn packages_members_and_tests_are_enriched() {
+        let source = r#"package com.acme.auth;
+
+import com.acme.store.TokenStore;
+import java.util.*;
+
+/** Issues and verifies tokens. Thread-safe. */
+public class AuthService<T> extends BaseService implements Verifier, Closeable {
+    private final TokenStore store;
+
+    protected Claims login(String user, T hint) {
+        validate(user);
+        return Claims.of(store.find(user));
+    }
+
+    void validate(String user) {}
+
+    interface Hook {
+        void fire();
+    }
+
+    @Test
+    void loginWorks() {
+        new AuthService<String>();
+    }
+}
+"#;
+        let result = parse("src/main/java/com/acme/auth/AuthService.java", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+
+        let class = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("AuthService")).expect("class");
+        assert_eq!(class.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(class.module_path.as_deref(), Some("com.acme.auth"));
+        assert_eq!(class.visibility, Some(Visibility::Pub));
+        assert_eq!(class.doc_summary.as_deref(), Some("Issues and verifies tokens"));
+        for base in ["BaseService", "Verifier", "Closeable", "TokenStore"] {
+            assert!(class.type_refs.contains(&base.to_string()), "missing type ref {base}");
+        }
+        assert_eq!(class.imports, vec!["com.acme.store.TokenStore"]);
+
+        let login = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("login")).expect("login");
+        assert_eq!(login.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(login.qualified_name.as_deref(), Some("AuthService.login"));
+        assert_eq!(login.parent_symbol.as_deref(), Some("AuthService"));
+        assert_eq!(login.visibility, Some(Visibility::Crate));
+        assert_eq!(login.signature.as_deref(), Some("protected Claims login(String user, T hint)"));
+        assert_eq!(login.calls, vec!["AuthService.validate", "Claims.of", "find"]);
+        assert_eq!(login.type_refs, vec!["Claims"]);
+
+        let validate = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("validate")).expect("validate");
+        assert_eq!(validate.visibility, Some(Visibility::Crate));
+
+        let fire = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("fire")).expect("fire");
+        assert_eq!(fire.qualified_name.as_deref(), Some("AuthService.Hook.fire"));
+        assert_eq!(fire.visibility, Some(Visibility::Pub));
+
+        let test = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("loginWorks")).expect("test");
+        assert_eq!(test.ast_kind, Some(AstNodeKind::Test));
+        assert_eq!(test.calls, vec!["AuthService"]);
+    }
+}
@@ -0,0 +1,435 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, node_text, push_unique, referenced_imports, summarize_doc, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for Python: classes, methods, decorators, docstrings and annotations.
+pub(crate) struct PythonAdapter;
+
+impl LanguageAdapter for PythonAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Python)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Python)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_python_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Derive the dotted module path for a Python file, treating `src/` as a layout root.
+pub(crate) fn python_module_path(path: &str) -> String {
+    let normalized = path.replace('\\', "/");
+    let relative = match normalized.rfind("src/") {
+        Some(idx) if idx == 0 || normalized[..idx].ends_with('/') => &normalized[idx + 4..],
+        _ => normalized.as_str(),
+    };
+    let stem = relative
+        .strip_suffix(".pyi")
+        .or_else(|| relative.strip_suffix(".py"))
+        .unwrap_or(relative);
+    let mut segments: Vec<&str> = stem.split('/').filter(|segment| !segment.is_empty()).collect();
+    if segments.last() == Some(&"__init__") {
+        segments.pop();
+    }
+    segments.join(".")
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_python_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_python_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::Python)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_python::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        imports: collect_file_imports(pack, root, text),
+        module_path: python_module_path(path),
+    };
+    let mut drafts = Vec::new();
+    collect_definitions(root, &context, &[], &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    imports: Vec<ImportBinding>,
+    module_path: String,
+}
+
+/// Walk a module or class body; `classes` holds the enclosing class names, outermost first.
+#[cfg(feature = "treesitter")]
+fn collect_definitions(node: Node, context: &FileContext, classes: &[String], drafts: &mut Vec<ChunkDraft>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let definition = match child.kind() {
+            "function_definition" | "class_definition" => child,
+            "decorated_definition" => match child.child_by_field_name("definition") {
+                Some(definition) => definition,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let Some(draft) = draft_from_definition(child, definition, context, classes) else {
+            continue;
+        };
+
+        let class_name = (definition.kind() == "class_definition")
+            .then(|| draft.symbol.clone())
+            .flatten();
+        drafts.push(draft);
+        if let (Some(class_name), Some(body)) = (class_name, definition.child_by_field_name("body")) {
+            let mut nested = classes.to_vec();
+            nested.push(class_name);
+            collect_definitions(body, context, &nested, drafts);
+        }
+    }
+}
+
+/// Build a draft spanning `outer` (including decorators) for the `definition` it wraps.
+#[cfg(feature = "treesitter")]
+fn draft_from_definition(
+    outer: Node,
+    definition: Node,
+    context: &FileContext,
+    classes: &[String],
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = definition
+        .child_by_field_name("name")
+        .and_then(|name| node_text(name, text))?
+        .to_string();
+    let content = node_text(outer, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let ast_kind = if definition.kind() == "class_definition" {
+        if symbol.starts_with("Test") {
+            AstNodeKind::Test
+        } else {
+            AstNodeKind::Class
+        }
+    } else if symbol.starts_with("test_") || symbol == "test" {
+        AstNodeKind::Test
+    } else if classes.is_empty() {
+        AstNodeKind::Function
+    } else {
+        AstNodeKind::Method
+    };
+
+    let qualified_name = classes
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let body = definition.child_by_field_name("body");
+    let signature = header_signature(definition, text, body.map(|body| body.start_byte()))
+        .map(|signature| signature.trim_end_matches(':').trim().to_string());
+    let visibility = if symbol.starts_with('_') && !(symbol.starts_with("__") && symbol.ends_with("__")) {
+        Visibility::Private
+    } else {
+        Visibility::Pub
+    };
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row + 1,
+        end_line: outer.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature,
+        parent_symbol: classes.last().cloned(),
+        imports: referenced_imports(&context.imports, outer, text, &["identifier"]),
+        exports: Vec::new(),
+        calls: extract_calls(outer, context, classes.last().map(String::as_str)),
+        method_calls: Vec::new(),
+        type_refs: extract_type_refs(definition, context),
+        doc_summary: body.and_then(|body| extract_docstring(body, text)),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: Some(context.module_path.clone()),
+        visibility: Some(visibility),
+        log_level: None,
+    })
+}
+
+/// First sentence of the docstring opening `body`, if any.
+#[cfg(feature = "treesitter")]
+fn extract_docstring(body: Node, text: &str) -> Option<String> {
+    let first = body.named_child(0)?;
+    if first.kind() != "expression_statement" {
+        return None;
+    }
+    let string = first.named_child(0).filter(|node| node.kind() == "string")?;
+    let raw = node_text(string, text)?;
+    let unprefixed = raw.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    let inner = ["\"\"\"", "'''", "\"", "'"]
+        .iter()
+        .find_map(|quote| {
+            unprefixed
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        })
+        .unwrap_or(unprefixed);
+    let lines: Vec<String> = inner
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    summarize_doc(&lines)
+}
+
+/// Expand `import` and `from ... import` statements into local bindings.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> Vec<ImportBinding> {
+    let mut bindings = Vec::new();
+    for (capture, node) in captures(&pack.imports, root, text) {
+        let module = match capture {
+            "import" => None,
+            "import_from" => match node
+                .child_by_field_name("module_name")
+                .and_then(|module| node_text(module, text))
+            {
+                Some(module) => Some(module.split_whitespace().collect::<String>()),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        let mut cursor = node.walk();
+        for name in node.children_by_field_name("name", &mut cursor) {
+            let (target, alias) = match name.kind() {
+                "aliased_import" => (
+                    name.child_by_field_name("name").and_then(|target| node_text(target, text)),
+                    name.child_by_field_name("alias").and_then(|alias| node_text(alias, text)),
+                ),
+                _ => (node_text(name, text), None),
+            };
+            let Some(target) = target.map(|target| target.split_whitespace().collect::<String>()) else {
+                continue;
+            };
+            let (local, path) = match &module {
+                // `from pkg.mod import name` binds `name`.
+                Some(module) => {
+                    let separator = if module.ends_with('.') { "" } else { "." };
+                    let local = alias.map(str::to_string).unwrap_or_else(|| target.clone());
+                    (local, format!("{module}{separator}{target}"))
+                }
+                // `import pkg.mod` binds `pkg`; `import pkg.mod as m` binds `m`.
+                None => {
+                    let local = alias
+                        .map(str::to_string)
+                        .unwrap_or_else(|| target.split('.').next().unwrap_or(&target).to_string());
+                    (local, target)
+                }
+            };
+            bindings.push(ImportBinding { local, path });
+        }
+    }
+    bindings
+}
+
+/// Dotted callee paths (`self.` rewritten to the enclosing class) plus decorator names.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, class: Option<&str>) -> Vec<String> {
+    let mut calls = Vec::new();
+    for (capture, callee) in captures(&context.pack.calls, node, context.text) {
+        let Some(path) = callee_path(callee, context.text) else {
+            continue;
+        };
+        if capture == "decorator" && matches!(path.as_str(), "property" | "staticmethod" | "classmethod") {
+            continue;
+        }
+        let path = match (class, path.split_once('.')) {
+            (Some(class), Some(("self" | "cls", rest))) => format!("{class}.{rest}"),
+            _ => path,
+        };
+        push_unique(&mut calls, &path);
+    }
+    calls
+}
+
+#[cfg(feature = "treesitter")]
+fn callee_path(node: Node, text: &str) -> Option<String> {
+    let compact: String = node_text(node, text)?.split_whitespace().collect();
+    if compact
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+    {
+        return Some(compact);
+    }
+    // Chained or computed receivers (`get_client().send`) keep only the attribute name.
+    node.child_by_field_name("attribute")
+        .and_then(|attribute| node_text(attribute, text))
+        .map(str::to_string)
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext) -> Vec<String> {
+    let mut refs = Vec::new();
+    for (capture, type_node) in captures(&context.pack.types, node, context.text) {
+        match capture {
+            "annotation" => collect_annotation_names(type_node, context.text, &mut refs),
+            "base" => push_type_ref(&mut refs, node_text(type_node, context.text)),
+            _ => {}
+        }
+    }
+    refs
+}
+
+#[cfg(feature = "treesitter")]
+fn collect_annotation_names(node: Node, text: &str, refs: &mut Vec<String>) {
+    match node.kind() {
+        "identifier" | "attribute" => push_type_ref(refs, node_text(node, text)),
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_annotation_names(child, text, refs);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn push_type_ref(refs: &mut Vec<String>, name: Option<&str>) {
+    let Some(name) = name else {
+        return;
+    };
+    let name: String = name.split_whitespace().collect();
+    // Builtins and `typing` containers carry no project-level relationship.
+    if matches!(
+        name.as_str(),
+        "str" | "int" | "float" | "bool" | "bytes" | "None" | "object" | "list" | "dict" | "set"
+            | "tuple" | "frozenset" | "type" | "Any" | "Optional" | "Union" | "List" | "Dict"
+            | "Set" | "Tuple" | "Callable" | "Iterable" | "Iterator" | "Sequence" | "Mapping"
+            | "Literal"
+    ) {
+        return;
+    }
+    push_unique(refs, &name);
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(LanguageId::Python),
+        };
+        PythonAdapter.parse(&request).expect("python parse result")
+    }
+
+    #[test]
+    fn module_path_follows_package_layout() {
+        assert_eq!(python_module_path("src/pkg/auth/service.py"), "pkg.auth.service");
+        assert_eq!(python_module_path("pkg/__init__.py"), "pkg");
+        assert_eq!(python_module_path("tools/run.py"), "tools.run");
+    }
+
+    #[test]
+    fn classes_methods_and_tests_are_enriched() {
+        let source = r#"import os.path
+from .tokens import Claims, sign as sign_token
+
+class AuthService(BaseService):
+    """Issues and verifies tokens. More detail here."""
+
+    @cached(ttl=60)
+    def login(self, user: str, store: TokenStore) -> Optional[Claims]:
+        path = os.path.join("a", "b")
+        return self._issue(sign_token(user))
+
+    def _issue(self, token):
+        return token
+
+def test_login():
+    assert AuthService().login("u", None)
+
+class TestAuth:
+    pass
+"#;
+        let result = parse("app/auth.py", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+
+        let class = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("AuthService")).expect("class");
+        assert_eq!(class.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(class.doc_summary.as_deref(), Some("Issues and verifies tokens"));
+        assert_eq!(class.module_path.as_deref(), Some("app.auth"));
+        assert!(class.type_refs.contains(&"BaseService".to_string()));
+
+        let login = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("login")).expect("login");
+        assert_eq!(login.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(login.qualified_name.as_deref(), Some("AuthService.login"));
+        assert_eq!(login.parent_symbol.as_deref(), Some("AuthService"));
+        assert_eq!(login.visibility, Some(Visibility::Pub));
+        assert_eq!(
+            login.signature.as_deref(),
+            Some("def login(self, user: str, store: TokenStore) -> Optional[Claims]")
+        );
+        assert!(login.content.starts_with("@cached"));
+        assert_eq!(login.type_refs, vec!["TokenStore", "Claims"]);
+        assert_eq!(login.imports, vec!["os.path", ".tokens.Claims", ".tokens.sign"]);
+        for call in ["cached", "os.path.join", "AuthService._issue", "sign_token"] {
+            assert!(login.calls.contains(&call.to_string()), "missing call {call}");
+        }
+
+        let issue = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("_issue")).expect("_issue");
+        assert_eq!(issue.visibility, Some(Visibility::Private));
+
+        let test_fn = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("test_login")).expect("test fn");
+        assert_eq!(test_fn.ast_kind, Some(AstNodeKind::Test));
+        let test_class = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("TestAuth")).expect("test class");
+        assert_eq!(test_class.ast_kind, Some(AstNodeKind::Test));
+    }
+}
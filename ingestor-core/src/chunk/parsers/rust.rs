@@ -0,0 +1,652 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, node_text, push_unique, referenced_imports, summarize_doc, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for Rust: items, impl methods, `use` trees, calls and type paths.
+pub(crate) struct RustAdapter;
+
+impl LanguageAdapter for RustAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::Rust)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::Rust)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_rust_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// Derive the `crate::…` module path for a Rust source file from its location under `src/`.
+pub(crate) fn rust_module_path(path: &str) -> String {
+    let normalized = path.replace('\\', "/");
+    let relative = match normalized.rfind("src/") {
+        Some(idx) if idx == 0 || normalized[..idx].ends_with('/') => &normalized[idx + 4..],
+        _ => return "crate".to_string(),
+    };
+
+    let mut segments: Vec<&str> = relative.split('/').filter(|segment| !segment.is_empty()).collect();
+    if segments.first() == Some(&"bin") {
+        return "crate".to_string();
+    }
+    let Some(file) = segments.pop() else {
+        return "crate".to_string();
+    };
+    let stem = file.strip_suffix(".rs").unwrap_or(file);
+    let is_root = segments.is_empty() && matches!(stem, "lib" | "main");
+    if !is_root && stem != "mod" {
+        segments.push(stem);
+    }
+
+    std::iter::once("crate")
+        .chain(segments)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_rust_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_rust_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let pack = query_pack(&LanguageId::Rust)?;
+    let mut parser = Parser::new();
+    parser.set_language(tree_sitter_rust::language()).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        imports: collect_file_imports(pack, root, text),
+    };
+    let scope = Scope {
+        parents: Vec::new(),
+        module_path: rust_module_path(path),
+        member_of: None,
+        generics: Vec::new(),
+    };
+    let mut drafts = Vec::new();
+    collect_items(root, &context, &scope, &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    imports: Vec<ImportBinding>,
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MemberOf {
+    Trait,
+    Impl { trait_impl: bool },
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone)]
+struct Scope {
+    /// Enclosing inline modules, impl types and traits, outermost first.
+    parents: Vec<String>,
+    module_path: String,
+    member_of: Option<MemberOf>,
+    /// Generic parameters in scope; never reported as type references.
+    generics: Vec<String>,
+}
+
+#[cfg(feature = "treesitter")]
+fn collect_items(node: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "function_item" | "function_signature_item" | "struct_item" | "enum_item" | "union_item"
+            | "type_item" | "const_item" | "static_item" => {
+                if let Some(draft) = draft_from_item(child, context, scope) {
+                    drafts.push(draft);
+                }
+            }
+            "trait_item" => {
+                let Some(draft) = draft_from_item(child, context, scope) else {
+                    continue;
+                };
+                let mut inner = nested_scope(scope, draft.symbol.clone().unwrap_or_default(), None);
+                inner.member_of = Some(MemberOf::Trait);
+                inner.generics.extend(type_parameter_names(child, context.text));
+                drafts.push(draft);
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_items(body, context, &inner, drafts);
+                }
+            }
+            "impl_item" => {
+                let Some(type_name) = child
+                    .child_by_field_name("type")
+                    .and_then(|ty| base_type_name(ty, context.text))
+                else {
+                    continue;
+                };
+                let mut inner = nested_scope(scope, type_name, None);
+                inner.member_of = Some(MemberOf::Impl {
+                    trait_impl: child.child_by_field_name("trait").is_some(),
+                });
+                inner.generics.extend(type_parameter_names(child, context.text));
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_items(body, context, &inner, drafts);
+                }
+            }
+            "mod_item" => {
+                let Some(draft) = draft_from_item(child, context, scope) else {
+                    continue;
+                };
+                let name = draft.symbol.clone().unwrap_or_default();
+                let module_path = format!("{}::{name}", scope.module_path);
+                drafts.push(draft);
+                if let Some(body) = child.child_by_field_name("body") {
+                    let inner = nested_scope(scope, name, Some(module_path));
+                    collect_items(body, context, &inner, drafts);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn nested_scope(scope: &Scope, parent: String, module_path: Option<String>) -> Scope {
+    let mut inner = scope.clone();
+    inner.parents.push(parent);
+    inner.member_of = None;
+    if let Some(module_path) = module_path {
+        inner.module_path = module_path;
+    }
+    inner
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_item(node: Node, context: &FileContext, scope: &Scope) -> Option<ChunkDraft> {
+    let text = context.text;
+    let symbol = node
+        .child_by_field_name("name")
+        .and_then(|name| node_text(name, text))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())?
+        .to_string();
+    let content = node_text(node, text)?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let ast_kind = classify_item(node, text, scope)?;
+    let qualified_name = scope
+        .parents
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join("::");
+
+    let mut generics = scope.generics.clone();
+    generics.extend(type_parameter_names(node, text));
+
+    let self_type = match scope.member_of {
+        Some(MemberOf::Impl { .. }) => scope.parents.last().map(String::as_str),
+        _ => None,
+    };
+    let (calls, method_calls) = extract_calls(node, context, self_type);
+    let mut type_refs = extract_type_refs(node, context, &generics);
+    if !matches!(ast_kind, AstNodeKind::Function | AstNodeKind::Method | AstNodeKind::Test) {
+        type_refs.retain(|type_ref| type_ref != &symbol);
+    }
+
+    let mut imports = Vec::new();
+    if node.kind() == "mod_item" && node.child_by_field_name("body").is_none() {
+        push_unique(&mut imports, &format!("self::{symbol}"));
+    }
+    for path in referenced_imports(&context.imports, node, text, &["identifier", "type_identifier"]) {
+        push_unique(&mut imports, &path);
+    }
+
+    let body_start = match node.kind() {
+        "const_item" | "static_item" | "type_item" => None,
+        _ => node.child_by_field_name("body").map(|body| body.start_byte()),
+    };
+
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: header_signature(node, text, body_start),
+        parent_symbol: scope.parents.last().cloned(),
+        imports,
+        exports: Vec::new(),
+        calls,
+        method_calls,
+        type_refs,
+        doc_summary: extract_doc_comment(node, text),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: Some(scope.module_path.clone()),
+        visibility: extract_visibility(node, text, scope),
+        log_level: None,
+    })
+}
+
+#[cfg(feature = "treesitter")]
+fn classify_item(node: Node, text: &str, scope: &Scope) -> Option<AstNodeKind> {
+    let kind = match node.kind() {
+        "function_item" | "function_signature_item" => {
+            if has_test_attribute(node, text) {
+                AstNodeKind::Test
+            } else if scope.member_of.is_some() {
+                AstNodeKind::Method
+            } else {
+                AstNodeKind::Function
+            }
+        }
+        "struct_item" | "union_item" | "type_item" => AstNodeKind::Type,
+        "enum_item" => AstNodeKind::Enum,
+        "trait_item" => AstNodeKind::Interface,
+        "const_item" => AstNodeKind::Constant,
+        "static_item" => AstNodeKind::Variable,
+        "mod_item" => AstNodeKind::Module,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Name of the type an `impl` block targets, without generic arguments or path prefix.
+#[cfg(feature = "treesitter")]
+fn base_type_name(node: Node, text: &str) -> Option<String> {
+    let node = match node.kind() {
+        "generic_type" => node.child_by_field_name("type")?,
+        _ => node,
+    };
+    let raw = node_text(node, text)?;
+    let name = raw.rsplit("::").next().unwrap_or(raw).trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn type_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        let name = match param.kind() {
+            "type_identifier" => Some(param),
+            "constrained_type_parameter" => param.child_by_field_name("left"),
+            "optional_type_parameter" => param.child_by_field_name("name"),
+            _ => None,
+        };
+        if let Some(name) = name.and_then(|name| node_text(name, text)) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+#[cfg(feature = "treesitter")]
+fn has_test_attribute(node: Node, text: &str) -> bool {
+    let mut cursor = node.prev_sibling();
+    while let Some(prev) = cursor {
+        match prev.kind() {
+            "attribute_item" => {
+                let attr = node_text(prev, text)
+                    .unwrap_or("")
+                    .trim_start_matches("#[")
+                    .trim_end_matches(']');
+                let path = attr.split(['(', ' ']).next().unwrap_or("").trim();
+                if path == "test" || path.ends_with("::test") {
+                    return true;
+                }
+            }
+            "line_comment" | "block_comment" => {}
+            _ => break,
+        }
+        cursor = prev.prev_sibling();
+    }
+    false
+}
+
+/// Collect `///` and `/** */` doc comments above an item, skipping interleaved attributes.
+#[cfg(feature = "treesitter")]
+fn extract_doc_comment(node: Node, text: &str) -> Option<String> {
+    let mut docs = Vec::new();
+    let mut cursor = node.prev_sibling();
+    while let Some(prev) = cursor {
+        match prev.kind() {
+            "attribute_item" => {}
+            "line_comment" => {
+                let raw = node_text(prev, text)?.trim();
+                match raw.strip_prefix("///") {
+                    Some(doc) if !doc.starts_with('/') => docs.push(doc.trim().to_string()),
+                    _ => break,
+                }
+            }
+            "block_comment" => {
+                let raw = node_text(prev, text)?.trim();
+                let Some(doc) = raw.strip_prefix("/**") else {
+                    break;
+                };
+                let lines = doc
+                    .trim_end_matches("*/")
+                    .lines()
+                    .rev()
+                    .map(|line| line.trim().trim_start_matches('*').trim().to_string());
+                docs.extend(lines);
+            }
+            _ => break,
+        }
+        cursor = prev.prev_sibling();
+    }
+    docs.reverse();
+    summarize_doc(&docs)
+}
+
+/// Map `pub` modifiers to [`Visibility`]; trait members and trait-impl members inherit theirs.
+#[cfg(feature = "treesitter")]
+fn extract_visibility(node: Node, text: &str, scope: &Scope) -> Option<Visibility> {
+    let mut cursor = node.walk();
+    let modifier = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "visibility_modifier");
+    match modifier.and_then(|modifier| node_text(modifier, text)) {
+        Some(modifier) => {
+            let modifier: String = modifier.split_whitespace().collect();
+            match modifier.as_str() {
+                "pub" => Some(Visibility::Pub),
+                "pub(self)" => Some(Visibility::Private),
+                _ => Some(Visibility::Crate),
+            }
+        }
+        None => match scope.member_of {
+            Some(MemberOf::Trait) | Some(MemberOf::Impl { trait_impl: true }) => None,
+            _ => Some(Visibility::Private),
+        },
+    }
+}
+
+/// Expand every `use` tree and body-less `mod` in the file into local bindings.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> Vec<ImportBinding> {
+    let mut bindings = Vec::new();
+    for (capture, node) in captures(&pack.imports, root, text) {
+        match capture {
+            "import" => expand_use_tree(node, "", text, &mut bindings),
+            "module" if node.child_by_field_name("body").is_none() => {
+                if let Some(name) = node.child_by_field_name("name").and_then(|name| node_text(name, text)) {
+                    bindings.push(ImportBinding {
+                        local: name.to_string(),
+                        path: format!("self::{name}"),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    bindings
+}
+
+#[cfg(feature = "treesitter")]
+fn expand_use_tree(node: Node, prefix: &str, text: &str, bindings: &mut Vec<ImportBinding>) {
+    let join = |segment: &str| {
+        let segment: String = segment.split_whitespace().collect();
+        if prefix.is_empty() {
+            segment
+        } else {
+            format!("{prefix}::{segment}")
+        }
+    };
+
+    match node.kind() {
+        "identifier" | "scoped_identifier" | "crate" | "super" | "metavariable" => {
+            let Some(raw) = node_text(node, text) else {
+                return;
+            };
+            let path = join(raw);
+            let local = path.rsplit("::").next().unwrap_or(&path).to_string();
+            bindings.push(ImportBinding { local, path });
+        }
+        // `use foo::{self, ...}` binds `foo` itself.
+        "self" if !prefix.is_empty() => {
+            let local = prefix.rsplit("::").next().unwrap_or(prefix).to_string();
+            bindings.push(ImportBinding {
+                local,
+                path: prefix.to_string(),
+            });
+        }
+        "use_as_clause" => {
+            let path = node.child_by_field_name("path").and_then(|path| node_text(path, text));
+            let alias = node.child_by_field_name("alias").and_then(|alias| node_text(alias, text));
+            if let (Some(path), Some(alias)) = (path, alias) {
+                if alias != "_" {
+                    bindings.push(ImportBinding {
+                        local: alias.to_string(),
+                        path: join(path),
+                    });
+                }
+            }
+        }
+        "scoped_use_list" => {
+            let next_prefix = node
+                .child_by_field_name("path")
+                .and_then(|path| node_text(path, text))
+                .map(join)
+                .unwrap_or_else(|| prefix.to_string());
+            if let Some(list) = node.child_by_field_name("list") {
+                expand_use_tree(list, &next_prefix, text, bindings);
+            }
+        }
+        "use_list" => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                expand_use_tree(child, prefix, text, bindings);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Callee paths as written (`Auth::new`, `util::sha256_hex`), bare method names, and macro names,
+/// with the method names never also called as free functions.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, self_type: Option<&str>) -> (Vec<String>, Vec<String>) {
+    let mut calls = Vec::new();
+    let mut free = Vec::new();
+    let mut methods = Vec::new();
+    for (_, callee) in captures(&context.pack.calls, node, context.text) {
+        let Some(raw) = node_text(callee, context.text) else {
+            continue;
+        };
+        let path: String = raw.split_whitespace().collect();
+        let path = match (path.strip_prefix("Self::"), self_type) {
+            (Some(rest), Some(self_type)) => format!("{self_type}::{rest}"),
+            _ => path,
+        };
+        let on_receiver = callee.parent().is_some_and(|parent| parent.kind() == "field_expression");
+        push_unique(if on_receiver { &mut methods } else { &mut free }, &path);
+        push_unique(&mut calls, &path);
+    }
+    methods.retain(|method| !free.contains(method));
+    (calls, methods)
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String]) -> Vec<String> {
+    let mut refs = Vec::new();
+    for (_, type_node) in captures(&context.pack.types, node, context.text) {
+        let Some(name) = node_text(type_node, context.text) else {
+            continue;
+        };
+        // Skip `Self`, in-scope generics and the prelude containers every item mentions.
+        if matches!(name, "Self" | "String" | "Vec" | "Option" | "Result" | "Box")
+            || generics.iter().any(|generic| generic == name)
+        {
+            continue;
+        }
+        push_unique(&mut refs, name);
+    }
+    refs
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::{ingest_files, EdgeKind, FileInput, IngestOptions};
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::Unknown,
+            options: &options,
+            language: Some(LanguageId::Rust),
+        };
+        RustAdapter.parse(&request).expect("rust parse result")
+    }
+
+    #[test]
+    fn module_path_follows_src_layout() {
+        assert_eq!(rust_module_path("src/lib.rs"), "crate");
+        assert_eq!(rust_module_path("src/main.rs"), "crate");
+        assert_eq!(rust_module_path("ingestor-core/src/chunk/generic.rs"), "crate::chunk::generic");
+        assert_eq!(rust_module_path("src/handlers/mod.rs"), "crate::handlers");
+        assert_eq!(rust_module_path("src/bin/tool.rs"), "crate");
+        assert_eq!(rust_module_path("build.rs"), "crate");
+    }
+
+    #[test]
+    fn methods_tests_and_use_trees_are_extracted() {
+        let source = r#"use crate::util::{sha256_hex, short_id as sid};
+use std::collections::HashMap;
+
+/// Authenticates users. Second sentence.
+pub struct Auth {
+    tokens: HashMap<String, Claims>,
+}
+
+impl Auth {
+    pub(crate) fn login<T: Into<String>>(&self, user: T) -> Option<Claims> {
+        let digest = sha256_hex(user.into().as_bytes());
+        Self::lookup(&digest);
+        self.tokens.get(&sid(&digest, 8)).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn login_works() {
+        assert!(true);
+    }
+}
+"#;
+        let result = parse("src/auth.rs", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+
+        let auth = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("Auth")).expect("Auth");
+        assert_eq!(auth.ast_kind, Some(AstNodeKind::Type));
+        assert_eq!(auth.doc_summary.as_deref(), Some("Authenticates users"));
+        assert_eq!(auth.module_path.as_deref(), Some("crate::auth"));
+        assert_eq!(auth.type_refs, vec!["HashMap", "Claims"]);
+        assert_eq!(auth.imports, vec!["std::collections::HashMap"]);
+
+        let login = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("login")).expect("login");
+        assert_eq!(login.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(login.qualified_name.as_deref(), Some("Auth::login"));
+        assert_eq!(login.parent_symbol.as_deref(), Some("Auth"));
+        assert_eq!(login.visibility, Some(Visibility::Crate));
+        assert_eq!(
+            login.signature.as_deref(),
+            Some("pub(crate) fn login<T: Into<String>>(&self, user: T) -> Option<Claims>")
+        );
+        assert!(login.calls.contains(&"sha256_hex".to_string()));
+        assert!(login.calls.contains(&"Auth::lookup".to_string()));
+        assert!(login.calls.contains(&"get".to_string()));
+        assert!(!login.type_refs.contains(&"T".to_string()));
+        assert_eq!(login.imports, vec!["crate::util::sha256_hex", "crate::util::short_id"]);
+
+        let test = result.chunks.iter().find(|c| c.symbol.as_deref() == Some("login_works")).expect("test fn");
+        assert_eq!(test.ast_kind, Some(AstNodeKind::Test));
+        assert_eq!(test.qualified_name.as_deref(), Some("tests::login_works"));
+        assert_eq!(test.module_path.as_deref(), Some("crate::auth::tests"));
+        assert!(test.calls.contains(&"assert".to_string()));
+    }
+
+    #[test]
+    fn ingest_resolves_qualified_rust_edges() {
+        let files = vec![
+            FileInput {
+                path: "src/util.rs".to_string(),
+                data: b"pub fn sha256_hex(input: &[u8]) -> String { String::new() }".to_vec(),
+                mtime_ms: None,
+                fingerprint_sha256: None,
+            },
+            FileInput {
+                path: "src/auth.rs".to_string(),
+                data: b"use crate::util;\npub fn digest() -> String { util::sha256_hex(b\"x\") }".to_vec(),
+                mtime_ms: None,
+                fingerprint_sha256: None,
+            },
+        ];
+        let index = ingest_files(files, IngestOptions::default());
+        let helper = index.chunks.iter().find(|c| c.symbol.as_deref() == Some("sha256_hex")).expect("helper");
+        let digest = index.chunks.iter().find(|c| c.symbol.as_deref() == Some("digest")).expect("digest");
+        // Only chunks with a reference bound by scope rules move up to the StackGraph tier.
+        assert_eq!(helper.resolution_tier, ResolutionTier::QueryPack);
+        assert_eq!(digest.resolution_tier, ResolutionTier::StackGraph);
+        let tiers: Vec<_> = index.files.iter().map(|file| (file.path.as_str(), file.resolution_tier)).collect();
+        assert_eq!(
+            tiers,
+            [("src/auth.rs", ResolutionTier::StackGraph), ("src/util.rs", ResolutionTier::QueryPack)]
+        );
+        let call = index.edges.forward[&digest.id]
+            .iter()
+            .find(|edge| edge.edge_kind == EdgeKind::Calls)
+            .expect("call edge");
+        assert_eq!(call.target_symbol, "util::sha256_hex");
+        assert_eq!(call.target_chunk_id.as_deref(), Some(helper.id.as_str()));
+    }
+}
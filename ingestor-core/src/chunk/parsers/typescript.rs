@@ -0,0 +1,1000 @@
+use crate::chunk::language::{LanguageAdapter, ParseRequest, ParseResult};
+use crate::model::{ChunkKind, LanguageId, ResolutionTier};
+#[cfg(feature = "treesitter")]
+use super::{header_signature, identifier_refs, leading_doc_comment, node_text, push_unique, referenced_imports, ImportBinding};
+#[cfg(feature = "treesitter")]
+use crate::chunk::legacy::{finalize_chunks, ChunkDraft};
+#[cfg(feature = "treesitter")]
+use crate::chunk::query_loader::{captures, query_pack, tsx_query_pack, QueryPack};
+#[cfg(feature = "treesitter")]
+use crate::model::{AstNodeKind, Visibility};
+#[cfg(feature = "treesitter")]
+use tree_sitter::Node;
+
+/// Query-pack adapter for TypeScript: interfaces, type aliases, enums, namespaces,
+/// ambient modules, abstract classes and decorators.
+///
+/// Imports are recorded as `specifier#name`. Type-only imports (`import type`,
+/// `{ type X }`) land in `type_refs` rather than `imports`, so they show up as
+/// `type_users` instead of importers.
+pub(crate) struct TypeScriptAdapter;
+
+impl LanguageAdapter for TypeScriptAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        Some(LanguageId::TypeScript)
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        ResolutionTier::QueryPack
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        request.language == Some(LanguageId::TypeScript)
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_typescript_chunks(request.path, request.text, request.kind)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+#[cfg(not(feature = "treesitter"))]
+fn parse_typescript_chunks(_path: &str, _text: &str, _kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    None
+}
+
+#[cfg(feature = "treesitter")]
+fn parse_typescript_chunks(path: &str, text: &str, kind: ChunkKind) -> Option<Vec<crate::Chunk>> {
+    use tree_sitter::Parser;
+
+    let (language, pack) = if path.ends_with(".tsx") {
+        (tree_sitter_typescript::language_tsx(), tsx_query_pack()?)
+    } else {
+        (tree_sitter_typescript::language_typescript(), query_pack(&LanguageId::TypeScript)?)
+    };
+    let mut parser = Parser::new();
+    parser.set_language(language).ok()?;
+    let tree = parser.parse(text, None)?;
+    let root = tree.root_node();
+
+    let (value_imports, type_imports) = collect_file_imports(pack, root, text);
+    let context = FileContext {
+        text,
+        kind,
+        pack,
+        value_imports,
+        type_imports,
+    };
+    let mut drafts = Vec::new();
+    collect_statements(root, &context, &Scope::default(), &mut drafts);
+    if drafts.is_empty() {
+        return None;
+    }
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(feature = "treesitter")]
+struct FileContext<'a> {
+    text: &'a str,
+    kind: ChunkKind,
+    pack: &'static QueryPack,
+    /// Bindings introduced by value imports.
+    value_imports: Vec<ImportBinding>,
+    /// Bindings introduced by `import type` / `{ type X }`.
+    type_imports: Vec<ImportBinding>,
+}
+
+#[cfg(feature = "treesitter")]
+#[derive(Clone, Default)]
+struct Scope {
+    /// Enclosing namespace and class names, outermost first.
+    parents: Vec<String>,
+    /// Enclosing namespace names only, for `module_path`.
+    namespaces: Vec<String>,
+    class: Option<String>,
+    generics: Vec<String>,
+}
+
+/// One declaration to draft: `outer` spans export/declare keywords and decorators,
+/// `node` is the declaration itself.
+#[cfg(feature = "treesitter")]
+struct Declaration<'tree> {
+    outer: Node<'tree>,
+    node: Node<'tree>,
+    exported: bool,
+    decorators: Vec<Node<'tree>>,
+}
+
+/// Walk a program or namespace body, unwrapping `export`, `declare` and `namespace`.
+/// Overload signatures fold into the declaration that follows them under the same
+/// name, so one chunk spans the signatures and the implementation.
+#[cfg(feature = "treesitter")]
+fn collect_statements(node: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    // The latest signature of an overload run, spanning from the first.
+    let mut overloads: Option<Declaration> = None;
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let declaration = match child.kind() {
+            "export_statement" => {
+                let Some(inner) = child
+                    .child_by_field_name("declaration")
+                    .or_else(|| child.child_by_field_name("value"))
+                else {
+                    if scope.namespaces.is_empty() {
+                        drafts.extend(reexport_draft(child, context).or_else(|| export_draft(child, context)));
+                    }
+                    continue;
+                };
+                // `export declare function f(): T;` wraps the declaration once more.
+                let inner = match inner.kind() {
+                    "ambient_declaration" => inner.named_child(0).unwrap_or(inner),
+                    _ => inner,
+                };
+                let mut decorators = Vec::new();
+                let mut export_cursor = child.walk();
+                decorators.extend(
+                    child
+                        .named_children(&mut export_cursor)
+                        .filter(|node| node.kind() == "decorator"),
+                );
+                Declaration {
+                    outer: child,
+                    node: inner,
+                    exported: true,
+                    decorators,
+                }
+            }
+            "ambient_declaration" => {
+                let mut ambient_cursor = child.walk();
+                let Some(inner) = child.named_children(&mut ambient_cursor).next() else {
+                    continue;
+                };
+                Declaration {
+                    outer: child,
+                    node: inner,
+                    exported: scope.namespaces.is_empty(),
+                    decorators: Vec::new(),
+                }
+            }
+            "expression_statement" => {
+                let mut expression_cursor = child.walk();
+                let Some(inner) = child
+                    .named_children(&mut expression_cursor)
+                    .find(|inner| inner.kind() == "internal_module")
+                else {
+                    continue;
+                };
+                Declaration {
+                    outer: child,
+                    node: inner,
+                    exported: false,
+                    decorators: Vec::new(),
+                }
+            }
+            _ => Declaration {
+                outer: child,
+                node: child,
+                exported: false,
+                decorators: Vec::new(),
+            },
+        };
+        let name = function_name(declaration.node, context.text);
+        let declaration = match overloads.take() {
+            Some(previous) if name.is_some() && name == function_name(previous.node, context.text) => Declaration {
+                outer: previous.outer,
+                ..declaration
+            },
+            Some(previous) => {
+                collect_declaration(previous, context, scope, drafts);
+                declaration
+            }
+            None => declaration,
+        };
+        if declaration.node.kind() == "function_signature" {
+            overloads = Some(declaration);
+        } else {
+            let (outer, drafted) = (declaration.outer, drafts.len());
+            collect_declaration(declaration, context, scope, drafts);
+            // `export default defineConfig({ ... })` declares nothing but is still the module's export.
+            if drafts.len() == drafted && outer.kind() == "export_statement" {
+                drafts.extend(export_draft(outer, context));
+            }
+        }
+    }
+    if let Some(previous) = overloads {
+        collect_declaration(previous, context, scope, drafts);
+    }
+}
+
+/// Name of a function declaration or overload signature.
+#[cfg(feature = "treesitter")]
+fn function_name<'a>(node: Node, text: &'a str) -> Option<&'a str> {
+    match node.kind() {
+        "function_declaration" | "generator_function_declaration" | "function_signature" => {
+            node_text(node.child_by_field_name("name")?, text)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn collect_declaration(declaration: Declaration, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let node = declaration.node;
+    match node.kind() {
+        "internal_module" | "module" => {
+            let Some(draft) = draft_from_declaration(&declaration, AstNodeKind::Module, context, scope) else {
+                return;
+            };
+            let name = draft.symbol.clone().unwrap_or_default();
+            drafts.push(draft);
+            if let Some(body) = node.child_by_field_name("body") {
+                let mut inner = scope.clone();
+                inner.parents.push(name.clone());
+                inner.namespaces.push(name);
+                collect_statements(body, context, &inner, drafts);
+            }
+        }
+        "class_declaration" | "abstract_class_declaration" | "class" => {
+            let mut decorators = declaration.decorators.clone();
+            let mut class_cursor = node.walk();
+            decorators.extend(node.named_children(&mut class_cursor).filter(|child| child.kind() == "decorator"));
+            let declaration = Declaration { decorators, ..declaration };
+            let Some(draft) = draft_from_declaration(&declaration, AstNodeKind::Class, context, scope) else {
+                return;
+            };
+            let name = draft.symbol.clone().unwrap_or_default();
+            drafts.push(draft);
+            if let Some(body) = node.child_by_field_name("body") {
+                let mut inner = scope.clone();
+                inner.parents.push(name.clone());
+                inner.class = Some(name);
+                inner.generics.extend(type_parameter_names(node, context.text));
+                collect_class_members(body, context, &inner, drafts);
+            }
+        }
+        "function_declaration" | "generator_function_declaration" | "function_signature" => {
+            let name = node_text(node.child_by_field_name("name").unwrap_or(node), context.text).unwrap_or("");
+            let kind = if is_test_name(name) {
+                AstNodeKind::Test
+            } else {
+                AstNodeKind::Function
+            };
+            drafts.extend(draft_from_declaration(&declaration, kind, context, scope));
+        }
+        "interface_declaration" => {
+            drafts.extend(draft_from_declaration(&declaration, AstNodeKind::Interface, context, scope));
+        }
+        "type_alias_declaration" => {
+            drafts.extend(draft_from_declaration(&declaration, AstNodeKind::Type, context, scope));
+        }
+        "enum_declaration" => {
+            drafts.extend(draft_from_declaration(&declaration, AstNodeKind::Enum, context, scope));
+        }
+        "lexical_declaration" | "variable_declaration" => {
+            let kind = if arrow_function_value(node).is_some() {
+                match declaration_name(node, context.text) {
+                    Some(name) if is_test_name(&name) => AstNodeKind::Test,
+                    _ => AstNodeKind::Function,
+                }
+            } else if node_text(node, context.text).is_some_and(|text| text.starts_with("const")) {
+                AstNodeKind::Constant
+            } else {
+                AstNodeKind::Variable
+            };
+            drafts.extend(draft_from_declaration(&declaration, kind, context, scope));
+        }
+        _ => {}
+    }
+}
+
+/// Methods become their own chunks, starting at their overload signatures;
+/// decorators are sibling nodes that precede them.
+#[cfg(feature = "treesitter")]
+fn collect_class_members(body: Node, context: &FileContext, scope: &Scope, drafts: &mut Vec<ChunkDraft>) {
+    let text = context.text;
+    let name = |node: Node| node.child_by_field_name("name").and_then(|name| node_text(name, text));
+    let mut decorators = Vec::new();
+    let mut overloads: Option<Node> = None;
+    let mut cursor = body.walk();
+    for child in body.named_children(&mut cursor) {
+        match child.kind() {
+            "decorator" => decorators.push(child),
+            "method_signature" => {
+                if overloads.is_none_or(|first| name(first) != name(child)) {
+                    overloads = Some(child);
+                }
+                decorators.clear();
+            }
+            "method_definition" | "abstract_method_signature" => {
+                let first = overloads.take().filter(|first| name(*first) == name(child));
+                let declaration = Declaration {
+                    outer: first.or(decorators.first().copied()).unwrap_or(child),
+                    node: child,
+                    exported: false,
+                    decorators: std::mem::take(&mut decorators),
+                };
+                drafts.extend(draft_from_declaration(&declaration, AstNodeKind::Method, context, scope));
+            }
+            _ => {
+                decorators.clear();
+                overloads = None;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "treesitter")]
+fn draft_from_declaration(
+    declaration: &Declaration,
+    ast_kind: AstNodeKind,
+    context: &FileContext,
+    scope: &Scope,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    let node = declaration.node;
+    let symbol = declaration_name(node, text)?;
+    let start = declaration.outer.start_byte().min(node.start_byte());
+    let content = text.get(start..node.end_byte().max(declaration.outer.end_byte()))?.trim().to_string();
+    if content.is_empty() {
+        return None;
+    }
+
+    let qualified_name = scope
+        .parents
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(symbol.as_str()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut generics = scope.generics.clone();
+    generics.extend(type_parameter_names(node, text));
+    if let Some(arrow) = arrow_function_value(node) {
+        generics.extend(type_parameter_names(arrow, text));
+    }
+    let is_type = matches!(
+        ast_kind,
+        AstNodeKind::Class | AstNodeKind::Interface | AstNodeKind::Type | AstNodeKind::Enum
+    );
+    let mut type_refs = extract_type_refs(node, context, &generics);
+    if is_type {
+        type_refs.retain(|type_ref| type_ref != &symbol);
+    }
+
+    let kinds = ["identifier", "shorthand_property_identifier", "type_identifier"];
+    let imports = referenced_imports(&context.value_imports, node, text, &kinds);
+    let referenced = identifier_refs(node, text, &["identifier", "type_identifier"]);
+    for binding in &context.type_imports {
+        if referenced.contains(&binding.local) && !generics.contains(&binding.local) {
+            type_refs.retain(|type_ref| type_ref != &binding.local);
+            push_unique(&mut type_refs, &binding.path);
+        }
+    }
+
+    let (mut calls, mut method_calls) = extract_calls(node, context, scope.class.as_deref());
+    for decorator in &declaration.decorators {
+        let (decorator_calls, decorator_methods) = extract_calls(*decorator, context, None);
+        for call in decorator_calls {
+            if !decorator_methods.contains(&call) {
+                method_calls.retain(|method| method != &call);
+            } else if !calls.contains(&call) {
+                method_calls.push(call.clone());
+            }
+            push_unique(&mut calls, &call);
+        }
+    }
+
+    let outer = declaration.outer;
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: outer.start_position().row.min(node.start_position().row) + 1,
+        end_line: node.end_position().row.max(outer.end_position().row) + 1,
+        content,
+        heading_path: Vec::new(),
+        symbol: Some(symbol.clone()),
+        address: None,
+        ast_kind: Some(ast_kind),
+        qualified_name: Some(qualified_name),
+        signature: extract_signature(node, text, &symbol),
+        parent_symbol: scope.parents.last().cloned(),
+        imports,
+        exports: if declaration.exported && scope.namespaces.is_empty() {
+            vec![symbol.clone()]
+        } else {
+            Vec::new()
+        },
+        calls,
+        method_calls,
+        type_refs,
+        doc_summary: leading_doc_comment(outer, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: Some(symbol),
+        module_path: (!scope.namespaces.is_empty()).then(|| scope.namespaces.join(".")),
+        visibility: Some(extract_visibility(declaration, text, scope)),
+        log_level: None,
+    })
+}
+
+/// `export { a as b } from "./x"`, `export * from "./x"` and `export * as ns from "./x"`
+/// become one Export chunk whose `imports[i]` (`./x#a`, `./x#*`) is re-exported as
+/// `exports[i]` (`b`, `*`, `ns`), so the resolver can follow barrel files.
+#[cfg(feature = "treesitter")]
+fn reexport_draft(node: Node, context: &FileContext) -> Option<ChunkDraft> {
+    let text = context.text;
+    let specifier = node
+        .child_by_field_name("source")
+        .and_then(|source| node_text(source, text))?
+        .trim_matches(|ch| matches!(ch, '"' | '\'' | '`'));
+    let mut imports = Vec::new();
+    let mut exports = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "export_clause" => {
+                let mut clause_cursor = child.walk();
+                for export in child.named_children(&mut clause_cursor) {
+                    let Some(name) = export.child_by_field_name("name").and_then(|name| node_text(name, text)) else {
+                        continue;
+                    };
+                    let alias = export.child_by_field_name("alias").and_then(|alias| node_text(alias, text));
+                    imports.push(format!("{specifier}#{name}"));
+                    exports.push(alias.unwrap_or(name).to_string());
+                }
+            }
+            "namespace_export" => {
+                let mut namespace_cursor = child.walk();
+                let name = child
+                    .named_children(&mut namespace_cursor)
+                    .find_map(|name| node_text(name, text));
+                if let Some(name) = name {
+                    imports.push(format!("{specifier}#*"));
+                    exports.push(name.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    if imports.is_empty() {
+        let mut star_cursor = node.walk();
+        if node.children(&mut star_cursor).any(|child| child.kind() == "*") {
+            imports.push(format!("{specifier}#*"));
+            exports.push("*".to_string());
+        }
+    }
+    if imports.is_empty() {
+        return None;
+    }
+    export_chunk(node, context, imports, exports, Vec::new(), Vec::new())
+}
+
+/// `export default <expression>` and local export lists (`export { app as server }`),
+/// as one Export chunk with the names it exports and the calls and imports it uses.
+#[cfg(feature = "treesitter")]
+fn export_draft(node: Node, context: &FileContext) -> Option<ChunkDraft> {
+    let text = context.text;
+    let mut exports = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "default" => exports.push("default".to_string()),
+            "export_clause" => {
+                let mut clause_cursor = child.walk();
+                for export in child.named_children(&mut clause_cursor) {
+                    let name = export
+                        .child_by_field_name("alias")
+                        .or_else(|| export.child_by_field_name("name"))
+                        .and_then(|name| node_text(name, text));
+                    exports.extend(name.map(str::to_string));
+                }
+            }
+            _ => {}
+        }
+    }
+    if exports.is_empty() {
+        return None;
+    }
+    let kinds = ["identifier", "shorthand_property_identifier"];
+    let imports = referenced_imports(&context.value_imports, node, text, &kinds);
+    let (calls, method_calls) = extract_calls(node, context, None);
+    export_chunk(node, context, imports, exports, calls, method_calls)
+}
+
+#[cfg(feature = "treesitter")]
+fn export_chunk(
+    node: Node,
+    context: &FileContext,
+    imports: Vec<String>,
+    exports: Vec<String>,
+    calls: Vec<String>,
+    method_calls: Vec<String>,
+) -> Option<ChunkDraft> {
+    let text = context.text;
+    Some(ChunkDraft {
+        kind: context.kind,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        content: node_text(node, text)?.trim().to_string(),
+        heading_path: Vec::new(),
+        symbol: None,
+        address: None,
+        ast_kind: Some(AstNodeKind::Export),
+        qualified_name: None,
+        signature: None,
+        parent_symbol: None,
+        imports,
+        exports,
+        calls,
+        method_calls,
+        type_refs: Vec::new(),
+        doc_summary: leading_doc_comment(node, text, &["comment"]),
+        symbol_id: None,
+        symbol_tail: None,
+        module_path: None,
+        visibility: Some(Visibility::Pub),
+        log_level: None,
+    })
+}
+
+/// Name of a declaration; anonymous `export default class {}` is named `default`.
+#[cfg(feature = "treesitter")]
+fn declaration_name(node: Node, text: &str) -> Option<String> {
+    if let Some(name) = node.child_by_field_name("name") {
+        let name = node_text(name, text)?;
+        return Some(name.trim_matches(|ch| matches!(ch, '"' | '\'')).to_string());
+    }
+    match node.kind() {
+        "lexical_declaration" | "variable_declaration" => {
+            let mut cursor = node.walk();
+            let declarator = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "variable_declarator")?;
+            node_text(declarator.child_by_field_name("name")?, text).map(str::to_string)
+        }
+        "class" => Some("default".to_string()),
+        _ => None,
+    }
+}
+
+/// Exported declarations are Pub; class members follow `private`/`protected`/`#name`.
+#[cfg(feature = "treesitter")]
+fn extract_visibility(declaration: &Declaration, text: &str, scope: &Scope) -> Visibility {
+    let node = declaration.node;
+    if scope.class.is_none() {
+        return if declaration.exported {
+            Visibility::Pub
+        } else {
+            Visibility::Private
+        };
+    }
+    let mut cursor = node.walk();
+    let modifier = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "accessibility_modifier")
+        .and_then(|modifier| node_text(modifier, text));
+    let private_name = node
+        .child_by_field_name("name")
+        .is_some_and(|name| name.kind() == "private_property_identifier");
+    match modifier {
+        _ if private_name => Visibility::Private,
+        Some("private") => Visibility::Private,
+        Some("protected") => Visibility::Crate,
+        _ => Visibility::Pub,
+    }
+}
+
+/// Functions keep the `name<T>(params): Return` form; types keep their header line,
+/// generic parameters and heritage clauses included.
+#[cfg(feature = "treesitter")]
+fn extract_signature(node: Node, text: &str, symbol: &str) -> Option<String> {
+    let callable = match node.kind() {
+        "function_declaration" | "generator_function_declaration" | "function_signature" | "method_definition"
+        | "abstract_method_signature" => Some(node),
+        "lexical_declaration" | "variable_declaration" => arrow_function_value(node),
+        _ => None,
+    };
+    if let Some(callable) = callable {
+        let type_params = callable
+            .child_by_field_name("type_parameters")
+            .and_then(|params| node_text(params, text))
+            .unwrap_or("");
+        let params = callable
+            .child_by_field_name("parameters")
+            .and_then(|params| node_text(params, text))
+            .unwrap_or("()");
+        let return_type = callable
+            .child_by_field_name("return_type")
+            .and_then(|return_type| node_text(return_type, text))
+            .map(|return_type| return_type.trim_start_matches(':').trim());
+        let signature = match return_type {
+            Some(return_type) => format!("{symbol}{type_params}{params}: {return_type}"),
+            None => format!("{symbol}{type_params}{params}"),
+        };
+        return Some(signature.split_whitespace().collect::<Vec<_>>().join(" "));
+    }
+    let body_start = match node.kind() {
+        "type_alias_declaration" => None,
+        _ => node.child_by_field_name("body").map(|body| body.start_byte()),
+    };
+    header_signature(node, text, body_start)
+}
+
+#[cfg(feature = "treesitter")]
+fn arrow_function_value(node: Node) -> Option<Node> {
+    if !matches!(node.kind(), "lexical_declaration" | "variable_declaration") {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let declarator = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "variable_declarator")?;
+    declarator
+        .child_by_field_name("value")
+        .filter(|value| matches!(value.kind(), "arrow_function" | "function"))
+}
+
+#[cfg(feature = "treesitter")]
+fn type_parameter_names(node: Node, text: &str) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    let names = params
+        .named_children(&mut cursor)
+        .filter(|param| param.kind() == "type_parameter")
+        .filter_map(|param| param.child_by_field_name("name"))
+        .filter_map(|name| node_text(name, text))
+        .map(str::to_string)
+        .collect();
+    names
+}
+
+#[cfg(feature = "treesitter")]
+fn is_test_name(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    lower.starts_with("test") || lower == "it" || lower == "describe"
+}
+
+/// Split import bindings into value and type-only ones. Named imports bind
+/// `specifier#name`, default imports `specifier#default`, namespaces `specifier#*`.
+#[cfg(feature = "treesitter")]
+fn collect_file_imports(pack: &'static QueryPack, root: Node, text: &str) -> (Vec<ImportBinding>, Vec<ImportBinding>) {
+    let mut values = Vec::new();
+    let mut types = Vec::new();
+    for (_, import) in captures(&pack.imports, root, text) {
+        let Some(specifier) = import
+            .child_by_field_name("source")
+            .and_then(|source| node_text(source, text))
+            .map(|source| source.trim_matches(|ch| matches!(ch, '"' | '\'' | '`')))
+        else {
+            continue;
+        };
+        let mut cursor = import.walk();
+        let type_only = import.children(&mut cursor).any(|child| child.kind() == "type");
+        let mut cursor = import.walk();
+        let Some(clause) = import
+            .named_children(&mut cursor)
+            .find(|child| child.kind() == "import_clause")
+        else {
+            continue;
+        };
+        let mut bind = |local: Option<&str>, name: &str, type_binding: bool| {
+            let Some(local) = local.filter(|local| !local.is_empty()) else {
+                return;
+            };
+            let binding = ImportBinding {
+                local: local.to_string(),
+                path: format!("{specifier}#{name}"),
+            };
+            if type_binding { types.push(binding) } else { values.push(binding) }
+        };
+        let mut clause_cursor = clause.walk();
+        for binding in clause.named_children(&mut clause_cursor) {
+            match binding.kind() {
+                "identifier" => bind(node_text(binding, text), "default", type_only),
+                "namespace_import" => {
+                    let mut namespace_cursor = binding.walk();
+                    let local = binding
+                        .named_children(&mut namespace_cursor)
+                        .find(|child| child.kind() == "identifier")
+                        .and_then(|local| node_text(local, text));
+                    bind(local, "*", type_only);
+                }
+                "named_imports" => {
+                    let mut named_cursor = binding.walk();
+                    for specifier_node in binding.named_children(&mut named_cursor) {
+                        if specifier_node.kind() != "import_specifier" {
+                            continue;
+                        }
+                        let mut specifier_cursor = specifier_node.walk();
+                        let type_specifier =
+                            specifier_node.children(&mut specifier_cursor).any(|child| child.kind() == "type");
+                        let Some(name) = specifier_node
+                            .child_by_field_name("name")
+                            .and_then(|name| node_text(name, text))
+                        else {
+                            continue;
+                        };
+                        let local = specifier_node
+                            .child_by_field_name("alias")
+                            .and_then(|alias| node_text(alias, text))
+                            .unwrap_or(name);
+                        bind(Some(local), name, type_only || type_specifier);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    (values, types)
+}
+
+/// Calls as `Class.method` for `this` and capitalized receivers, else the bare callee;
+/// constructor calls and decorators as the named type or function. Bare callees only ever
+/// called on a receiver (`items.push()`) are also returned on their own.
+#[cfg(feature = "treesitter")]
+fn extract_calls(node: Node, context: &FileContext, class: Option<&str>) -> (Vec<String>, Vec<String>) {
+    let text = context.text;
+    let mut calls = Vec::new();
+    let mut free = Vec::new();
+    let mut methods = Vec::new();
+    for (_, callee) in captures(&context.pack.calls, node, text) {
+        let (path, on_receiver) = match callee.kind() {
+            "identifier" => match node_text(callee, text) {
+                Some(name) => (name.to_string(), false),
+                None => continue,
+            },
+            "member_expression" => {
+                let Some(property) = callee.child_by_field_name("property").and_then(|name| node_text(name, text))
+                else {
+                    continue;
+                };
+                let object = callee.child_by_field_name("object");
+                match (object.map(|object| object.kind()), object.and_then(|object| node_text(object, text))) {
+                    (Some("this"), _) => match class {
+                        Some(class) => (format!("{class}.{property}"), false),
+                        None => (property.to_string(), true),
+                    },
+                    (Some("identifier"), Some(receiver)) if receiver.starts_with(char::is_uppercase) => {
+                        (format!("{receiver}.{property}"), false)
+                    }
+                    _ => (property.to_string(), true),
+                }
+            }
+            _ => continue,
+        };
+        push_unique(if on_receiver { &mut methods } else { &mut free }, &path);
+        push_unique(&mut calls, &path);
+    }
+    methods.retain(|method| !free.contains(method));
+    (calls, methods)
+}
+
+#[cfg(feature = "treesitter")]
+fn extract_type_refs(node: Node, context: &FileContext, generics: &[String]) -> Vec<String> {
+    let text = context.text;
+    let mut refs = Vec::new();
+    for (_, type_node) in captures(&context.pack.types, node, text) {
+        // The name inside `ns.Type` is covered by the enclosing nested capture.
+        if type_node
+            .parent()
+            .is_some_and(|parent| parent.kind() == "nested_type_identifier")
+        {
+            continue;
+        }
+        let Some(name) = node_text(type_node, text) else {
+            continue;
+        };
+        let name: String = name.split_whitespace().collect();
+        // Standard library types every file mentions carry no project relationship.
+        if matches!(
+            name.as_str(),
+            "Promise" | "Array" | "ReadonlyArray" | "Record" | "Partial" | "Required" | "Readonly" | "Pick"
+                | "Omit" | "Map" | "Set" | "Date" | "Error"
+        ) || generics.iter().any(|generic| generic == &name)
+        {
+            continue;
+        }
+        push_unique(&mut refs, &name);
+    }
+    refs
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use super::*;
+    use crate::IngestOptions;
+
+    fn parse(path: &str, text: &str) -> ParseResult {
+        let options = IngestOptions::default();
+        let request = ParseRequest {
+            path,
+            text,
+            kind: ChunkKind::JavaScript,
+            options: &options,
+            language: Some(LanguageId::TypeScript),
+        };
+        TypeScriptAdapter.parse(&request).expect("typescript parse result")
+    }
+
+    #[test]
+    fn typescript_declarations_are_classified() {
+        let source = r#"import type { Claims } from "./tokens";
+import { type Session, verify } from "./auth";
+
+/** Persists entities. */
+@Injectable()
+export abstract class Repo<T extends Base> implements Store<T> {
+  @Log()
+  async find<K>(id: K, session: Session): Promise<T> {
+    this.touch(id);
+    return verify(id);
+  }
+
+  abstract save(item: T): void;
+
+  private touch(id: unknown) {}
+}
+
+export interface Store<T> extends Base {
+  get(id: string): T;
+}
+
+export type Result<T> = Ok<T> | Err;
+
+export enum Color { Red, Green }
+
+namespace Util {
+  export function helper(claims: Claims): void {}
+}
+
+declare module "express" {
+  interface Request { user: Claims }
+}
+
+export const identity = <T,>(value: T): T => value;
+"#;
+        let result = parse("src/repo.ts", source);
+        assert_eq!(result.resolution_tier, ResolutionTier::QueryPack);
+        let chunk = |name: &str| {
+            result
+                .chunks
+                .iter()
+                .find(|c| c.symbol.as_deref() == Some(name))
+                .unwrap_or_else(|| panic!("missing chunk {name}"))
+        };
+
+        let repo = chunk("Repo");
+        assert_eq!(repo.ast_kind, Some(AstNodeKind::Class));
+        assert_eq!(repo.exports, vec!["Repo"]);
+        assert_eq!(repo.doc_summary.as_deref(), Some("Persists entities."));
+        assert_eq!(
+            repo.signature.as_deref(),
+            Some("abstract class Repo<T extends Base> implements Store<T>")
+        );
+        assert!(repo.calls.contains(&"Injectable".to_string()));
+
+        let find = chunk("find");
+        assert_eq!(find.ast_kind, Some(AstNodeKind::Method));
+        assert_eq!(find.qualified_name.as_deref(), Some("Repo.find"));
+        assert_eq!(find.signature.as_deref(), Some("find<K>(id: K, session: Session): Promise<T>"));
+        assert!(find.content.starts_with("@Log()"));
+        assert_eq!(find.calls, vec!["Repo.touch", "verify", "Log"]);
+        assert_eq!(find.imports, vec!["./auth#verify"]);
+        assert_eq!(find.type_refs, vec!["./auth#Session"]);
+        assert_eq!(chunk("touch").visibility, Some(Visibility::Private));
+        assert_eq!(chunk("save").ast_kind, Some(AstNodeKind::Method));
+
+        assert_eq!(chunk("Store").ast_kind, Some(AstNodeKind::Interface));
+        assert_eq!(chunk("Store").signature.as_deref(), Some("interface Store<T> extends Base"));
+        assert_eq!(chunk("Result").ast_kind, Some(AstNodeKind::Type));
+        assert_eq!(chunk("Result").type_refs, vec!["Ok", "Err"]);
+        assert_eq!(chunk("Color").ast_kind, Some(AstNodeKind::Enum));
+
+        assert_eq!(chunk("Util").ast_kind, Some(AstNodeKind::Module));
+        let helper = chunk("helper");
+        assert_eq!(helper.qualified_name.as_deref(), Some("Util.helper"));
+        assert_eq!(helper.module_path.as_deref(), Some("Util"));
+        assert_eq!(helper.type_refs, vec!["./tokens#Claims"]);
+        assert!(helper.imports.is_empty());
+
+        assert_eq!(chunk("express").ast_kind, Some(AstNodeKind::Module));
+        assert_eq!(chunk("Request").qualified_name.as_deref(), Some("express.Request"));
+
+        let identity = chunk("identity");
+        assert_eq!(identity.ast_kind, Some(AstNodeKind::Function));
+        assert_eq!(identity.signature.as_deref(), Some("identity<T,>(value: T): T"));
+        assert!(identity.type_refs.is_empty());
+    }
+    #[test]
+    fn overloads_fold_into_their_implementation_and_merged_declarations_share_a_name() {
+        let source = r#"/** Parses a value. */
+export function parse(input: string): Config;
+export function parse(input: Buffer): Config;
+export function parse(input: string | Buffer): Config {
+  return load(input);
+}
+
+export declare function format(value: number): string;
+export declare function format(value: Date): string;
+
+export class Client {
+  send(body: string): void;
+  send(body: Uint8Array): void;
+  send(body: unknown) {
+    this.write(body);
+  }
+}
+
+export interface Box { width: number }
+export interface Box { height: number }
+export namespace Box {
+  export const unit = "px";
+}
+"#;
+        let result = parse("src/parse.ts", source);
+        let named = |name: &str| result.chunks.iter().filter(|c| c.symbol.as_deref() == Some(name)).collect::<Vec<_>>();
+
+        let [parse] = named("parse")[..] else { panic!("one parse chunk") };
+        assert_eq!((parse.start_line, parse.end_line), (2, 6));
+        assert_eq!(parse.doc_summary.as_deref(), Some("Parses a value."));
+        assert_eq!(parse.signature.as_deref(), Some("parse(input: string | Buffer): Config"));
+        assert_eq!(parse.calls, vec!["load"]);
+        assert_eq!(parse.exports, vec!["parse"]);
+
+        // Signatures without an implementation still make one chunk.
+        let [format] = named("format")[..] else { panic!("one format chunk") };
+        assert_eq!((format.start_line, format.end_line), (8, 9));
+        assert_eq!(format.exports, vec!["format"]);
+
+        let [send] = named("send")[..] else { panic!("one send chunk") };
+        assert_eq!(send.qualified_name.as_deref(), Some("Client.send"));
+        assert_eq!((send.start_line, send.end_line), (12, 16));
+        assert!(send.content.starts_with("send(body: string): void;"));
+        assert_eq!(send.calls, vec!["Client.write"]);
+
+        // Merged interfaces and their namespace keep separate chunks under one name.
+        let boxes = named("Box");
+        let kinds: Vec<_> = boxes.iter().map(|chunk| chunk.ast_kind).collect();
+        assert_eq!(kinds, [Some(AstNodeKind::Interface), Some(AstNodeKind::Interface), Some(AstNodeKind::Module)]);
+        assert!(boxes.iter().all(|chunk| chunk.qualified_name.as_deref() == Some("Box")));
+        assert_eq!(named("unit")[0].qualified_name.as_deref(), Some("Box.unit"));
+    }
+    #[test]
+    fn value_exports_and_local_export_lists_keep_their_lines() {
+        let source = r#"import { defineConfig } from "vite";
+import react from "@vitejs/plugin-react";
+
+/** Dev server and build settings. */
+export default defineConfig({
+  plugins: [react()],
+  server: { port: 5173 },
+});
+"#;
+        let result = parse("vite.config.ts", source);
+        let [config] = &result.chunks[..] else { panic!("one export chunk: {:?}", result.chunks) };
+        assert_eq!(config.ast_kind, Some(AstNodeKind::Export));
+        assert_eq!((config.start_line, config.end_line), (5, 8));
+        assert_eq!(config.exports, vec!["default"]);
+        assert_eq!(config.calls, vec!["defineConfig", "react"]);
+        assert_eq!(config.imports, vec!["vite#defineConfig", "@vitejs/plugin-react#default"]);
+        assert_eq!(config.doc_summary.as_deref(), Some("Dev server and build settings."));
+
+        let source = "const app = createApp();\n\nexport default {\n  port: 3000,\n};\nexport { app, app as server };\n";
+        let result = parse("app.config.ts", source);
+        let exports: Vec<_> = result.chunks.iter().filter(|c| c.ast_kind == Some(AstNodeKind::Export)).collect();
+        assert_eq!(exports.len(), 2);
+        assert_eq!((exports[0].start_line, exports[0].end_line), (3, 5));
+        assert_eq!(exports[0].exports, vec!["default"]);
+        assert_eq!(exports[1].exports, vec!["app", "server"]);
+    }
+    #[test]
+    fn arrow_functions_named_as_tests_are_tests() {
+        let source = "const testParsesConfig = () => {\n  parse(\"{}\");\n};\nconst parseAll = (inputs: string[]) => inputs.map(parse);\n";
+        let result = parse("src/config.test.ts", source);
+        let kind = |name: &str| result.chunks.iter().find(|c| c.symbol.as_deref() == Some(name)).and_then(|c| c.ast_kind);
+        assert_eq!(kind("testParsesConfig"), Some(AstNodeKind::Test));
+        assert_eq!(kind("parseAll"), Some(AstNodeKind::Function));
+    }
+}
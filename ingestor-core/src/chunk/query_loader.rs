@@ -0,0 +1,80 @@
+//! Compile-time query packs for the dedicated language adapters (Parser Tier 2).
+//!
+//! Each pack is a set of `.scm` tree-sitter queries under `queries/<lang>/`,
+//! embedded with `include_str!` so the binary stays self-contained. Packs are
+//! compiled once per process and shared across parses.
+
+use crate::model::LanguageId;
+use std::sync::OnceLock;
+use tree_sitter::{Language, Node, Query, QueryCursor};
+
+pub(crate) struct QueryPack {
+    pub(crate) imports: Query,
+    pub(crate) calls: Query,
+    pub(crate) types: Query,
+}
+
+impl QueryPack {
+    fn compile(language: Language, imports: &str, calls: &str, types: &str) -> Option<Self> {
+        Some(Self {
+            imports: Query::new(language, imports).ok()?,
+            calls: Query::new(language, calls).ok()?,
+            types: Query::new(language, types).ok()?,
+        })
+    }
+}
+
+/// Compile (once) and return the pack stored under `queries/<dir>/`.
+macro_rules! embedded_pack {
+    ($dir:literal, $language:expr) => {{
+        static PACK: OnceLock<Option<QueryPack>> = OnceLock::new();
+        PACK.get_or_init(|| {
+            QueryPack::compile(
+                $language,
+                include_str!(concat!("../../queries/", $dir, "/imports.scm")),
+                include_str!(concat!("../../queries/", $dir, "/calls.scm")),
+                include_str!(concat!("../../queries/", $dir, "/types.scm")),
+            )
+        })
+        .as_ref()
+    }};
+}
+
+/// Return the compiled query pack for `language`, if one ships with llmx.
+pub(crate) fn query_pack(language: &LanguageId) -> Option<&'static QueryPack> {
+    match language {
+        LanguageId::Rust => embedded_pack!("rust", tree_sitter_rust::language()),
+        LanguageId::Python => embedded_pack!("python", tree_sitter_python::language()),
+        LanguageId::Go => embedded_pack!("go", tree_sitter_go::language()),
+        LanguageId::Java => embedded_pack!("java", tree_sitter_java::language()),
+        LanguageId::CSharp => embedded_pack!("csharp", tree_sitter_c_sharp::language()),
+        // C sources parse with the C++ grammar so headers shared by both resolve alike.
+        LanguageId::C | LanguageId::Cpp => embedded_pack!("cpp", tree_sitter_cpp::language()),
+        LanguageId::TypeScript => embedded_pack!("typescript", tree_sitter_typescript::language_typescript()),
+        _ => None,
+    }
+}
+
+/// The TypeScript pack compiled against the TSX grammar, whose node ids differ.
+pub(crate) fn tsx_query_pack() -> Option<&'static QueryPack> {
+    embedded_pack!("typescript", tree_sitter_typescript::language_tsx())
+}
+
+/// Run `query` over the subtree rooted at `node`, returning `(capture_name, node)` pairs
+/// in document order.
+pub(crate) fn captures<'tree>(
+    query: &'static Query,
+    node: Node<'tree>,
+    text: &str,
+) -> Vec<(&'static str, Node<'tree>)> {
+    let names = query.capture_names();
+    let mut cursor = QueryCursor::new();
+    let mut out = Vec::new();
+    for query_match in cursor.matches(query, node, text.as_bytes()) {
+        for capture in query_match.captures {
+            out.push((names[capture.index as usize].as_str(), capture.node));
+        }
+    }
+    out.sort_by_key(|(_, node)| node.start_byte());
+    out
+}
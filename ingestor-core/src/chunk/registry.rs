@@ -1,5 +1,12 @@
+use super::formats::{
+    CssAdapter, CsvAdapter, DockerfileAdapter, GraphqlAdapter, JsonlAdapter, LogAdapter, MakefileAdapter, OpenApiAdapter,
+    ProtoAdapter, ShellAdapter, SqlAdapter, TerraformAdapter, TomlAdapter, XmlAdapter, YamlAdapter,
+};
 use super::generic::GenericTreeSitterAdapter;
 use super::language::{LanguageAdapter, ParseRequest, ParseResult};
+use super::notebook::NotebookAdapter;
+use super::parsers::{CSharpAdapter, CppAdapter, GoAdapter, JavaAdapter, PythonAdapter, RustAdapter, TypeScriptAdapter};
+use super::sfc::SfcAdapter;
 use crate::model::{ChunkKind, IngestOptions};
 use crate::util::detect_language;
 
@@ -26,6 +33,38 @@ impl LanguageAdapter for JavaScriptLegacyAdapter {
     }
 }
 
+/// Adapters in the order they are tried; the generic tree-sitter one comes last.
+fn adapters() -> [&'static dyn LanguageAdapter; 26] {
+    [
+        &TypeScriptAdapter,
+        &JavaScriptLegacyAdapter,
+        &RustAdapter,
+        &PythonAdapter,
+        &GoAdapter,
+        &JavaAdapter,
+        &CSharpAdapter,
+        &CppAdapter,
+        &ShellAdapter,
+        &SqlAdapter,
+        &OpenApiAdapter,
+        &YamlAdapter,
+        &TomlAdapter,
+        &CssAdapter,
+        &NotebookAdapter,
+        &SfcAdapter,
+        &LogAdapter,
+        &CsvAdapter,
+        &JsonlAdapter,
+        &XmlAdapter,
+        &DockerfileAdapter,
+        &MakefileAdapter,
+        &TerraformAdapter,
+        &ProtoAdapter,
+        &GraphqlAdapter,
+        &GenericTreeSitterAdapter,
+    ]
+}
+
 pub(crate) fn parse(path: &str, text: &str, kind: ChunkKind, options: &IngestOptions) -> Option<ParseResult> {
     let request = ParseRequest {
         path,
@@ -35,8 +74,7 @@ pub(crate) fn parse(path: &str, text: &str, kind: ChunkKind, options: &IngestOpt
         language: detect_language(path),
     };
 
-    let adapters: [&dyn LanguageAdapter; 2] = [&JavaScriptLegacyAdapter, &GenericTreeSitterAdapter];
-    for adapter in adapters {
+    for adapter in adapters() {
         let _ = adapter.language_id();
         if adapter.supports(&request) {
             if let Some(result) = adapter.parse(&request) {
@@ -47,3 +85,19 @@ pub(crate) fn parse(path: &str, text: &str, kind: ChunkKind, options: &IngestOpt
 
     None
 }
+
+/// The tier of the first adapter that takes files like `path`, without parsing one.
+pub(crate) fn adapter_tier(path: &str, kind: ChunkKind) -> Option<crate::ResolutionTier> {
+    let options = IngestOptions::default();
+    let request = ParseRequest {
+        path,
+        text: "",
+        kind,
+        options: &options,
+        language: detect_language(path),
+    };
+    adapters()
+        .into_iter()
+        .find(|adapter| adapter.supports(&request))
+        .map(|adapter| adapter.resolution_tier())
+}
@@ -0,0 +1,242 @@
+use super::language::{LanguageAdapter, ParseRequest, ParseResult};
+use super::legacy::{chunk_text, embedded_blocks, embedded_code_drafts, finalize_chunks, ChunkDraft};
+use crate::model::{ChunkKind, IngestOptions, LanguageId, ResolutionTier};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Vue and Svelte built-ins that look like components in a template.
+const BUILTIN_COMPONENTS: &[&str] = &["Transition", "TransitionGroup", "KeepAlive", "Teleport", "Suspense"];
+
+fn component_tag_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"<([A-Z][A-Za-z0-9]*(?:\.[A-Z][A-Za-z0-9]*)*|[a-z][a-z0-9]*(?:-[a-z0-9]+)+)[\s/>]")
+            .expect("component tag regex")
+    })
+}
+
+fn script_import_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"(?m)^\s*import\s+(?:type\s+)?([A-Za-z_$][\w$]*)?\s*,?\s*(?:\{([^}]*)\})?\s*from\s*["']([^"']+)["']"#,
+        )
+        .expect("script import regex")
+    })
+}
+
+/// Vue and Svelte single-file components, split into their template, script and
+/// style sections (addressed `template`, `script`, `script setup`, `style`). The
+/// script is parsed by the JavaScript/TypeScript adapter with the component as the
+/// parent of its top-level declarations; components used in the template are
+/// recorded as type references, bound to the script import that brings them in.
+pub(crate) struct SfcAdapter;
+
+impl LanguageAdapter for SfcAdapter {
+    fn language_id(&self) -> Option<LanguageId> {
+        None
+    }
+
+    fn resolution_tier(&self) -> ResolutionTier {
+        // Sections parse under a sibling path, so scope rules keyed by path bind only component imports.
+        ResolutionTier::TextOnly
+    }
+
+    fn supports(&self, request: &ParseRequest<'_>) -> bool {
+        matches!(&request.language, Some(LanguageId::Other(extension)) if extension == "vue" || extension == "svelte")
+    }
+
+    fn parse(&self, request: &ParseRequest<'_>) -> Option<ParseResult> {
+        let chunks = parse_sfc_chunks(request.path, request.text, request.options)?;
+        Some(ParseResult {
+            chunks,
+            resolution_tier: self.resolution_tier(),
+        })
+    }
+}
+
+/// `user-card` and `user_card` → `UserCard`.
+fn pascal_case(name: &str) -> String {
+    name.split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or(String::new(), |first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        })
+        .collect()
+}
+
+/// The component a file defines, named after it (`user-card.vue` → `UserCard`).
+fn component_name(path: &str) -> String {
+    let stem = std::path::Path::new(path).file_stem().and_then(|stem| stem.to_str()).unwrap_or(path);
+    pascal_case(stem)
+}
+
+/// Components used in template markup, in order of first use.
+fn component_refs(template: &str, component: &str) -> Vec<String> {
+    let mut refs: Vec<String> = Vec::new();
+    for caps in component_tag_re().captures_iter(template) {
+        let name = if caps[1].contains('-') { pascal_case(&caps[1]) } else { caps[1].to_string() };
+        if name != component && !BUILTIN_COMPONENTS.contains(&name.as_str()) && !refs.contains(&name) {
+            refs.push(name);
+        }
+    }
+    refs
+}
+
+/// `(local, specifier#name)` bindings of a script's default and named imports, in the
+/// form the TypeScript adapter records them.
+fn script_imports(script: &str) -> Vec<(String, String)> {
+    let mut bindings = Vec::new();
+    for caps in script_import_re().captures_iter(script) {
+        let specifier = &caps[3];
+        if let Some(local) = caps.get(1) {
+            bindings.push((local.as_str().to_string(), format!("{specifier}#default")));
+        }
+        let named = caps.get(2).map_or("", |named| named.as_str());
+        for binding in named.split(',') {
+            let binding = binding.trim().trim_start_matches("type ").trim();
+            let (name, local) = binding.split_once(" as ").unwrap_or((binding, binding));
+            if !name.trim().is_empty() {
+                bindings.push((local.trim().to_string(), format!("{specifier}#{}", name.trim())));
+            }
+        }
+    }
+    bindings
+}
+
+fn section_drafts(mut drafts: Vec<ChunkDraft>, address: &str, component: &str) -> Vec<ChunkDraft> {
+    for draft in &mut drafts {
+        draft.address = Some(address.to_string());
+        draft.heading_path = vec![component.to_string()];
+    }
+    drafts
+}
+
+fn parse_sfc_chunks(path: &str, text: &str, options: &IngestOptions) -> Option<Vec<crate::Chunk>> {
+    let component = component_name(path);
+    if component.is_empty() {
+        return None;
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let blocks = embedded_blocks(text);
+    let bindings: Vec<(String, String)> = blocks
+        .iter()
+        .filter(|block| block.tag == "script")
+        .flat_map(|block| script_imports(&block.body))
+        .collect();
+
+    let mut drafts = Vec::new();
+    // Markup outside the script and style blocks: Vue's `<template>`, or Svelte's
+    // top-level markup. Each run of lines between blocks is chunked on its own.
+    let mut run_start = 0;
+    let mut bounds: Vec<(usize, usize)> = blocks.iter().map(|block| (block.open_line - 1, block.close_line)).collect();
+    bounds.push((lines.len(), lines.len()));
+    for (open, close) in bounds {
+        let first = (run_start..open).find(|idx| !lines[*idx].trim().is_empty());
+        if let Some(first) = first {
+            let last = (first..open).rfind(|idx| !lines[*idx].trim().is_empty()).unwrap_or(first);
+            let markup = lines[first..=last].join("\n");
+            let mut template = section_drafts(chunk_text(&markup, options), "template", &component);
+            for draft in &mut template {
+                draft.start_line += first;
+                draft.end_line += first;
+                draft.kind = ChunkKind::Html;
+                draft.symbol = Some(component.clone());
+                // A component imported by the script is referenced through that import.
+                for name in component_refs(&draft.content, &component) {
+                    match bindings.iter().find(|(local, _)| *local == name) {
+                        Some((_, path)) => {
+                            draft.imports.push(path.clone());
+                            draft.type_refs.push(path.clone());
+                        }
+                        None => draft.type_refs.push(name),
+                    }
+                }
+            }
+            drafts.extend(template);
+        }
+        run_start = run_start.max(close);
+    }
+
+    for block in &blocks {
+        let Some(extension) = block.extension.filter(|_| !block.body.trim().is_empty()) else {
+            continue;
+        };
+        let code = embedded_code_drafts(path, &block.body, extension, block.line_offset, &[], options);
+        if block.tag == "style" {
+            drafts.extend(section_drafts(code, "style", &component));
+            continue;
+        }
+        let setup = block.attrs.split_whitespace().any(|attr| attr == "setup");
+        let mut code = section_drafts(code, if setup { "script setup" } else { "script" }, &component);
+        for draft in &mut code {
+            if draft.parent_symbol.is_none() {
+                draft.parent_symbol = Some(component.clone());
+            }
+        }
+        drafts.extend(code);
+    }
+    if drafts.is_empty() {
+        return None;
+    }
+    drafts.sort_by_key(|draft| draft.start_line);
+    Some(finalize_chunks(path, drafts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vue_sections_are_chunked_with_component_context() {
+        let text = r#"<template>
+  <section class="profile">
+    <UserAvatar :src="user.avatar" />
+    <user-stats :user="user"></user-stats>
+    <Transition name="fade"><p v-if="open">{{ user.bio }}</p></Transition>
+  </section>
+</template>
+
+<script setup lang="ts">
+import UserAvatar from './UserAvatar.vue'
+
+function toggleBio(): void {
+  open.value = !open.value
+}
+</script>
+
+<style scoped lang="scss">
+.profile { display: grid; }
+</style>
+"#;
+        let chunks = parse_sfc_chunks("src/components/user-profile.vue", text, &IngestOptions::default())
+            .expect("component should chunk");
+        let sections: Vec<_> = chunks.iter().map(|chunk| chunk.address.as_deref().unwrap_or("")).collect();
+        assert_eq!(sections, ["template", "script setup", "style"]);
+
+        let template = &chunks[0];
+        assert_eq!((template.start_line, template.end_line), (1, 7));
+        assert_eq!(template.symbol.as_deref(), Some("UserProfile"));
+        assert_eq!(template.type_refs, ["./UserAvatar.vue#default", "UserStats"]);
+        assert_eq!(template.imports, ["./UserAvatar.vue#default"]);
+
+        let function = &chunks[1];
+        assert_eq!(function.symbol.as_deref(), Some("toggleBio"));
+        assert_eq!(function.parent_symbol.as_deref(), Some("UserProfile"));
+        assert_eq!((function.start_line, function.end_line), (12, 14));
+        assert_eq!(function.heading_path, ["UserProfile"]);
+
+        assert_eq!((chunks[2].start_line, chunks[2].end_line), (18, 18));
+    }
+
+    #[test]
+    fn svelte_markup_around_the_script_is_the_template() {
+        let text = "<script>\n  export let items = [];\n</script>\n\n<ul>\n  {#each items as item}\n    <ListItem {item} />\n  {/each}\n</ul>\n";
+        let chunks = parse_sfc_chunks("src/routes/+page.svelte", text, &IngestOptions::default())
+            .expect("component should chunk");
+        let template = chunks.iter().find(|chunk| chunk.address.as_deref() == Some("template")).expect("template");
+        assert_eq!((template.start_line, template.end_line), (5, 9));
+        assert_eq!(template.type_refs, ["ListItem"]);
+    }
+}
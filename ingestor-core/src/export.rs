@@ -1,5 +1,5 @@
 use crate::model::{Chunk, ChunkKind, FileMeta, IndexFile};
-use crate::util::build_chunk_refs;
+use crate::util::{build_chunk_refs, compact_repeated_lines};
 use serde::Serialize;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
@@ -784,50 +784,6 @@ fn compact_for_export(chunk: &Chunk) -> (String, bool) {
     compact_repeated_lines(&chunk.content, 3)
 }
 
-fn compact_repeated_lines(text: &str, min_repeat: usize) -> (String, bool) {
-    let mut out: Vec<String> = Vec::new();
-    let mut current: Option<String> = None;
-    let mut count = 0usize;
-    let mut compacted = false;
-
-    for line in text.lines() {
-        match &current {
-            Some(prev) if prev == line => {
-                count += 1;
-            }
-            Some(prev) => {
-                push_run(&mut out, prev, count, min_repeat, &mut compacted);
-                current = Some(line.to_string());
-                count = 1;
-            }
-            None => {
-                current = Some(line.to_string());
-                count = 1;
-            }
-        }
-    }
-    if let Some(prev) = current {
-        push_run(&mut out, &prev, count, min_repeat, &mut compacted);
-    }
-
-    (out.join("\n"), compacted)
-}
-
-fn push_run(out: &mut Vec<String>, line: &str, count: usize, min_repeat: usize, compacted: &mut bool) {
-    if count >= min_repeat {
-        out.push(line.to_string());
-        out.push(format!(
-            "... (previous line repeated {} more times)",
-            count.saturating_sub(1)
-        ));
-        *compacted = true;
-    } else {
-        for _ in 0..count {
-            out.push(line.to_string());
-        }
-    }
-}
-
 fn yaml_string(value: &str) -> String {
     serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
 }
@@ -0,0 +1,305 @@
+use crate::model::{Chunk, ChunkKind, FileInput};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use regex::Regex;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Lines searched for a generator's header comment.
+const HEADER_LINES: usize = 10;
+/// Files and chunks smaller than this are never judged minified.
+const MIN_DENSE_BYTES: usize = 1_024;
+/// A line this long is minified or bundled output.
+const LONG_LINE_CHARS: usize = 500;
+/// An unbroken run this long with high byte entropy is an encoded blob (base64, hashes).
+const BLOB_LINE_CHARS: usize = 60;
+const BLOB_ENTROPY_BITS: f64 = 4.5;
+
+/// Dependency lockfiles, written by package managers.
+const GENERATED_FILE_NAMES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lockb",
+    "poetry.lock",
+    "Pipfile.lock",
+    "uv.lock",
+    "Gemfile.lock",
+    "composer.lock",
+    "go.sum",
+    "flake.lock",
+    "packages.lock.json",
+];
+
+/// File name endings of minified bundles and code generator outputs.
+const GENERATED_SUFFIXES: &[&str] = &[
+    ".min.js",
+    ".min.mjs",
+    ".min.css",
+    ".bundle.js",
+    ".js.map",
+    ".css.map",
+    ".pb.go",
+    ".pb.gw.go",
+    "_pb2.py",
+    "_pb2.pyi",
+    "_pb2_grpc.py",
+    "_pb.js",
+    "_pb.d.ts",
+    ".pb.h",
+    ".pb.cc",
+    "_generated.go",
+    ".gen.go",
+    ".generated.ts",
+    ".generated.cs",
+    ".g.cs",
+    ".designer.cs",
+    ".g.dart",
+    ".freezed.dart",
+];
+
+/// Directories holding code generator outputs.
+const GENERATED_DIRS: &[&str] = &["__generated__", "generated"];
+
+/// Directories holding third-party code checked into the repository.
+const VENDORED_DIRS: &[&str] = &["vendor", "third_party", "bower_components", "Pods"];
+
+fn header_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)code generated\b.*\bdo not edit|@generated\b|<auto-generated|\b(?:auto-?generated|generated by)\b.*\bdo not (?:edit|modify)|this file (?:is|was|has been) (?:automatically |auto-?)?generated",
+        )
+        .expect("generated header regex")
+    })
+}
+
+/// `linguist-generated` and `linguist-vendored` rules from `.gitattributes` files.
+#[derive(Default)]
+pub(crate) struct GitAttributes {
+    /// Directory the rule's file sits in (`""` at the root), its pattern, and the
+    /// value it gives `linguist-generated` and `linguist-vendored`.
+    rules: Vec<(String, Gitignore, Option<bool>, Option<bool>)>,
+}
+
+impl GitAttributes {
+    /// Rules from the `.gitattributes` files among `files`, or from the one at the
+    /// root of `root_path` on disk when none was passed in.
+    pub(crate) fn collect(files: &[FileInput], root_path: &str) -> Self {
+        let mut attributes = Self::default();
+        for file in files {
+            if let Some(dir) = attributes_dir(&file.path) {
+                attributes.add(dir, &String::from_utf8_lossy(&file.data));
+            }
+        }
+        if !files.iter().any(|file| attributes_dir(&file.path) == Some("")) && !root_path.is_empty() {
+            if let Ok(text) = std::fs::read_to_string(Path::new(root_path).join(".gitattributes")) {
+                attributes.rules.splice(0..0, Self::parse("", &text));
+            }
+        }
+        attributes
+    }
+
+    /// Rules from a `.gitattributes` file kept from a previous index, read back from
+    /// its chunks.
+    pub(crate) fn add_indexed(&mut self, path: &str, chunks: &[Chunk]) {
+        if let Some(dir) = attributes_dir(path) {
+            let text: Vec<&str> = chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+            self.add(dir, &text.join("\n"));
+        }
+    }
+
+    fn add(&mut self, dir: &str, text: &str) {
+        self.rules.extend(Self::parse(dir, text));
+    }
+
+    fn parse(dir: &str, text: &str) -> Vec<(String, Gitignore, Option<bool>, Option<bool>)> {
+        let mut rules = Vec::new();
+        for line in text.lines() {
+            let mut fields = line.split_whitespace();
+            let Some(pattern) = fields.next().filter(|pattern| !pattern.starts_with(['#', '!'])) else {
+                continue;
+            };
+            let (mut generated, mut vendored) = (None, None);
+            for attribute in fields {
+                let (name, value) = match attribute.split_once('=') {
+                    Some((name, value)) => (name, value != "false"),
+                    None => match attribute.strip_prefix(['-', '!']) {
+                        Some(name) => (name, false),
+                        None => (attribute, true),
+                    },
+                };
+                match name {
+                    "linguist-generated" => generated = Some(value),
+                    "linguist-vendored" => vendored = Some(value),
+                    _ => {}
+                }
+            }
+            if generated.is_none() && vendored.is_none() {
+                continue;
+            }
+            let mut builder = GitignoreBuilder::new(".");
+            if builder.add_line(None, pattern).is_err() {
+                continue;
+            }
+            if let Ok(matcher) = builder.build() {
+                rules.push((dir.to_string(), matcher, generated, vendored));
+            }
+        }
+        rules
+    }
+
+    /// The values of `linguist-generated` and `linguist-vendored` for `path`, if
+    /// any rule sets them. Later rules override earlier ones.
+    pub(crate) fn lookup(&self, path: &str) -> (Option<bool>, Option<bool>) {
+        let path = path.trim_start_matches("./").trim_start_matches('/');
+        let (mut generated, mut vendored) = (None, None);
+        for (dir, matcher, rule_generated, rule_vendored) in &self.rules {
+            let relative = if dir.is_empty() {
+                path
+            } else {
+                match path.strip_prefix(dir.as_str()).and_then(|rest| rest.strip_prefix('/')) {
+                    Some(relative) => relative,
+                    None => continue,
+                }
+            };
+            if matcher.matched_path_or_any_parents(relative, false).is_ignore() {
+                generated = rule_generated.or(generated);
+                vendored = rule_vendored.or(vendored);
+            }
+        }
+        (generated, vendored)
+    }
+}
+
+/// The directory of a `.gitattributes` file, relative to the index root.
+fn attributes_dir(path: &str) -> Option<&str> {
+    let path = path.trim_start_matches("./");
+    if path == ".gitattributes" {
+        return Some("");
+    }
+    path.strip_suffix("/.gitattributes")
+}
+
+/// Whether a file is generated, vendored or minified: by its `.gitattributes`,
+/// name and location, generator header, or the shape of its lines. An attribute
+/// set to false overrides the heuristics for what it covers.
+pub(crate) fn is_generated_file(path: &str, text: &str, kind: ChunkKind, attributes: &GitAttributes) -> bool {
+    let (generated, vendored) = attributes.lookup(path);
+    let vendored = vendored.unwrap_or_else(|| in_dir(path, VENDORED_DIRS));
+    let generated =
+        generated.unwrap_or_else(|| is_generated_path(path) || has_generated_header(text) || is_dense(text, kind));
+    generated || vendored
+}
+
+fn is_generated_path(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    GENERATED_FILE_NAMES.contains(&name)
+        || GENERATED_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+        || in_dir(path, GENERATED_DIRS)
+}
+
+fn in_dir(path: &str, dirs: &[&str]) -> bool {
+    path.split('/').rev().skip(1).any(|dir| dirs.contains(&dir))
+}
+
+fn has_generated_header(text: &str) -> bool {
+    text.lines().take(HEADER_LINES).any(|line| header_re().is_match(line))
+}
+
+/// Whether most of `text` sits on minified lines or encoded blobs. Prose is
+/// exempt: a Markdown paragraph is often a single long line.
+pub(crate) fn is_dense(text: &str, kind: ChunkKind) -> bool {
+    if text.len() < MIN_DENSE_BYTES || matches!(kind, ChunkKind::Markdown | ChunkKind::Text | ChunkKind::Image) {
+        return false;
+    }
+    let dense: usize = text
+        .lines()
+        .filter(|line| {
+            let line = line.trim();
+            line.len() >= LONG_LINE_CHARS
+                || (line.len() >= BLOB_LINE_CHARS && !line.contains(' ') && byte_entropy(line) >= BLOB_ENTROPY_BITS)
+        })
+        .map(str::len)
+        .sum();
+    dense * 2 >= text.len()
+}
+
+/// Shannon entropy of `text`'s bytes, in bits per byte.
+fn byte_entropy(text: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for byte in text.bytes() {
+        counts[byte as usize] += 1;
+    }
+    let len = text.len() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(path: &str, text: &str) -> FileInput {
+        FileInput {
+            path: path.to_string(),
+            data: text.as_bytes().to_vec(),
+            mtime_ms: None,
+            fingerprint_sha256: None,
+        }
+    }
+
+    #[test]
+    fn detects_generated_files_by_header_name_and_shape() {
+        let none = GitAttributes::default();
+        let go = "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage users\n";
+        assert!(is_generated_file("api/users.go", go, ChunkKind::Unknown, &none));
+        assert!(is_generated_file("src/lib.rs", "// @generated by build.rs\n", ChunkKind::Unknown, &none));
+        assert!(is_generated_file("Cargo.lock", "version = 3\n", ChunkKind::Unknown, &none));
+        assert!(is_generated_file("web/app.min.js", "x", ChunkKind::JavaScript, &none));
+        assert!(is_generated_file("vendor/lib/a.go", "package a\n", ChunkKind::Unknown, &none));
+
+        let bundle = format!("var a={};\n", "b:1,".repeat(400));
+        assert!(is_generated_file("web/app.js", &bundle, ChunkKind::JavaScript, &none));
+        let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut seed = 7u32;
+        let mut base64_line = || -> String {
+            (0..76)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    alphabet[(seed >> 16) as usize % 64] as char
+                })
+                .collect()
+        };
+        let encoded: Vec<String> = (0..20).map(|_| base64_line()).collect();
+        let certificate = format!("-----BEGIN CERTIFICATE-----\n{}\n-----END CERTIFICATE-----\n", encoded.join("\n"));
+        assert!(is_dense(&certificate, ChunkKind::Unknown));
+
+        let code = "fn main() {\n    println!(\"hello\");\n}\n".repeat(40);
+        assert!(!is_generated_file("src/main.rs", &code, ChunkKind::Unknown, &none));
+        let prose = "word ".repeat(400);
+        assert!(!is_generated_file("README.md", &prose, ChunkKind::Markdown, &none));
+    }
+
+    #[test]
+    fn gitattributes_mark_and_clear_files() {
+        let files = vec![
+            input(".gitattributes", "# Linguist overrides\n*.snap linguist-generated\nvendor/** -linguist-vendored\n"),
+            input("web/.gitattributes", "dist/* linguist-generated=true\n"),
+        ];
+        let attributes = GitAttributes::collect(&files, "");
+        assert_eq!(attributes.lookup("tests/__snapshots__/a.snap"), (Some(true), None));
+        assert_eq!(attributes.lookup("web/dist/app.js"), (Some(true), None));
+        assert_eq!(attributes.lookup("dist/app.js"), (None, None));
+        assert_eq!(attributes.lookup("vendor/lib/a.go"), (None, Some(false)));
+        assert!(is_generated_file("tests/__snapshots__/a.snap", "", ChunkKind::Unknown, &attributes));
+        assert!(!is_generated_file("vendor/lib/a.go", "package a\n", ChunkKind::Unknown, &attributes));
+    }
+}
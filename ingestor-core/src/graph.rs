@@ -5,7 +5,8 @@
 ///
 /// No serialization overhead: recomputed from the index in <1ms for typical
 /// codebases (10k chunks), so there is no reason to persist it.
-use crate::model::{AstNodeKind, Chunk, Edge, EdgeIndex, EdgeKind, SymbolIndexEntry, SymbolTable};
+use crate::model::{AstNodeKind, Chunk, Edge, EdgeConfidence, EdgeIndex, EdgeKind, SymbolIndexEntry, SymbolTable};
+use crate::resolve::ScopeResolver;
 use std::collections::{BTreeMap, HashMap};
 
 /// Adjacency structure for code relationships.
@@ -58,6 +59,7 @@ pub fn build_symbol_table(chunks: &[Chunk]) -> SymbolTable {
             signature: chunk.signature.clone(),
             doc_summary: chunk.doc_summary.clone(),
             parent_symbol: chunk.parent_symbol.clone(),
+            symbol_id: chunk.symbol_id.clone(),
         };
 
         add_symbol_entry(&mut table, &name, entry.clone());
@@ -76,6 +78,7 @@ pub fn build_symbol_table(chunks: &[Chunk]) -> SymbolTable {
 
 pub fn build_edge_index(chunks: &[Chunk], symbols: &SymbolTable) -> EdgeIndex {
     let mut edges = EdgeIndex::default();
+    let scopes = ScopeResolver::build(chunks);
 
     for chunk in chunks {
         push_edges(
@@ -84,6 +87,7 @@ pub fn build_edge_index(chunks: &[Chunk], symbols: &SymbolTable) -> EdgeIndex {
             &chunk.imports,
             EdgeKind::Imports,
             symbols,
+            &scopes,
         );
         push_edges(
             &mut edges,
@@ -91,6 +95,7 @@ pub fn build_edge_index(chunks: &[Chunk], symbols: &SymbolTable) -> EdgeIndex {
             &chunk.calls,
             EdgeKind::Calls,
             symbols,
+            &scopes,
         );
         push_edges(
             &mut edges,
@@ -98,9 +103,31 @@ pub fn build_edge_index(chunks: &[Chunk], symbols: &SymbolTable) -> EdgeIndex {
             &chunk.type_refs,
             EdgeKind::TypeRef,
             symbols,
+            &scopes,
         );
     }
 
+    // File-level module dependencies, reachable in reverse through the imported file's path.
+    for import in scopes.module_imports() {
+        let edge = Edge {
+            source_chunk_id: import.source.id.clone(),
+            target_symbol: import.specifier.to_string(),
+            target_chunk_id: Some(import.target.id.clone()),
+            edge_kind: EdgeKind::Imports,
+            confidence: Some(EdgeConfidence::Precise),
+        };
+        edges
+            .forward
+            .entry(import.source.id.clone())
+            .or_default()
+            .push(edge.clone());
+        edges
+            .reverse
+            .entry(file_symbol_key(&import.target.path))
+            .or_default()
+            .push(edge);
+    }
+
     for edge_list in edges.forward.values_mut() {
         edge_list.sort_by(|a, b| {
             a.target_symbol
@@ -178,6 +205,13 @@ impl CodeGraph {
                     .entry(import_name.clone())
                     .or_default()
                     .push(chunk_id.clone());
+                let local_name = import_lookup_name(import_name);
+                if local_name != import_name {
+                    graph.importers
+                        .entry(local_name.to_string())
+                        .or_default()
+                        .push(chunk_id.clone());
+                }
             }
         }
 
@@ -319,22 +353,39 @@ fn push_edges(
     targets: &[String],
     edge_kind: EdgeKind,
     symbols: &SymbolTable,
+    scopes: &ScopeResolver,
 ) {
     for target_symbol in targets {
         if target_symbol.trim().is_empty() {
             continue;
         }
 
-        let resolved_target = resolve_target_symbol(chunk, target_symbol, symbols);
-        let reverse_key = resolved_target
-            .map(|entry| canonical_symbol_key(&entry.qualified_name))
-            .unwrap_or_else(|| raw_symbol_key(target_symbol));
+        // Scope rules first; name matching only for what they cannot bind.
+        let (target_chunk_id, reverse_key, confidence) =
+            if let Some(binding) = scopes.resolve(chunk, target_symbol) {
+                (
+                    Some(binding.chunk.id.clone()),
+                    canonical_symbol_key(binding.name),
+                    Some(EdgeConfidence::Precise),
+                )
+            } else if let Some(entry) = resolve_target_symbol(chunk, target_symbol, symbols) {
+                (
+                    Some(entry.chunk_id.clone()),
+                    canonical_symbol_key(&entry.qualified_name),
+                    Some(EdgeConfidence::Heuristic),
+                )
+            } else if edge_kind == EdgeKind::Imports {
+                (None, raw_symbol_key(import_lookup_name(target_symbol)), None)
+            } else {
+                (None, raw_symbol_key(target_symbol), None)
+            };
 
         let edge = Edge {
             source_chunk_id: chunk.id.clone(),
             target_symbol: target_symbol.clone(),
-            target_chunk_id: resolved_target.map(|entry| entry.chunk_id.clone()),
+            target_chunk_id,
             edge_kind,
+            confidence,
         };
 
         edges
@@ -355,22 +406,106 @@ fn resolve_target_symbol<'a>(
     target_symbol: &str,
     symbols: &'a SymbolTable,
 ) -> Option<&'a SymbolIndexEntry> {
-    let candidates = symbols.get(&normalize_symbol_key(target_symbol))?;
-    unique_symbol_entry(candidates.iter().filter(|candidate| candidate.path == chunk.path))
-        .or_else(|| unique_symbol_entry(candidates.iter()))
+    if let Some(candidates) = symbols.get(&normalize_symbol_key(target_symbol)) {
+        return unique_symbol_entry(candidates.iter().filter(|candidate| candidate.path == chunk.path))
+            .or_else(|| unique_symbol_entry(candidates.iter()));
+    }
+
+    // Path-qualified targets (`util::sha256_hex`, `helpers.compute`, `./auth#verify`) fall
+    // back to their tail, accepted only when the qualifier names the owning type or module file.
+    let (qualifier, tail) = split_qualified_path(target_symbol)?;
+    let qualifier = strip_path_anchors(qualifier);
+    let candidates = symbols.get(&normalize_symbol_key(tail))?;
+    if qualifier.is_empty() {
+        return unique_symbol_entry(candidates.iter().filter(|candidate| candidate.path == chunk.path))
+            .or_else(|| unique_symbol_entry(candidates.iter()));
+    }
+    let owner = match qualifier.rsplit_once('/') {
+        Some((_, module)) => module,
+        None => split_qualified_path(qualifier).map_or(qualifier, |(_, owner)| owner),
+    };
+    unique_symbol_entry(
+        candidates
+            .iter()
+            .filter(|candidate| qualifier_names_candidate(owner, candidate)),
+    )
+}
+
+/// Split `a::b::c`, `a.b.c` or `./module#name` at its last separator.
+fn split_qualified_path(path: &str) -> Option<(&str, &str)> {
+    if let Some((module, name)) = path.rsplit_once('#') {
+        return Some((module, name));
+    }
+    let colons = path.rfind("::").map(|idx| (idx, idx + 2));
+    let dot = path.rfind('.').map(|idx| (idx, idx + 1));
+    let (end, start) = match (colons, dot) {
+        (Some(colons), Some(dot)) => colons.max(dot),
+        (Some(split), None) | (None, Some(split)) => split,
+        (None, None) => return None,
+    };
+    Some((&path[..end], &path[start..]))
+}
+
+fn strip_path_anchors(qualifier: &str) -> &str {
+    // Python relative imports lead with dots (`..pkg.mod`).
+    let mut qualifier = qualifier.trim_start_matches('.');
+    loop {
+        let stripped = ["crate", "self", "super"].iter().find_map(|anchor| {
+            if qualifier == *anchor {
+                Some("")
+            } else {
+                qualifier.strip_prefix(anchor).and_then(|rest| rest.strip_prefix("::"))
+            }
+        });
+        match stripped {
+            Some(rest) => qualifier = rest,
+            None => return qualifier,
+        }
+    }
+}
+
+fn qualifier_names_candidate(owner: &str, candidate: &SymbolIndexEntry) -> bool {
+    let parent = candidate
+        .parent_symbol
+        .as_deref()
+        .map(|parent| split_qualified_path(parent).map_or(parent, |(_, tail)| tail));
+    if parent == Some(owner) {
+        return true;
+    }
+    let mut segments = candidate.path.rsplit('/');
+    let file = segments.next().unwrap_or("");
+    let stem = file.split('.').next().unwrap_or(file);
+    if stem == owner || file.rsplit_once('.').is_some_and(|(name, _)| name == owner) {
+        return true;
+    }
+    // Module directories (`util/mod.rs`, `pkg/__init__.py`) and Go packages name the directory.
+    segments.next() == Some(owner)
 }
 
 fn unique_symbol_entry<'a>(
     mut candidates: impl Iterator<Item = &'a SymbolIndexEntry>,
 ) -> Option<&'a SymbolIndexEntry> {
     let first = candidates.next()?;
-    if candidates.any(|candidate| {
-        candidate.chunk_id != first.chunk_id || candidate.qualified_name != first.qualified_name
+    let rest: Vec<&SymbolIndexEntry> = candidates.collect();
+    if rest.iter().all(|candidate| {
+        candidate.chunk_id == first.chunk_id && candidate.qualified_name == first.qualified_name
     }) {
-        None
-    } else {
-        Some(first)
+        return Some(first);
     }
+    // A declaration and its definition are one symbol; the definition is the better target.
+    if first.symbol_id.is_some() && rest.iter().all(|candidate| candidate.symbol_id == first.symbol_id) {
+        return std::iter::once(first)
+            .chain(rest)
+            .max_by_key(|candidate| (!is_header_path(&candidate.path), candidate.end_line - candidate.start_line));
+    }
+    None
+}
+
+fn is_header_path(path: &str) -> bool {
+    matches!(
+        path.rsplit('.').next(),
+        Some("h" | "hh" | "hpp" | "hxx")
+    )
 }
 
 pub fn normalize_symbol_key(symbol: &str) -> String {
@@ -385,6 +520,22 @@ pub fn raw_symbol_key(symbol: &str) -> String {
     format!("raw::{}", normalize_symbol_key(symbol))
 }
 
+/// The name an import is looked up by when nothing in the index binds it: the
+/// imported name of `react#useState`, and the module's last segment for default
+/// and namespace imports (`express#default`, `./utils#*`).
+pub fn import_lookup_name(import: &str) -> &str {
+    match import.rsplit_once('#') {
+        Some((specifier, "default" | "*")) => specifier.rsplit('/').next().unwrap_or(specifier),
+        Some((_, name)) if !name.is_empty() => name,
+        _ => import,
+    }
+}
+
+/// Reverse-edge key for a whole file, used by file-level module imports.
+pub fn file_symbol_key(path: &str) -> String {
+    format!("file::{}", normalize_symbol_key(path))
+}
+
 pub fn ast_kind_label(ast_kind: AstNodeKind) -> &'static str {
     match ast_kind {
         AstNodeKind::Function => "function",
@@ -394,6 +545,7 @@ pub fn ast_kind_label(ast_kind: AstNodeKind) -> &'static str {
         AstNodeKind::Interface => "interface",
         AstNodeKind::Type => "type",
         AstNodeKind::Enum => "enum",
+        AstNodeKind::Table => "table",
         AstNodeKind::Constant => "constant",
         AstNodeKind::Variable => "variable",
         AstNodeKind::Import => "import",
@@ -438,6 +590,7 @@ mod tests {
             is_generated: false,
             quality_score: None,
             resolution_tier: crate::model::ResolutionTier::GenericTreeSitter,
+            log_level: None,
             ast_kind: Some(AstNodeKind::Function),
             qualified_name: Some(symbol.to_string()),
             symbol_id: None,
@@ -449,6 +602,7 @@ mod tests {
             imports: imports.into_iter().map(|s| s.to_string()).collect(),
             exports: exports.into_iter().map(|s| s.to_string()).collect(),
             calls: calls.into_iter().map(|s| s.to_string()).collect(),
+            method_calls: Vec::new(),
             type_refs: Vec::new(),
             doc_summary: None,
         }
@@ -515,6 +669,18 @@ mod tests {
         assert_eq!(importers[0], "c1");
     }
 
+    #[test]
+    fn unresolved_module_imports_are_found_by_their_local_name() {
+        let chunks = vec![make_chunk("c1", "App", vec![], vec!["react#useState", "express#default"], vec![])];
+        let graph = CodeGraph::build(&chunks);
+        assert_eq!(graph.get_importers("useState"), ["c1"]);
+        assert_eq!(graph.get_importers("express"), ["c1"]);
+
+        let edges = build_edge_index(&chunks, &build_symbol_table(&chunks));
+        assert_eq!(edges.reverse[&raw_symbol_key("useState")][0].target_symbol, "react#useState");
+        assert_eq!(edges.reverse[&raw_symbol_key("express")][0].target_symbol, "express#default");
+    }
+
     #[test]
     fn test_walk_callees_two_hops() {
         let chunks = vec![
@@ -567,4 +733,31 @@ mod tests {
 
         assert_eq!(call_edge.target_chunk_id.as_deref(), Some("c1"));
     }
+
+    #[test]
+    fn test_build_edge_index_prefers_definition_over_header_declaration() {
+        let mut declared = make_chunk("c1", "token_parse", vec![], vec![], vec![]);
+        declared.path = "include/token.h".to_string();
+        declared.start_line = 4;
+        declared.end_line = 4;
+        declared.symbol_id = Some("cxx token_parse()".to_string());
+
+        let mut defined = make_chunk("c2", "token_parse", vec![], vec![], vec![]);
+        defined.path = "src/token.c".to_string();
+        defined.symbol_id = Some("cxx token_parse()".to_string());
+
+        let mut caller = make_chunk("c3", "main", vec!["token_parse"], vec![], vec![]);
+        caller.path = "src/main.c".to_string();
+
+        let chunks = vec![declared, defined, caller];
+        let symbols = build_symbol_table(&chunks);
+        let edges = build_edge_index(&chunks, &symbols);
+
+        let call_edge = edges
+            .forward
+            .get("c3")
+            .and_then(|edges| edges.iter().find(|edge| edge.edge_kind == EdgeKind::Calls))
+            .expect("token_parse call edge should exist");
+        assert_eq!(call_edge.target_chunk_id.as_deref(), Some("c2"));
+    }
 }
@@ -252,6 +252,7 @@ mod tests {
                 is_generated: false,
                 quality_score: None,
                 resolution_tier: crate::ResolutionTier::GenericTreeSitter,
+                log_level: None,
                 ast_kind: None,
                 qualified_name: None,
                 symbol_id: None,
@@ -263,6 +264,7 @@ mod tests {
                 imports: Vec::new(),
                 exports: Vec::new(),
                 calls: Vec::new(),
+                method_calls: Vec::new(),
                 type_refs: Vec::new(),
                 doc_summary: None,
             }],
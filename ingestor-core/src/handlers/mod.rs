@@ -15,10 +15,10 @@ pub use safety::{
 };
 pub use storage::{IndexMetadata, IndexStore, Registry};
 pub use types::*;
-pub use crate::walk::{ALLOWED_DOTFILES, ALLOWED_EXTENSIONS};
+pub use crate::walk::{ALLOWED_BUILD_FILES, ALLOWED_DOTFILES, ALLOWED_EXTENSIONS};
 
 use crate::{
-    graph::{ast_kind_label, canonical_symbol_key, normalize_symbol_key, raw_symbol_key, CodeGraph},
+    graph::{ast_kind_label, canonical_symbol_key, file_symbol_key, normalize_symbol_key, raw_symbol_key, CodeGraph},
     ingest_files_with_root, search, search_advanced, Edge, EdgeKind, IndexFile, IngestOptions,
     QueryIntent, SearchFilters, SymbolIndexEntry, DEFAULT_MAX_FILE_BYTES,
 };
@@ -98,6 +98,12 @@ pub fn llmx_index_handler(store: &mut IndexStore, input: IndexInput) -> Result<I
             .and_then(|o| o.max_total_bytes)
             .unwrap_or(usize::MAX),
         max_chunks_per_file: 2000,
+        notebook_outputs: input
+            .options
+            .as_ref()
+            .and_then(|o| o.notebook_outputs)
+            .unwrap_or(false),
+        sample_data_rows: input.options.as_ref().and_then(|o| o.sample_data_rows),
     };
 
     let index = {
@@ -316,6 +322,7 @@ pub fn llmx_symbols_handler(store: &mut IndexStore, input: SymbolsInput) -> Resu
                 doc_summary: chunk.doc_summary.clone(),
                 exported,
                 chunk_id: chunk.id.clone(),
+                symbol_id: chunk.symbol_id.clone(),
             }
         })
         .collect();
@@ -372,6 +379,7 @@ pub fn llmx_lookup_handler(store: &mut IndexStore, input: LookupInput) -> Result
             doc_summary: entry.doc_summary.clone(),
             exported,
             chunk_id: entry.chunk_id.clone(),
+            symbol_id: entry.symbol_id.clone(),
         });
     };
 
@@ -465,6 +473,7 @@ fn parse_ast_kind_filter(s: &str) -> crate::model::AstNodeKind {
         "interface" => AstNodeKind::Interface,
         "type" => AstNodeKind::Type,
         "enum" => AstNodeKind::Enum,
+        "table" => AstNodeKind::Table,
         "constant" => AstNodeKind::Constant,
         "variable" => AstNodeKind::Variable,
         "import" => AstNodeKind::Import,
@@ -584,6 +593,7 @@ fn ast_kind_label_for_stats(kind: crate::model::AstNodeKind) -> &'static str {
         crate::model::AstNodeKind::Interface => "interface",
         crate::model::AstNodeKind::Type => "type",
         crate::model::AstNodeKind::Enum => "enum",
+        crate::model::AstNodeKind::Table => "table",
         crate::model::AstNodeKind::Constant => "constant",
         crate::model::AstNodeKind::Variable => "variable",
         crate::model::AstNodeKind::Import => "import",
@@ -741,11 +751,26 @@ fn lookup_symbol_chunk_ids(index: &crate::IndexFile, symbol: &str) -> Vec<String
         }
     }
 
+    // A file path stands for its first chunk, where file-level module imports start.
+    let file_head = index
+        .chunks
+        .iter()
+        .filter(|chunk| chunk.path == symbol)
+        .min_by_key(|chunk| chunk.chunk_index);
+    if let Some(chunk) = file_head {
+        if seen.insert(chunk.id.clone()) {
+            chunk_ids.push(chunk.id.clone());
+        }
+    }
+
     chunk_ids
 }
 
 fn resolve_reverse_keys(index: &crate::IndexFile, symbol: &str) -> Vec<String> {
-    let keys = resolve_symbol_lookup_keys(index, symbol);
+    let mut keys = resolve_symbol_lookup_keys(index, symbol);
+    if index.files.iter().any(|file| file.path == symbol) {
+        keys.push(file_symbol_key(symbol));
+    }
     if keys.is_empty() {
         vec![raw_symbol_key(symbol)]
     } else {
@@ -816,6 +841,7 @@ fn build_ref_result(
         context: crate::util::snippet(&context_chunk.content, 200),
         chunk_id: context_chunk.id.clone(),
         target_chunk_id: edge.target_chunk_id.clone(),
+        confidence: edge.confidence,
     })
 }
 
@@ -1049,6 +1075,8 @@ pub fn llmx_search_dynamic_handler(
         max_file_bytes: DEFAULT_MAX_FILE_BYTES,
         max_total_bytes: usize::MAX,
         max_chunks_per_file: 2000,
+        notebook_outputs: false,
+        sample_data_rows: None,
     };
 
     let index = {
@@ -1136,6 +1164,8 @@ fn perform_search(
             kind: f.kind.as_ref().and_then(|k| parse_chunk_kind(k)),
             heading_prefix: f.heading_prefix.clone(),
             symbol_prefix: f.symbol_prefix.clone(),
+            log_level: f.log_level.as_ref().and_then(|level| parse_log_level(level)),
+            exclude_generated: f.exclude_generated.unwrap_or(false),
         })
         .unwrap_or_default();
 
@@ -1269,6 +1299,18 @@ fn parse_chunk_kind(s: &str) -> Option<crate::ChunkKind> {
     }
 }
 
+fn parse_log_level(s: &str) -> Option<crate::LogLevel> {
+    match s {
+        "trace" => Some(crate::LogLevel::Trace),
+        "debug" => Some(crate::LogLevel::Debug),
+        "info" => Some(crate::LogLevel::Info),
+        "warn" | "warning" => Some(crate::LogLevel::Warn),
+        "error" => Some(crate::LogLevel::Error),
+        "fatal" => Some(crate::LogLevel::Fatal),
+        _ => None,
+    }
+}
+
 fn parse_query_intent(value: Option<&str>) -> Result<QueryIntent> {
     Ok(match value {
         None => QueryIntent::Auto,
@@ -1359,7 +1401,7 @@ fn semantic_only_search(
         #[cfg(feature = "embeddings")]
         for result in &mut results {
             if result.match_reason.is_none() {
-                result.match_reason = Some(crate::query::explain_match(&[("dense", result.score)], None, query));
+                result.match_reason = Some(crate::query::explain_match(&[("dense", result.score)], None, query, &[]));
             }
         }
     }
@@ -19,6 +19,8 @@ pub struct IngestOptionsInput {
     pub chunk_target_chars: Option<usize>,
     pub max_file_bytes: Option<usize>,
     pub max_total_bytes: Option<usize>,
+    pub notebook_outputs: Option<bool>,
+    pub sample_data_rows: Option<usize>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -49,6 +51,8 @@ pub struct SearchFiltersInput {
     pub kind: Option<String>,
     pub symbol_prefix: Option<String>,
     pub heading_prefix: Option<String>,
+    pub log_level: Option<String>,
+    pub exclude_generated: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -264,6 +268,8 @@ pub struct SymbolEntry {
     #[serde(skip_serializing_if = "is_false")]
     pub exported: bool,
     pub chunk_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_id: Option<String>,
 }
 
 fn is_false(b: &bool) -> bool { !b }
@@ -318,6 +324,9 @@ pub struct RefResult {
     pub chunk_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_chunk_id: Option<String>,
+    /// `precise` when bound by scope rules, `heuristic` when by name matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<crate::EdgeConfidence>,
 }
 
 #[derive(Debug, Serialize)]
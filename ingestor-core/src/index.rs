@@ -218,6 +218,14 @@ pub(crate) fn passes_filters(chunk: &Chunk, filters: &SearchFilters) -> bool {
             return false;
         }
     }
+    if let Some(level) = filters.log_level {
+        if chunk.log_level.is_none_or(|chunk_level| chunk_level < level) {
+            return false;
+        }
+    }
+    if filters.exclude_generated && chunk.is_generated {
+        return false;
+    }
     true
 }
 
@@ -577,6 +585,7 @@ mod tests {
             is_generated: false,
             quality_score: None,
             resolution_tier: crate::model::ResolutionTier::GenericTreeSitter,
+            log_level: None,
             ast_kind: Some(AstNodeKind::Function),
             qualified_name: Some("verify".to_string()),
             symbol_id: None,
@@ -588,6 +597,7 @@ mod tests {
             imports: Vec::new(),
             exports: Vec::new(),
             calls: Vec::new(),
+            method_calls: Vec::new(),
             type_refs: vec!["Claims".to_string()],
             doc_summary: None,
         };
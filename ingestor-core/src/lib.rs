@@ -3,11 +3,14 @@
 mod chunk;
 mod embedding_store;
 mod export;
+mod generated;
 pub mod handlers;
 mod index;
 mod model;
 pub mod pathnorm;
+mod quality;
 mod registry_lock;
+mod resolve;
 pub mod util;
 pub mod walk;
 
@@ -42,7 +45,9 @@ use crate::symbol_search::symbol_search;
 use crate::pathnorm::{infer_root_path, normalize_root_path};
 use crate::util::{build_chunk_refs, detect_kind, detect_language, sha256_hex};
 use crate::graph::build_structural_indexes;
-use std::collections::{BTreeMap, HashMap};
+use crate::generated::GitAttributes;
+use crate::chunk::GoModules;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 /// Return the default storage directory for llmx indexes.
@@ -254,14 +259,19 @@ fn stamp_chunk_metadata(
     root_path: &str,
     path: &str,
     language: Option<LanguageId>,
-    resolution_tier: ResolutionTier,
+    is_generated: bool,
 ) {
     for chunk in chunks {
         chunk.path = path.to_string();
         chunk.root_path = root_path.to_string();
         chunk.relative_path = path.to_string();
         chunk.language = language.clone();
-        chunk.resolution_tier = resolution_tier;
+        // A minified bundle or encoded blob can sit inside an otherwise handwritten file.
+        // JSON chunks hold values re-serialized onto one line, so only the file's own
+        // lines say whether it was minified.
+        chunk.is_generated =
+            is_generated || (chunk.kind != ChunkKind::Json && generated::is_dense(&chunk.content, chunk.kind));
+        chunk.quality_score = Some(quality::quality_score(chunk));
     }
 }
 
@@ -269,6 +279,8 @@ fn ingest_one_file(
     file: FileInput,
     options: &IngestOptions,
     root_path: &str,
+    attributes: &GitAttributes,
+    go_modules: &GoModules,
     warnings: &mut Vec<IngestWarning>,
 ) -> Option<(FileMeta, Vec<Chunk>, usize)> {
     let path = file.path;
@@ -287,20 +299,15 @@ fn ingest_one_file(
 
     let kind = detect_kind(&path);
     let language = detect_language(&path);
-    let resolution_tier = if language.is_some() {
-        ResolutionTier::GenericTreeSitter
-    } else {
-        ResolutionTier::TextOnly
-    };
     let file_hash = sha256_hex(&data);
     let bytes_len = data.len();
 
-    let (line_count, mut file_chunks) = if kind == ChunkKind::Image {
+    let (line_count, is_generated, mut file_chunks) = if kind == ChunkKind::Image {
         let mut chunks = chunk::chunk_file(&path, "", kind, options);
         for chunk in &mut chunks {
             chunk.asset_path = Some(format!("images/{}", sanitize_zip_path(&path)));
         }
-        (1usize, chunks)
+        (1usize, false, chunks)
     } else {
         let text = match String::from_utf8(data) {
             Ok(text) => text,
@@ -314,7 +321,22 @@ fn ingest_one_file(
             }
         };
         let line_count = text.lines().count().max(1);
-        (line_count, chunk::chunk_file(&path, &text, kind, options))
+        let is_generated = generated::is_generated_file(&path, &text, kind, attributes);
+        let sample = options
+            .sample_data_rows
+            .and_then(|limit| chunk::sample_records(&path, &text, limit).map(|sample| (limit, sample)));
+        let text = match sample {
+            Some((limit, (sample, records))) => {
+                warnings.push(IngestWarning {
+                    path: path.clone(),
+                    code: "sampled_rows".to_string(),
+                    message: format!("Indexed the first {limit} of {records} records; the rest were skipped."),
+                });
+                sample
+            }
+            None => text,
+        };
+        (line_count, is_generated, chunk::chunk_file(&path, &text, kind, options))
     };
 
     if file_chunks.len() > options.max_chunks_per_file {
@@ -326,7 +348,20 @@ fn ingest_one_file(
         file_chunks.truncate(options.max_chunks_per_file);
     }
 
-    stamp_chunk_metadata(&mut file_chunks, root_path, &path, language.clone(), resolution_tier);
+    if language == Some(LanguageId::Go) {
+        if let Some(import_path) = go_modules.import_path(&path, root_path) {
+            for chunk in file_chunks.iter_mut().filter(|chunk| chunk.module_path.is_some()) {
+                chunk.module_path = Some(import_path.clone());
+            }
+        }
+    }
+    stamp_chunk_metadata(&mut file_chunks, root_path, &path, language.clone(), is_generated);
+    // The file reports the strongest tier any of its chunks were resolved with.
+    let resolution_tier = file_chunks
+        .iter()
+        .map(|chunk| chunk.resolution_tier)
+        .min()
+        .unwrap_or(ResolutionTier::TextOnly);
 
     Some((
         FileMeta {
@@ -338,7 +373,7 @@ fn ingest_one_file(
             bytes: bytes_len,
             sha256: file_hash,
             line_count,
-            is_generated: false,
+            is_generated,
             resolution_tier,
             mtime_ms,
             fingerprint_sha256,
@@ -360,6 +395,8 @@ pub fn ingest_files_with_root(
     files.sort_by(|a, b| a.path.cmp(&b.path));
 
     let root_path = prepare_root_path(&files, root_hint);
+    let attributes = GitAttributes::collect(&files, &root_path);
+    let go_modules = GoModules::collect(&files, &root_path);
     let mut warnings = Vec::new();
     let mut total_bytes = 0usize;
     let mut file_metas = Vec::new();
@@ -376,7 +413,7 @@ pub fn ingest_files_with_root(
         }
 
         let Some((file_meta, file_chunks, bytes_len)) =
-            ingest_one_file(file, &options, &root_path, &mut warnings)
+            ingest_one_file(file, &options, &root_path, &attributes, &go_modules, &mut warnings)
         else {
             continue;
         };
@@ -413,6 +450,8 @@ pub fn update_index(prev: IndexFile, files: Vec<FileInput>, options: IngestOptio
     let mut file_metas = Vec::new();
     let mut chunks = Vec::new();
     let root_path = prepare_root_path(&new_files, None);
+    let attributes = GitAttributes::collect(&new_files, &root_path);
+    let go_modules = GoModules::collect(&new_files, &root_path);
     let mut total_bytes = 0usize;
 
     for file in new_files {
@@ -434,7 +473,7 @@ pub fn update_index(prev: IndexFile, files: Vec<FileInput>, options: IngestOptio
             }
         }
         let Some((file_meta, file_chunks, bytes_len)) =
-            ingest_one_file(file, &options, &root_path, &mut warnings)
+            ingest_one_file(file, &options, &root_path, &attributes, &go_modules, &mut warnings)
         else {
             continue;
         };
@@ -480,6 +519,14 @@ pub fn update_index_selective(
     keep_paths_sorted.sort();
     keep_paths_sorted.dedup();
 
+    let mut attributes = GitAttributes::collect(&new_files, &root_path);
+    let go_modules = GoModules::collect(&new_files, &root_path);
+    for path in &keep_paths_sorted {
+        if let Some((_, kept_chunks)) = prev_map.get(path) {
+            attributes.add_indexed(path, kept_chunks);
+        }
+    }
+
     for path in keep_paths_sorted {
         if let Some((meta, existing_chunks)) = prev_map.get(&path) {
             file_metas.push(meta.clone());
@@ -508,7 +555,7 @@ pub fn update_index_selective(
             }
         }
         let Some((file_meta, file_chunks, bytes_len)) =
-            ingest_one_file(file, &options, &root_path, &mut warnings)
+            ingest_one_file(file, &options, &root_path, &attributes, &go_modules, &mut warnings)
         else {
             continue;
         };
@@ -599,18 +646,25 @@ pub fn search_advanced(
     #[cfg(not(feature = "embeddings"))]
     let dense_results: Vec<SearchResult> = Vec::new();
 
-    let bm25_scored: Vec<(&str, f32)> = bm25_results
-        .iter()
-        .map(|result| (result.chunk_id.as_str(), result.score))
-        .collect();
-    let symbol_scored: Vec<(&str, f32)> = symbol_results
-        .iter()
-        .map(|result| (result.chunk_id.as_str(), result.score))
-        .collect();
-    let dense_scored: Vec<(&str, f32)> = dense_results
-        .iter()
-        .map(|result| (result.chunk_id.as_str(), result.score))
-        .collect();
+    let chunk_map: HashMap<&str, &Chunk> = index.chunks.iter().map(|chunk| (chunk.id.as_str(), chunk)).collect();
+    // Source-quality priors: logs repeat the words of the code that writes them, and
+    // generated, vendored and minified code the names of the code it was built from,
+    // so both rank below handwritten source, as do tests, docs and examples.
+    let scored = |results: &[SearchResult]| -> Vec<(String, f32)> {
+        let mut scored: Vec<(String, f32)> = results
+            .iter()
+            .map(|result| {
+                let factor =
+                    chunk_map.get(result.chunk_id.as_str()).map_or(1.0, |chunk| quality::quality_factor(chunk));
+                (result.chunk_id.clone(), result.score * factor)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored
+    };
+    let bm25_scored = scored(&bm25_results);
+    let symbol_scored = scored(&symbol_results);
+    let dense_scored = scored(&dense_results);
 
     let mut engine_results = vec![
         ("bm25", weights.bm25, to_ranked_results(&bm25_scored)),
@@ -625,8 +679,6 @@ pub fn search_advanced(
         return Ok(Vec::new());
     }
 
-    let chunk_map: HashMap<&str, &Chunk> = index.chunks.iter().map(|chunk| (chunk.id.as_str(), chunk)).collect();
-
     Ok(fused
         .into_iter()
         .filter_map(|result| {
@@ -659,6 +711,7 @@ pub fn search_advanced(
                         &engine_scores,
                         chunk.qualified_name.as_deref().or(chunk.symbol.as_deref()),
                         query,
+                        &quality::quality_priors(chunk),
                     )
                 }),
                 matched_engines,
@@ -679,8 +732,8 @@ fn compute_index_id(files: &[FileMeta]) -> String {
 }
 
 fn build_index(
-    file_metas: Vec<FileMeta>,
-    chunks: Vec<Chunk>,
+    mut file_metas: Vec<FileMeta>,
+    mut chunks: Vec<Chunk>,
     warnings: Vec<IngestWarning>,
     embeddings: Option<Vec<Vec<f32>>>,
     embedding_model: Option<String>,
@@ -690,6 +743,7 @@ fn build_index(
     let stats = compute_stats(&file_metas, &chunks);
     let index_id = compute_index_id(&file_metas);
     let (symbols, edges) = build_structural_indexes(&chunks);
+    promote_scope_resolved(&mut file_metas, &mut chunks, &edges);
 
     IndexFile {
         version: INDEX_VERSION,
@@ -707,6 +761,38 @@ fn build_index(
     }
 }
 
+/// Raise chunks with a reference bound by scope rules, and their files, to the
+/// StackGraph tier; the rest keep the tier their adapter parsed them at.
+fn promote_scope_resolved(files: &mut [FileMeta], chunks: &mut [Chunk], edges: &EdgeIndex) {
+    // Files reused by an update were raised against the old index; start them over.
+    let mut adapter_tiers: HashMap<String, ResolutionTier> = HashMap::new();
+    for file in files.iter_mut().filter(|file| file.resolution_tier == ResolutionTier::StackGraph) {
+        file.resolution_tier = chunk::adapter_tier(&file.path, file.kind);
+        adapter_tiers.insert(file.path.clone(), file.resolution_tier);
+    }
+    for chunk in chunks.iter_mut().filter(|chunk| chunk.resolution_tier == ResolutionTier::StackGraph) {
+        chunk.resolution_tier = adapter_tiers
+            .get(&chunk.path)
+            .copied()
+            .unwrap_or_else(|| chunk::adapter_tier(&chunk.path, chunk.kind));
+    }
+
+    let bound: HashSet<&str> = edges
+        .forward
+        .iter()
+        .filter(|(_, edges)| edges.iter().any(|edge| edge.confidence == Some(EdgeConfidence::Precise)))
+        .map(|(chunk_id, _)| chunk_id.as_str())
+        .collect();
+    let mut bound_files: HashSet<String> = HashSet::new();
+    for chunk in chunks.iter_mut().filter(|chunk| bound.contains(chunk.id.as_str())) {
+        chunk.resolution_tier = ResolutionTier::StackGraph;
+        bound_files.insert(chunk.path.clone());
+    }
+    for file in files.iter_mut().filter(|file| bound_files.contains(&file.path)) {
+        file.resolution_tier = ResolutionTier::StackGraph;
+    }
+}
+
 fn sanitize_zip_path(input: &str) -> String {
     let replaced = input.replace('\\', "/");
     let mut parts = Vec::new();
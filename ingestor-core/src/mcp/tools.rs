@@ -1,4 +1,4 @@
-use crate::graph::{ast_kind_label, canonical_symbol_key, normalize_symbol_key, raw_symbol_key, CodeGraph};
+use crate::graph::{ast_kind_label, canonical_symbol_key, file_symbol_key, normalize_symbol_key, raw_symbol_key, CodeGraph};
 use crate::handlers::MAX_SEARCH_LIMIT;
 use crate::mcp::jobs::{JobStatus, JobStore};
 use crate::mcp::storage::{IndexStore, IndexMetadata};
@@ -60,6 +60,10 @@ pub struct IngestOptionsInput {
     pub max_file_bytes: Option<usize>,
     #[cfg_attr(feature = "mcp", schemars(description = "Maximum total bytes to ingest (default 100MB)"))]
     pub max_total_bytes: Option<usize>,
+    #[cfg_attr(feature = "mcp", schemars(description = "Index the text outputs of notebook cells (default false)"))]
+    pub notebook_outputs: Option<bool>,
+    #[cfg_attr(feature = "mcp", schemars(description = "Index only the first N records of larger CSV and JSONL files"))]
+    pub sample_data_rows: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -134,6 +138,10 @@ pub struct SearchFiltersInput {
     pub symbol_prefix: Option<String>,
     #[cfg_attr(feature = "mcp", schemars(description = "Filter by heading prefix"))]
     pub heading_prefix: Option<String>,
+    #[cfg_attr(feature = "mcp", schemars(description = "Only log chunks at or above this level (trace, debug, info, warn, error, fatal)"))]
+    pub log_level: Option<String>,
+    #[cfg_attr(feature = "mcp", schemars(description = "Leave out generated, vendored and minified files"))]
+    pub exclude_generated: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -255,9 +263,9 @@ pub struct SymbolsInput {
     #[serde(default)]
     #[cfg_attr(feature = "mcp", schemars(description = "Symbol name pattern: exact 'foo', prefix 'foo*', or substring '*foo*'"))]
     pub pattern: Option<String>,
-    /// Filter by AST kind: function, method, class, interface, type, enum, constant, variable, test.
+    /// Filter by AST kind: function, method, class, interface, type, enum, table, constant, variable, test.
     #[serde(default)]
-    #[cfg_attr(feature = "mcp", schemars(description = "Filter by kind: function, method, class, interface, type, enum, constant, variable, test"))]
+    #[cfg_attr(feature = "mcp", schemars(description = "Filter by kind: function, method, class, interface, type, enum, table, constant, variable, test"))]
     pub ast_kind: Option<String>,
     /// Filter by file path prefix.
     #[serde(default)]
@@ -290,6 +298,9 @@ pub struct SymbolEntry {
     pub exported: bool,
     /// Chunk ID for follow-up `get_chunk` calls.
     pub chunk_id: String,
+    /// Shared by a declaration and its definition, e.g. a header prototype and its body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_id: Option<String>,
 }
 
 fn is_false(b: &bool) -> bool { !b }
@@ -313,7 +324,7 @@ pub struct LookupInput {
     #[cfg_attr(feature = "mcp", schemars(description = "Exact symbol or prefix pattern, for example 'parseConfig' or 'parse*'"))]
     pub symbol: String,
     #[serde(default)]
-    #[cfg_attr(feature = "mcp", schemars(description = "Filter by kind: function, method, class, interface, type, enum, constant, variable, test"))]
+    #[cfg_attr(feature = "mcp", schemars(description = "Filter by kind: function, method, class, interface, type, enum, table, constant, variable, test"))]
     pub kind: Option<String>,
     #[serde(default)]
     #[cfg_attr(feature = "mcp", schemars(description = "Filter by file path prefix"))]
@@ -339,7 +350,7 @@ pub struct RefsInput {
     #[serde(default, alias = "path")]
     #[cfg_attr(feature = "mcp", schemars(description = "Filesystem location to resolve against. Defaults to the current directory when index_id is omitted."))]
     pub loc: Option<String>,
-    #[cfg_attr(feature = "mcp", schemars(description = "Symbol to trace references for"))]
+    #[cfg_attr(feature = "mcp", schemars(description = "Symbol to trace references for, or an indexed file path for module-level imports/importers"))]
     pub symbol: String,
     #[cfg_attr(feature = "mcp", schemars(description = "Direction: callers, callees, importers, imports, or type_users"))]
     pub direction: String,
@@ -366,6 +377,9 @@ pub struct RefResult {
     pub chunk_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_chunk_id: Option<String>,
+    /// `precise` when bound by scope rules, `heuristic` when by name matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<crate::EdgeConfidence>,
 }
 
 #[derive(Debug, Serialize)]
@@ -624,6 +638,10 @@ pub fn run_index_work(input: &IndexInput) -> Result<(crate::IndexFile, String, I
             .and_then(|o| o.max_total_bytes)
             .unwrap_or(usize::MAX),
         max_chunks_per_file: 2000,
+        notebook_outputs: input.options.as_ref()
+            .and_then(|o| o.notebook_outputs)
+            .unwrap_or(false),
+        sample_data_rows: input.options.as_ref().and_then(|o| o.sample_data_rows),
     };
 
     let index = {
@@ -743,6 +761,8 @@ pub fn llmx_search_handler(store: &mut IndexStore, input: SearchInput) -> Result
         kind: f.kind.as_ref().and_then(|k| parse_chunk_kind(k)),
         heading_prefix: f.heading_prefix.clone(),
         symbol_prefix: f.symbol_prefix.clone(),
+        log_level: f.log_level.as_ref().and_then(|level| parse_log_level(level)),
+        exclude_generated: f.exclude_generated.unwrap_or(false),
     }).unwrap_or_default();
 
     let limit = input.limit.unwrap_or(10).min(MAX_SEARCH_LIMIT);
@@ -897,6 +917,18 @@ fn parse_chunk_kind(s: &str) -> Option<crate::ChunkKind> {
     }
 }
 
+fn parse_log_level(s: &str) -> Option<crate::LogLevel> {
+    match s {
+        "trace" => Some(crate::LogLevel::Trace),
+        "debug" => Some(crate::LogLevel::Debug),
+        "info" => Some(crate::LogLevel::Info),
+        "warn" | "warning" => Some(crate::LogLevel::Warn),
+        "error" => Some(crate::LogLevel::Error),
+        "fatal" => Some(crate::LogLevel::Fatal),
+        _ => None,
+    }
+}
+
 fn parse_query_intent(value: Option<&str>) -> Result<QueryIntent> {
     Ok(match value {
         None => QueryIntent::Auto,
@@ -985,7 +1017,7 @@ fn semantic_only_search(
     if explain {
         for result in &mut results {
             if result.match_reason.is_none() {
-                result.match_reason = Some(explain_match(&[("dense", result.score)], None, query));
+                result.match_reason = Some(explain_match(&[("dense", result.score)], None, query, &[]));
             }
         }
     }
@@ -1289,6 +1321,7 @@ fn ast_kind_label_for_stats(kind: crate::model::AstNodeKind) -> &'static str {
         crate::model::AstNodeKind::Interface => "interface",
         crate::model::AstNodeKind::Type => "type",
         crate::model::AstNodeKind::Enum => "enum",
+        crate::model::AstNodeKind::Table => "table",
         crate::model::AstNodeKind::Constant => "constant",
         crate::model::AstNodeKind::Variable => "variable",
         crate::model::AstNodeKind::Import => "import",
@@ -1391,6 +1424,7 @@ pub fn llmx_symbols_handler(store: &mut IndexStore, input: SymbolsInput) -> Resu
                 doc_summary: chunk.doc_summary.clone(),
                 exported,
                 chunk_id: chunk.id.clone(),
+                symbol_id: chunk.symbol_id.clone(),
             }
         })
         .collect();
@@ -1455,6 +1489,7 @@ pub fn llmx_lookup_handler(store: &mut IndexStore, input: LookupInput) -> Result
             doc_summary: entry.doc_summary.clone(),
             exported,
             chunk_id: entry.chunk_id.clone(),
+            symbol_id: entry.symbol_id.clone(),
         });
     };
 
@@ -1684,11 +1719,26 @@ fn lookup_symbol_chunk_ids(index: &crate::IndexFile, symbol: &str) -> Vec<String
         }
     }
 
+    // A file path stands for its first chunk, where file-level module imports start.
+    let file_head = index
+        .chunks
+        .iter()
+        .filter(|chunk| chunk.path == symbol)
+        .min_by_key(|chunk| chunk.chunk_index);
+    if let Some(chunk) = file_head {
+        if seen.insert(chunk.id.clone()) {
+            chunk_ids.push(chunk.id.clone());
+        }
+    }
+
     chunk_ids
 }
 
 fn resolve_reverse_keys(index: &crate::IndexFile, symbol: &str) -> Vec<String> {
-    let keys = resolve_symbol_lookup_keys(index, symbol);
+    let mut keys = resolve_symbol_lookup_keys(index, symbol);
+    if index.files.iter().any(|file| file.path == symbol) {
+        keys.push(file_symbol_key(symbol));
+    }
     if keys.is_empty() {
         vec![raw_symbol_key(symbol)]
     } else {
@@ -1755,6 +1805,7 @@ fn build_ref_result(
         context: crate::util::snippet(&context_chunk.content, 200),
         chunk_id: context_chunk.id.clone(),
         target_chunk_id: edge.target_chunk_id.clone(),
+        confidence: edge.confidence,
     })
 }
 
@@ -1805,6 +1856,7 @@ fn parse_ast_kind_filter(s: &str) -> crate::model::AstNodeKind {
         "interface" => AstNodeKind::Interface,
         "type" => AstNodeKind::Type,
         "enum" => AstNodeKind::Enum,
+        "table" => AstNodeKind::Table,
         "constant" => AstNodeKind::Constant,
         "variable" => AstNodeKind::Variable,
         "import" => AstNodeKind::Import,
@@ -1907,6 +1959,7 @@ mod tests {
                 is_generated: false,
                 quality_score: None,
                 resolution_tier: ResolutionTier::GenericTreeSitter,
+                log_level: None,
                 ast_kind: None,
                 qualified_name: None,
                 symbol_id: None,
@@ -1918,6 +1971,7 @@ mod tests {
                 imports: Vec::new(),
                 exports: Vec::new(),
                 calls: Vec::new(),
+                method_calls: Vec::new(),
                 type_refs: Vec::new(),
                 doc_summary: None,
             }],
@@ -1947,6 +2001,7 @@ mod tests {
                         signature: None,
                         doc_summary: None,
                         parent_symbol: None,
+                        symbol_id: None,
                     }],
                 );
                 symbols
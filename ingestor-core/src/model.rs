@@ -51,6 +51,18 @@ pub enum ResolutionTier {
     TextOnly,
 }
 
+/// Severity of a log line, least severe first.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
@@ -66,6 +78,12 @@ pub struct IngestOptions {
     pub max_file_bytes: usize,
     pub max_total_bytes: usize,
     pub max_chunks_per_file: usize,
+    /// Keep the text outputs of notebook code cells as chunks of their own.
+    #[serde(default)]
+    pub notebook_outputs: bool,
+    /// Index only the first this-many records of larger CSV and JSONL files.
+    #[serde(default)]
+    pub sample_data_rows: Option<usize>,
 }
 
 impl Default for IngestOptions {
@@ -76,6 +94,8 @@ impl Default for IngestOptions {
             max_file_bytes: DEFAULT_MAX_FILE_BYTES,
             max_total_bytes: usize::MAX,
             max_chunks_per_file: 2_000,
+            notebook_outputs: false,
+            sample_data_rows: None,
         }
     }
 }
@@ -122,6 +142,9 @@ pub struct Chunk {
     pub quality_score: Option<u16>,
     #[serde(default)]
     pub resolution_tier: ResolutionTier,
+    /// Most severe level among the log lines in this chunk (log files only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<LogLevel>,
 
     // Phase 7: Structural metadata for code intelligence
     /// AST node kind (function, class, method, module, import, type, etc.)
@@ -157,6 +180,10 @@ pub struct Chunk {
     /// Functions/methods called within this chunk
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub calls: Vec<String>,
+    /// Callees in `calls` only ever called on a receiver (`v.len()`); what they bind to
+    /// depends on the receiver's type
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub method_calls: Vec<String>,
     /// Types referenced (struct names, trait bounds, type annotations)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub type_refs: Vec<String>,
@@ -176,6 +203,8 @@ pub enum AstNodeKind {
     Interface,
     Type,
     Enum,
+    /// Tables and views declared in SQL.
+    Table,
     Constant,
     Variable,
     Import,
@@ -276,6 +305,9 @@ pub struct SymbolIndexEntry {
     pub doc_summary: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_symbol: Option<String>,
+    /// Shared by a declaration and its definition (e.g. a C++ header prototype and its body).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol_id: Option<String>,
 }
 
 pub type SymbolTable = BTreeMap<String, Vec<SymbolIndexEntry>>;
@@ -295,6 +327,19 @@ pub struct Edge {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_chunk_id: Option<String>,
     pub edge_kind: EdgeKind,
+    /// How `target_chunk_id` was bound; absent when the target is unresolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<EdgeConfidence>,
+}
+
+/// How an edge target was bound to a definition.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeConfidence {
+    /// Bound by scope rules (imports, module paths, lexical scope): Parser Tier 1.
+    Precise,
+    /// Bound by a unique name match across the index.
+    Heuristic,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -316,6 +361,11 @@ pub struct SearchFilters {
     pub kind: Option<ChunkKind>,
     pub heading_prefix: Option<String>,
     pub symbol_prefix: Option<String>,
+    /// Only log chunks with a line at this level or above.
+    pub log_level: Option<LogLevel>,
+    /// Leave out chunks of generated, vendored and minified files.
+    #[serde(default)]
+    pub exclude_generated: bool,
 }
 
 /// Phase 6: Hybrid search strategy
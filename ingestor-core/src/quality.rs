@@ -0,0 +1,176 @@
+use crate::model::{AstNodeKind, Chunk, ChunkKind, LanguageId};
+
+/// Category weights from the source-quality priors of the v3 spec (§8).
+const SOURCE_PRIOR: f32 = 1.0;
+const TEST_PRIOR: f32 = 0.6;
+const DOCS_PRIOR: f32 = 0.4;
+const GENERATED_PRIOR: f32 = 0.2;
+const LOG_PRIOR: f32 = 0.05;
+
+/// A chunk that names its symbol answers "where is X" directly.
+const SYMBOL_FACTOR: f32 = 1.15;
+/// A documented symbol says what it is for.
+const DOC_FACTOR: f32 = 1.1;
+/// Example and demo code shows how to call an API, not where it lives.
+const EXAMPLE_FACTOR: f32 = 0.7;
+
+/// Directories a file may sit under before its depth counts against it.
+const SHALLOW_DIRS: usize = 3;
+const DEPTH_STEP: f32 = 0.03;
+const DEPTH_FLOOR: f32 = 0.85;
+
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__", "spec", "specs", "testdata", "fixtures", "e2e"];
+const EXAMPLE_DIRS: &[&str] = &["example", "examples", "sample", "samples", "demo", "demos"];
+/// Plain-text formats written as prose.
+const DOC_EXTENSIONS: &[&str] = &["txt", "rst", "adoc", "asciidoc", "org"];
+
+/// The score stored on a chunk when its priors multiply to 1.0.
+const SCORE_SCALE: f32 = 1_000.0;
+
+/// The named priors that make up a chunk's quality score: its category first,
+/// then every modifier that applies.
+pub(crate) fn quality_priors(chunk: &Chunk) -> Vec<(&'static str, f32)> {
+    let mut priors = vec![category(chunk)];
+    if chunk.symbol.is_some() {
+        priors.push(("symbol", SYMBOL_FACTOR));
+    }
+    if chunk.doc_summary.is_some() {
+        priors.push(("doc comment", DOC_FACTOR));
+    }
+    let path = chunk.path.trim_start_matches("./");
+    let depth = path.matches('/').count();
+    if depth > SHALLOW_DIRS {
+        let factor = (1.0 - DEPTH_STEP * (depth - SHALLOW_DIRS) as f32).max(DEPTH_FLOOR);
+        priors.push(("path depth", factor));
+    }
+    if in_dir(path, EXAMPLE_DIRS) {
+        priors.push(("example", EXAMPLE_FACTOR));
+    }
+    priors
+}
+
+/// The product of a chunk's priors, scaled so a plain source chunk scores 1000.
+pub(crate) fn quality_score(chunk: &Chunk) -> u16 {
+    let product: f32 = quality_priors(chunk).iter().map(|(_, factor)| factor).product();
+    (product * SCORE_SCALE).round().min(f32::from(u16::MAX)) as u16
+}
+
+/// The score factor of a chunk's quality: its stored score, or the priors worked
+/// out again for an index built before scores were stored.
+pub(crate) fn quality_factor(chunk: &Chunk) -> f32 {
+    f32::from(chunk.quality_score.unwrap_or_else(|| quality_score(chunk))) / SCORE_SCALE
+}
+
+fn category(chunk: &Chunk) -> (&'static str, f32) {
+    let path = chunk.path.trim_start_matches("./");
+    if matches!(&chunk.language, Some(LanguageId::Other(extension)) if extension == "log") {
+        ("log", LOG_PRIOR)
+    } else if chunk.is_generated {
+        ("generated", GENERATED_PRIOR)
+    } else if chunk.ast_kind == Some(AstNodeKind::Test) || is_test_path(path) {
+        ("test", TEST_PRIOR)
+    } else if chunk.kind == ChunkKind::Markdown
+        || matches!(&chunk.language, Some(LanguageId::Other(extension)) if DOC_EXTENSIONS.contains(&extension.as_str()))
+    {
+        ("docs", DOCS_PRIOR)
+    } else {
+        ("source", SOURCE_PRIOR)
+    }
+}
+
+fn is_test_path(path: &str) -> bool {
+    if in_dir(path, TEST_DIRS) {
+        return true;
+    }
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let stem = name.split('.').next().unwrap_or(name);
+    stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_spec")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+}
+
+fn in_dir(path: &str, dirs: &[&str]) -> bool {
+    path.split('/').rev().skip(1).any(|dir| dirs.contains(&dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ResolutionTier;
+
+    fn chunk(path: &str, kind: ChunkKind, language: Option<LanguageId>) -> Chunk {
+        Chunk {
+            id: String::new(),
+            short_id: String::new(),
+            slug: String::new(),
+            path: path.to_string(),
+            root_path: String::new(),
+            relative_path: path.to_string(),
+            kind,
+            language,
+            chunk_index: 0,
+            start_line: 1,
+            end_line: 1,
+            content: String::new(),
+            content_hash: String::new(),
+            token_estimate: 0,
+            heading_path: Vec::new(),
+            symbol: None,
+            address: None,
+            asset_path: None,
+            is_generated: false,
+            quality_score: None,
+            resolution_tier: ResolutionTier::TextOnly,
+            log_level: None,
+            ast_kind: None,
+            qualified_name: None,
+            symbol_id: None,
+            symbol_tail: None,
+            signature: None,
+            module_path: None,
+            parent_symbol: None,
+            visibility: None,
+            imports: Vec::new(),
+            exports: Vec::new(),
+            calls: Vec::new(),
+            method_calls: Vec::new(),
+            type_refs: Vec::new(),
+            doc_summary: None,
+        }
+    }
+
+    #[test]
+    fn categories_follow_the_spec_priors() {
+        let rust = || Some(LanguageId::Rust);
+        let source = chunk("src/lib.rs", ChunkKind::Unknown, rust());
+        let test = chunk("tests/search_tests.rs", ChunkKind::Unknown, rust());
+        let docs = chunk("README.md", ChunkKind::Markdown, Some(LanguageId::Markdown));
+        let generated = Chunk {
+            is_generated: true,
+            ..chunk("src/schema.rs", ChunkKind::Unknown, rust())
+        };
+        let log = chunk("app.log", ChunkKind::Text, Some(LanguageId::Other("log".to_string())));
+        let scores: Vec<u16> = [&source, &test, &docs, &generated, &log].into_iter().map(quality_score).collect();
+        assert_eq!(scores, [1000, 600, 400, 200, 50]);
+        assert_eq!(quality_priors(&chunk("web/app.spec.ts", ChunkKind::JavaScript, None))[0].0, "test");
+    }
+
+    #[test]
+    fn modifiers_reward_symbols_and_penalize_examples_and_depth() {
+        let documented = Chunk {
+            symbol: Some("search".to_string()),
+            doc_summary: Some("Search the index.".to_string()),
+            ..chunk("src/lib.rs", ChunkKind::Unknown, Some(LanguageId::Rust))
+        };
+        assert_eq!(quality_score(&documented), 1265);
+
+        let example = chunk("examples/basic/main.rs", ChunkKind::Unknown, Some(LanguageId::Rust));
+        assert_eq!(quality_priors(&example), [("source", 1.0), ("example", EXAMPLE_FACTOR)]);
+        assert_eq!(quality_score(&example), 700);
+
+        let deep = chunk("a/b/c/d/e/f/g/h/lib.rs", ChunkKind::Unknown, Some(LanguageId::Rust));
+        assert_eq!(quality_score(&deep), 850);
+    }
+}
@@ -371,11 +371,13 @@ fn snake_to_pascal(s: &str) -> String {
 
 // ── Match Explanation ───────────────────────────────────────────────────────
 
-/// Generate a human-readable explanation of why a result matched.
+/// Generate a human-readable explanation of why a result matched, listing the
+/// source-quality priors its score was weighted by.
 pub fn explain_match(
     engines: &[(&str, f32)], // (engine_name, score_from_that_engine)
     symbol: Option<&str>,
     query: &str,
+    priors: &[(&str, f32)], // (prior_name, score_factor)
 ) -> String {
     if engines.is_empty() {
         return String::new();
@@ -416,6 +418,15 @@ pub fn explain_match(
         reason.push_str(&format!(" (also matched: {})", also));
     }
 
+    if !priors.is_empty() {
+        let factors = priors
+            .iter()
+            .map(|(name, factor)| format!("{} ×{:.2}", name, factor))
+            .collect::<Vec<String>>()
+            .join(", ");
+        reason.push_str(&format!(" [priors: {}]", factors));
+    }
+
     reason
 }
 
@@ -490,6 +501,18 @@ mod tests {
         assert_eq!(snake_to_camel("html_parser"), "htmlParser");
     }
 
+    #[test]
+    fn test_explain_match_lists_priors() {
+        let reason = explain_match(
+            &[("symbol", 0.03), ("bm25", 0.01)],
+            Some("search"),
+            "search",
+            &[("test", 0.6), ("symbol", 1.15)],
+        );
+        assert_eq!(reason, "Symbol match: search (also matched: bm25) [priors: test ×0.60, symbol ×1.15]");
+        assert_eq!(explain_match(&[("dense", 0.5)], None, "q", &[]), "Semantic similarity: 0.50");
+    }
+
     #[test]
     fn test_weights_for_intent() {
         let w = weights_for_intent(QueryIntent::Symbol);
@@ -0,0 +1,585 @@
+//! Scope-rule name resolution (Parser Tier 1, [`crate::ResolutionTier::StackGraph`]).
+//!
+//! Runs over the full chunk set once chunking is done. For each supported language
+//! a chunk's imports, calls and type references are bound the way the compiler
+//! would bind them: lexical scope first, then the file's imports, then module
+//! paths. Whatever a resolver cannot bind falls back to the name matching in
+//! [`crate::graph`], whose edges are marked heuristic rather than precise. A chunk
+//! moves up to the StackGraph tier once one of its references binds here.
+
+mod python;
+mod rust;
+mod sfc;
+mod shell;
+mod typescript;
+
+use crate::model::{Chunk, LanguageId};
+use std::collections::BTreeMap;
+
+pub(crate) struct ScopeResolver<'a> {
+    components: sfc::ComponentScopes<'a>,
+    python: python::PythonScopes<'a>,
+    rust: rust::RustScopes<'a>,
+    shell: shell::ShellScopes<'a>,
+    typescript: typescript::TypeScriptScopes<'a>,
+}
+
+impl<'a> ScopeResolver<'a> {
+    pub(crate) fn build(chunks: &'a [Chunk]) -> Self {
+        Self {
+            components: sfc::ComponentScopes::build(chunks),
+            python: python::PythonScopes::build(chunks),
+            rust: rust::RustScopes::build(chunks),
+            shell: shell::ShellScopes::build(chunks),
+            typescript: typescript::TypeScriptScopes::build(chunks),
+        }
+    }
+
+    /// Bind `target`, as referenced from `chunk`, to the chunk that defines it.
+    pub(crate) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<Binding<'a>> {
+        // Component files are imported the same way from scripts and other components.
+        if let Some(binding) = self.components.resolve(chunk, target) {
+            return Some(binding);
+        }
+        match chunk.language {
+            Some(LanguageId::Python) => self.python.resolve(chunk, target),
+            // A bare name called on a receiver (`v.len()`) is a method of whatever type
+            // the receiver has, which scope rules do not track.
+            Some(LanguageId::Rust | LanguageId::TypeScript | LanguageId::JavaScript)
+                if chunk.method_calls.iter().any(|call| call == target) =>
+            {
+                None
+            }
+            Some(LanguageId::Rust) => self.rust.resolve(chunk, target),
+            Some(LanguageId::Shell) => self.shell.resolve(chunk, target),
+            Some(LanguageId::TypeScript | LanguageId::JavaScript) => {
+                self.typescript.resolve(chunk, target).map(Binding::item)
+            }
+            _ => None,
+        }
+    }
+
+    /// File-level dependencies between indexed JS/TS and Python modules, and the
+    /// files shell scripts source.
+    pub(crate) fn module_imports(&self) -> Vec<ModuleImport<'a>> {
+        let mut imports = self.typescript.module_imports();
+        imports.extend(self.python.module_imports());
+        imports.extend(self.shell.module_imports());
+        imports
+    }
+}
+
+/// A file-level dependency: the file of `source` imports the file of `target`
+/// through `specifier`. Both chunks are the first chunk of their file.
+pub(crate) struct ModuleImport<'a> {
+    pub(crate) source: &'a Chunk,
+    pub(crate) specifier: &'a str,
+    pub(crate) target: &'a Chunk,
+}
+
+/// A resolved reference: the chunk it lands on and the name the edge is indexed under.
+pub(crate) struct Binding<'a> {
+    pub(crate) chunk: &'a Chunk,
+    pub(crate) name: &'a str,
+}
+
+impl<'a> Binding<'a> {
+    fn item(chunk: &'a Chunk) -> Self {
+        let name = chunk
+            .qualified_name
+            .as_deref()
+            .or(chunk.symbol.as_deref())
+            .unwrap_or(&chunk.short_id);
+        Self { chunk, name }
+    }
+}
+
+/// Chunks of `language`, the scopes its resolver binds references in.
+fn scoped_chunks(chunks: &[Chunk], language: LanguageId) -> impl Iterator<Item = &Chunk> {
+    chunks.iter().filter(move |chunk| chunk.language.as_ref() == Some(&language))
+}
+
+/// Chunks of the files named `file_name` (`package.json`, `Cargo.toml`), grouped by
+/// path and in chunk order.
+fn manifests<'a>(chunks: &'a [Chunk], file_name: &str) -> BTreeMap<&'a str, Vec<&'a Chunk>> {
+    let mut manifests: BTreeMap<&str, Vec<&Chunk>> = BTreeMap::new();
+    for chunk in chunks {
+        if chunk.path.rsplit('/').next() == Some(file_name) {
+            manifests.entry(chunk.path.as_str()).or_default().push(chunk);
+        }
+    }
+    for parts in manifests.values_mut() {
+        parts.sort_by_key(|chunk| chunk.chunk_index);
+    }
+    manifests
+}
+
+/// Reassemble a JSON manifest from its chunks: one chunk per top-level key
+/// (`$.key`, split ones as `$.key#n`), or plain text when the file did not parse
+/// as strict JSON, e.g. a `tsconfig.json` with comments.
+fn json_manifest(parts: &[&Chunk]) -> Option<serde_json::Value> {
+    let keyed = parts
+        .iter()
+        .all(|chunk| chunk.address.as_deref().is_some_and(|address| address.starts_with("$.")));
+    if !keyed {
+        let text = parts.iter().map(|chunk| chunk.content.as_str()).collect::<Vec<_>>().join("\n");
+        return serde_json::from_str(&strip_json_comments(&text)).ok();
+    }
+    let mut values: Vec<(&str, String)> = Vec::new();
+    for chunk in parts {
+        let key = chunk.symbol.as_deref()?;
+        match values.last_mut() {
+            Some((last, content)) if *last == key && chunk.address.as_deref().is_some_and(|a| a.contains('#')) => {
+                content.push_str(&chunk.content)
+            }
+            _ => values.push((key, chunk.content.clone())),
+        }
+    }
+    let object = values
+        .into_iter()
+        .filter_map(|(key, content)| Some((key.to_string(), serde_json::from_str(&content).ok()?)))
+        .collect();
+    Some(serde_json::Value::Object(object))
+}
+
+/// Drop `//` and `/* */` comments and trailing commas, as `tsconfig.json` allows.
+fn strip_json_comments(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            match ch {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(ch);
+            }
+            ('/', Some('/')) => {
+                while chars.next_if(|next| *next != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            (']' | '}', _) => {
+                let trimmed = out.trim_end().len();
+                if out[..trimmed].ends_with(',') {
+                    out.truncate(trimmed - 1);
+                }
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// The single distinct chunk among `candidates`; `None` when empty or ambiguous.
+fn unique<'a>(candidates: impl IntoIterator<Item = &'a Chunk>) -> Option<&'a Chunk> {
+    let mut candidates = candidates.into_iter();
+    let first = candidates.next()?;
+    if candidates.any(|candidate| candidate.id != first.id) {
+        None
+    } else {
+        Some(first)
+    }
+}
+
+/// Directory part of a `/`-separated path, without the trailing slash.
+fn parent_dir(path: &str) -> &str {
+    path.rfind('/').map_or("", |idx| &path[..idx])
+}
+
+/// Join `relative` onto `dir`, folding `.` and `..` segments.
+fn join_path(dir: &str, relative: &str) -> String {
+    let mut segments: Vec<&str> = dir.split('/').collect();
+    if segments == [""] {
+        segments.clear();
+    }
+    for segment in relative.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                if segments.last().is_some_and(|last| !last.is_empty() && *last != "..") {
+                    segments.pop();
+                } else {
+                    segments.push("..");
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+#[cfg(all(test, feature = "treesitter"))]
+mod tests {
+    use crate::model::{EdgeConfidence, EdgeKind, FileInput, IngestOptions};
+    use crate::IndexFile;
+
+    fn inputs(files: &[(&str, &str)]) -> Vec<FileInput> {
+        files
+            .iter()
+            .map(|(path, text)| FileInput {
+                path: path.to_string(),
+                data: text.as_bytes().to_vec(),
+                mtime_ms: None,
+                fingerprint_sha256: None,
+            })
+            .collect()
+    }
+
+    fn ingest(files: &[(&str, &str)]) -> IndexFile {
+        crate::ingest_files(inputs(files), IngestOptions::default())
+    }
+
+    fn edge_target<'a>(index: &'a IndexFile, source: &str, target: &str) -> (&'a str, Option<EdgeConfidence>) {
+        let source = index
+            .chunks
+            .iter()
+            .find(|chunk| chunk.qualified_name.as_deref() == Some(source))
+            .unwrap_or_else(|| panic!("missing source chunk {source}"));
+        let edge = index.edges.forward[&source.id]
+            .iter()
+            .find(|edge| edge.target_symbol == target)
+            .unwrap_or_else(|| panic!("missing edge to {target}"));
+        let target_chunk = edge
+            .target_chunk_id
+            .as_deref()
+            .and_then(|id| index.chunks.iter().find(|chunk| chunk.id == id))
+            .expect("edge should be bound");
+        (target_chunk.path.as_str(), edge.confidence)
+    }
+
+    #[test]
+    fn rust_uses_bind_ambiguous_names_to_the_imported_module() {
+        let index = ingest(&[
+            ("src/lib.rs", "mod auth;\nmod billing;\nmod app;\n"),
+            ("src/auth.rs", "pub struct Claims;\n\npub fn verify(claims: &Claims) -> bool { true }\n"),
+            ("src/billing.rs", "pub struct Claims;\n\npub fn verify(claims: &Claims) -> bool { false }\n"),
+            (
+                "src/app.rs",
+                "use crate::auth::{verify, Claims};\n\npub fn run(claims: Claims) -> bool {\n    verify(&claims)\n}\n",
+            ),
+        ]);
+
+        assert_eq!(edge_target(&index, "run", "verify"), ("src/auth.rs", Some(EdgeConfidence::Precise)));
+        assert_eq!(edge_target(&index, "run", "Claims"), ("src/auth.rs", Some(EdgeConfidence::Precise)));
+        assert_eq!(
+            edge_target(&index, "run", "crate::auth::verify"),
+            ("src/auth.rs", Some(EdgeConfidence::Precise))
+        );
+        let tiers: Vec<_> = index.files.iter().map(|file| file.resolution_tier).collect();
+        assert!(tiers.iter().all(|tier| *tier == crate::ResolutionTier::StackGraph), "{tiers:?}");
+    }
+
+    #[test]
+    fn method_calls_are_left_to_name_matching() {
+        let index = ingest(&[
+            (
+                "src/lib.rs",
+                "pub fn len(bytes: &[u8]) -> usize {\n    0\n}\n\npub fn go(v: Vec<u8>) -> usize {\n    v.len() + v.get::<usize>(0)\n}\n\npub fn count(v: Vec<u8>) -> usize {\n    len(&v) + v.len()\n}\n",
+            ),
+            (
+                "web/list.ts",
+                "function push(item: number): void {}\n\nexport function add(items?: number[]): void {\n  items?.push(1);\n}\n",
+            ),
+        ]);
+
+        assert_eq!(edge_target(&index, "go", "len"), ("src/lib.rs", Some(EdgeConfidence::Heuristic)));
+        assert_eq!(edge_target(&index, "count", "len"), ("src/lib.rs", Some(EdgeConfidence::Precise)));
+        assert_eq!(edge_target(&index, "add", "push"), ("web/list.ts", Some(EdgeConfidence::Heuristic)));
+        let method_calls = |name: &str| {
+            let chunk = index.chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some(name)).expect("chunk");
+            chunk.method_calls.clone()
+        };
+        assert_eq!(method_calls("go"), ["len", "get"]);
+        assert!(method_calls("count").is_empty());
+    }
+
+    #[test]
+    fn rust_module_paths_bind_to_module_files_and_workspace_crates() {
+        let index = ingest(&[
+            ("core/Cargo.toml", "[package]\nname = \"app-core\"\nversion = \"0.1.0\"\n"),
+            ("core/src/lib.rs", "pub mod auth;\npub mod billing;\n"),
+            (
+                "core/src/auth/mod.rs",
+                "mod session;\n\npub fn verify(token: &str) -> bool {\n    session::active(token)\n}\n",
+            ),
+            (
+                "core/src/auth/session.rs",
+                "use super::verify;\n\npub fn active(token: &str) -> bool { !token.is_empty() }\n\npub fn refresh(token: &str) -> bool {\n    verify(token)\n}\n",
+            ),
+            (
+                "core/src/billing.rs",
+                "use crate::auth;\n\npub fn charge(token: &str) -> bool {\n    auth::verify(token)\n}\n",
+            ),
+            (
+                "core/src/bin/server.rs",
+                "use app_core::auth::verify;\n\nfn serve(token: &str) -> bool {\n    verify(token)\n}\n",
+            ),
+        ]);
+
+        assert_eq!(
+            edge_target(&index, "auth", "self::auth"),
+            ("core/src/auth/mod.rs", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "session", "self::session"),
+            ("core/src/auth/session.rs", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "charge", "crate::auth"),
+            ("core/src/auth/mod.rs", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "refresh", "super::verify"),
+            ("core/src/auth/mod.rs", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "verify", "session::active"),
+            ("core/src/auth/session.rs", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "serve", "app_core::auth::verify"),
+            ("core/src/auth/mod.rs", Some(EdgeConfidence::Precise))
+        );
+
+        // Importers of the module are found through its declaration name.
+        let billing = index.chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("charge")).expect("charge");
+        assert!(index.edges.reverse["auth"]
+            .iter()
+            .any(|edge| edge.source_chunk_id == billing.id && edge.edge_kind == EdgeKind::Imports));
+    }
+
+    #[test]
+    fn python_imports_bind_same_named_helpers_per_package_root() {
+        let index = ingest(&[
+            ("services/billing/pyproject.toml", "[tool.setuptools.packages.find]\nwhere = [\"src\"]\n"),
+            ("services/billing/src/billing/__init__.py", "\"\"\"Billing service.\"\"\"\n"),
+            ("services/billing/src/billing/helpers.py", "def normalize(value):\n    return value.strip()\n"),
+            ("services/billing/src/billing/invoices/__init__.py", "\"\"\"Invoices.\"\"\"\n"),
+            (
+                "services/billing/src/billing/invoices/render.py",
+                "from ..helpers import normalize\nfrom billing import helpers\n\ndef render(value):\n    return normalize(value)\n\ndef render_all(values):\n    return [helpers.normalize(v) for v in values]\n",
+            ),
+            ("services/accounts/app/__init__.py", "\"\"\"Accounts service.\"\"\"\n"),
+            ("services/accounts/app/helpers.py", "def normalize(value):\n    return value.lower()\n"),
+            (
+                "services/accounts/app/views.py",
+                "import app.helpers\n\ndef show(value):\n    return app.helpers.normalize(value)\n",
+            ),
+        ]);
+
+        let billing = ("services/billing/src/billing/helpers.py", Some(EdgeConfidence::Precise));
+        let accounts = ("services/accounts/app/helpers.py", Some(EdgeConfidence::Precise));
+        assert_eq!(edge_target(&index, "render", "normalize"), billing);
+        assert_eq!(edge_target(&index, "render", "..helpers.normalize"), billing);
+        assert_eq!(edge_target(&index, "render_all", "helpers.normalize"), billing);
+        assert_eq!(edge_target(&index, "render_all", "billing.helpers"), billing);
+        assert_eq!(edge_target(&index, "show", "app.helpers.normalize"), accounts);
+        assert_eq!(edge_target(&index, "show", "app.helpers"), accounts);
+
+        let importers: Vec<&str> = index.edges.reverse[&crate::graph::file_symbol_key(billing.0)]
+            .iter()
+            .map(|edge| index.chunks.iter().find(|chunk| chunk.id == edge.source_chunk_id).expect("source"))
+            .map(|chunk| chunk.path.as_str())
+            .collect();
+        assert_eq!(importers, vec!["services/billing/src/billing/invoices/render.py"]);
+    }
+
+    #[test]
+    fn typescript_imports_bind_through_relative_specifiers() {
+        let index = ingest(&[
+            ("web/auth/index.ts", "export function verify(token: string): boolean { return true; }\n"),
+            ("web/billing.ts", "export function verify(token: string): boolean { return false; }\n"),
+            ("web/tokens.ts", "export interface Claims { sub: string }\n"),
+            (
+                "web/app/main.ts",
+                "import { verify } from \"../auth\";\nimport type { Claims } from \"../tokens\";\n\nexport function run(claims: Claims): boolean {\n  return verify(claims.sub);\n}\n",
+            ),
+        ]);
+
+        assert_eq!(
+            edge_target(&index, "run", "verify"),
+            ("web/auth/index.ts", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "run", "../auth#verify"),
+            ("web/auth/index.ts", Some(EdgeConfidence::Precise))
+        );
+        assert_eq!(
+            edge_target(&index, "run", "../tokens#Claims"),
+            ("web/tokens.ts", Some(EdgeConfidence::Precise))
+        );
+        let run = index.chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("run")).expect("run");
+        let type_edge = index.edges.forward[&run.id]
+            .iter()
+            .find(|edge| edge.edge_kind == EdgeKind::TypeRef)
+            .expect("type edge");
+        assert_eq!(type_edge.target_symbol, "../tokens#Claims");
+    }
+
+    #[test]
+    fn js_and_ts_specifiers_resolve_through_tsconfig_paths_and_workspace_packages() {
+        let index = ingest(&[
+            (
+                "tsconfig.json",
+                "{\n  // shared aliases\n  \"compilerOptions\": {\n    \"baseUrl\": \".\",\n    \"paths\": { \"@app/*\": [\"apps/web/src/*\"] },\n  }\n}\n",
+            ),
+            (
+                "packages/auth/package.json",
+                r#"{"name": "@acme/auth", "exports": {".": {"types": "./src/index.ts", "import": "./dist/index.js"}, "./session": "./src/session.ts"}}"#,
+            ),
+            ("packages/auth/src/index.ts", "export { verify } from \"./verify\";\nexport * from \"./session\";\n"),
+            ("packages/auth/src/verify.ts", "export function verify(token: string): boolean { return token.length > 0; }\n"),
+            (
+                "packages/auth/src/session.ts",
+                "export interface Session { id: string }\n\nexport function openSession(id: string): Session { return { id }; }\n",
+            ),
+            ("apps/web/src/util/format.ts", "export function format(value: string): string { return value; }\n"),
+            (
+                "apps/web/src/main.ts",
+                "import { verify } from \"@acme/auth\";\nimport { openSession } from \"@acme/auth/session\";\nimport { format } from \"@app/util/format\";\n\nexport function run(token: string): string {\n  verify(token);\n  openSession(token);\n  return format(token);\n}\n",
+            ),
+            (
+                "apps/web/src/legacy.js",
+                "import { verify } from \"@acme/auth\";\n\nexport function check(token) {\n  return verify(token);\n}\n",
+            ),
+        ]);
+
+        let precise = |path| (path, Some(EdgeConfidence::Precise));
+        assert_eq!(edge_target(&index, "run", "@acme/auth#verify"), precise("packages/auth/src/verify.ts"));
+        assert_eq!(
+            edge_target(&index, "run", "@acme/auth/session#openSession"),
+            precise("packages/auth/src/session.ts")
+        );
+        assert_eq!(edge_target(&index, "run", "@app/util/format#format"), precise("apps/web/src/util/format.ts"));
+        assert_eq!(edge_target(&index, "check", "@acme/auth#verify"), precise("packages/auth/src/verify.ts"));
+
+        let importers = |path: &str| {
+            let mut sources: Vec<&str> = index.edges.reverse[&crate::graph::file_symbol_key(path)]
+                .iter()
+                .map(|edge| index.chunks.iter().find(|chunk| chunk.id == edge.source_chunk_id).expect("source"))
+                .map(|chunk| chunk.path.as_str())
+                .collect();
+            sources.sort();
+            sources
+        };
+        assert_eq!(importers("packages/auth/src/index.ts"), vec!["apps/web/src/legacy.js", "apps/web/src/main.ts"]);
+        assert_eq!(importers("packages/auth/src/verify.ts"), vec!["packages/auth/src/index.ts"]);
+        assert_eq!(
+            importers("packages/auth/src/session.ts"),
+            vec!["apps/web/src/main.ts", "packages/auth/src/index.ts"]
+        );
+    }
+
+    #[test]
+    fn shell_calls_bind_in_file_and_sourced_paths_bind_to_scripts() {
+        let index = ingest(&[
+            ("scripts/lib/common.sh", "log() {\n  echo \"[$(date)] $*\"\n}\n"),
+            (
+                "scripts/deploy.sh",
+                "#!/usr/bin/env bash\nsource \"$(dirname \"$0\")/lib/common.sh\"\n\nusage() {\n  echo \"usage: deploy <env>\"\n}\n\nmain() {\n  [ $# -gt 0 ] || usage\n  log deploying\n}\n\nmain \"$@\"\n",
+            ),
+            (
+                "ci/build.sh",
+                "#!/bin/sh\n. scripts/lib/common.sh\n\nusage() {\n  echo \"usage: build\"\n}\n\nbuild() {\n  usage\n}\n",
+            ),
+        ]);
+
+        let precise = |path| (path, Some(EdgeConfidence::Precise));
+        assert_eq!(edge_target(&index, "main", "usage"), precise("scripts/deploy.sh"));
+        assert_eq!(edge_target(&index, "build", "usage"), precise("ci/build.sh"));
+
+        let mut importers: Vec<&str> = index.edges.reverse[&crate::graph::file_symbol_key("scripts/lib/common.sh")]
+            .iter()
+            .map(|edge| index.chunks.iter().find(|chunk| chunk.id == edge.source_chunk_id).expect("source"))
+            .map(|chunk| chunk.path.as_str())
+            .collect();
+        importers.sort();
+        assert_eq!(importers, vec!["ci/build.sh", "scripts/deploy.sh"]);
+    }
+    #[test]
+    fn component_imports_bind_to_the_imported_component_file() {
+        let index = ingest(&[
+            ("src/components/BaseCard.vue", "<template>\n  <div class=\"card\"><slot /></div>\n</template>\n"),
+            ("src/legacy/BaseCard.ts", "export class BaseCard {}\n"),
+            (
+                "src/pages/Home.vue",
+                "<template>\n  <BaseCard>Home</BaseCard>\n</template>\n\n<script setup>\nimport BaseCard from \"../components/BaseCard.vue\";\n</script>\n",
+            ),
+            (
+                "src/pages/About.vue",
+                "<template>\n  <base-card>About</base-card>\n</template>\n\n<script>\nimport BaseCard from \"../components/BaseCard.vue\";\n\nexport default { components: { BaseCard } };\n</script>\n",
+            ),
+            (
+                "src/pages/Contact.vue",
+                "<template>\n  <BaseCard>Contact</BaseCard>\n</template>\n\n<script lang=\"ts\">\nimport BaseCard from \"../components/BaseCard.vue\";\n\nexport default { components: { BaseCard } };\n</script>\n",
+            ),
+        ]);
+        let section = |path: &str, address: &str| {
+            index
+                .chunks
+                .iter()
+                .find(|chunk| chunk.path == path && chunk.address.as_deref() == Some(address))
+                .unwrap_or_else(|| panic!("missing {address} of {path}"))
+        };
+        let card = section("src/components/BaseCard.vue", "template");
+        let import = "../components/BaseCard.vue#default";
+
+        for source in [
+            section("src/pages/Home.vue", "template"),
+            section("src/pages/About.vue", "template"),
+            section("src/pages/About.vue", "script"),
+            section("src/pages/Contact.vue", "script"),
+        ] {
+            assert_eq!(source.imports, [import], "{}", source.path);
+            let edge = index.edges.forward[&source.id]
+                .iter()
+                .find(|edge| edge.edge_kind == EdgeKind::Imports)
+                .expect("component import edge");
+            assert_eq!(edge.target_chunk_id.as_deref(), Some(card.id.as_str()));
+            assert_eq!(edge.confidence, Some(EdgeConfidence::Precise));
+        }
+        let reference = index.edges.forward[&section("src/pages/Home.vue", "template").id]
+            .iter()
+            .find(|edge| edge.edge_kind == EdgeKind::TypeRef)
+            .expect("template component reference");
+        assert_eq!(reference.target_chunk_id.as_deref(), Some(card.id.as_str()));
+    }
+
+    #[test]
+    fn updates_lower_reused_files_whose_bindings_are_gone() {
+        let files = [
+            ("src/lib.rs", "mod auth;\nmod app;\n"),
+            ("src/auth.rs", "pub fn verify() -> bool {\n    true\n}\n"),
+            ("src/app.rs", "use crate::auth::verify;\n\npub fn run() -> bool {\n    verify()\n}\n"),
+        ];
+        let tier = |index: &IndexFile, path: &str| {
+            index.files.iter().find(|file| file.path == path).map(|file| file.resolution_tier)
+        };
+        let index = ingest(&files);
+        assert_eq!(tier(&index, "src/app.rs"), Some(crate::ResolutionTier::StackGraph));
+
+        // `src/app.rs` is unchanged and reused, but the file it bound to is deleted.
+        let updated = crate::update_index(index, inputs(&[files[0], files[2]]), IngestOptions::default());
+        assert_eq!(tier(&updated, "src/app.rs"), Some(crate::ResolutionTier::QueryPack));
+        let run = updated.chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("run")).expect("run");
+        assert_eq!(run.resolution_tier, crate::ResolutionTier::QueryPack);
+    }
+}
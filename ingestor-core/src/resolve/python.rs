@@ -0,0 +1,257 @@
+//! Python scope rules: definitions of the current file, then the names its imports
+//! bind, resolved to the module file and the definition inside it.
+//!
+//! Absolute imports search the import roots above the importing file, deepest
+//! first: the top of its `__init__.py` package chain, `src/` layout directories,
+//! and the package directories declared in `pyproject.toml`. Relative imports
+//! (`from ..x import y`) walk up from the importing file's own package.
+
+use super::{parent_dir, unique, Binding, ModuleImport};
+use crate::model::{Chunk, LanguageId};
+use std::collections::{BTreeSet, HashMap};
+
+pub(super) struct PythonScopes<'a> {
+    /// File path → definitions in it.
+    files: HashMap<&'a str, Vec<&'a Chunk>>,
+    /// Every indexed `.py` file → its first chunk, including files without definitions.
+    modules: HashMap<&'a str, &'a Chunk>,
+    /// Directories absolute imports are resolved against.
+    roots: BTreeSet<String>,
+}
+
+impl<'a> PythonScopes<'a> {
+    pub(super) fn build(chunks: &'a [Chunk]) -> Self {
+        let mut files: HashMap<_, Vec<&'a Chunk>> = HashMap::new();
+        for chunk in super::scoped_chunks(chunks, LanguageId::Python) {
+            files.entry(chunk.path.as_str()).or_default().push(chunk);
+        }
+
+        let mut modules: HashMap<&'a str, &'a Chunk> = HashMap::new();
+        for chunk in chunks.iter().filter(|chunk| chunk.path.ends_with(".py")) {
+            let head = modules.entry(chunk.path.as_str()).or_insert(chunk);
+            if chunk.chunk_index < head.chunk_index {
+                *head = chunk;
+            }
+        }
+
+        let mut roots = BTreeSet::new();
+        for path in modules.keys() {
+            // `src/` layouts put the importable packages one level down.
+            if let Some(idx) = path.rfind("src/").filter(|idx| *idx == 0 || path[..*idx].ends_with('/')) {
+                roots.insert(path[..idx + "src".len()].to_string());
+            }
+            // The directory above the outermost `__init__.py` package.
+            if path.rsplit('/').next() == Some("__init__.py") {
+                let mut package = parent_dir(path);
+                while !package.is_empty() && modules.contains_key(format!("{}/__init__.py", parent_dir(package)).as_str()) {
+                    package = parent_dir(package);
+                }
+                roots.insert(parent_dir(package).to_string());
+            }
+        }
+        for (path, parts) in super::manifests(chunks, "pyproject.toml") {
+            let dir = parent_dir(path);
+            roots.insert(dir.to_string());
+            for declared in pyproject_roots(&parts) {
+                roots.insert(super::join_path(dir, &declared));
+            }
+        }
+
+        Self { files, modules, roots }
+    }
+
+    pub(super) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<Binding<'a>> {
+        // `from .tokens import Claims` records the import path itself.
+        if chunk.imports.iter().any(|import| import == target) {
+            return self.import_path(&chunk.path, target);
+        }
+        if let Some(local) = self.declared_in(&chunk.path, target) {
+            return Some(Binding::item(local));
+        }
+
+        // `Claims`, `tokens.sign` or `app.tokens.sign`, where the first segment came in through an import.
+        let (first, rest) = match target.split_once('.') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (target, None),
+        };
+        let mut imported = self.file_imports(&chunk.path).into_iter().filter_map(|import| {
+            let (head, tail) = (import.split('.').find(|segment| !segment.is_empty())?, import.rsplit('.').next()?);
+            if tail == first {
+                // `from app import tokens` binds `tokens`.
+                let path = match rest {
+                    Some(rest) => format!("{import}.{rest}"),
+                    None => import.to_string(),
+                };
+                self.import_path(&chunk.path, &path)
+            } else if head == first && !import.starts_with('.') {
+                // `import app.tokens` binds `app`.
+                self.import_path(&chunk.path, target)
+            } else {
+                None
+            }
+        });
+        let first = imported.next()?;
+        imported.all(|other| other.chunk.id == first.chunk.id).then_some(first)
+    }
+
+    /// One edge per distinct module a file imports, from either import form.
+    pub(super) fn module_imports(&self) -> Vec<ModuleImport<'a>> {
+        let mut imports = Vec::new();
+        for path in self.files.keys() {
+            let Some(source) = self.modules.get(path) else {
+                continue;
+            };
+            let mut targets: Vec<&str> = Vec::new();
+            for import in self.file_imports(path) {
+                let Some((target, _)) = self.split_module(path, import) else {
+                    continue;
+                };
+                if target == *path || targets.contains(&target) {
+                    continue;
+                }
+                targets.push(target);
+                imports.push(ModuleImport {
+                    source,
+                    specifier: import,
+                    target: self.modules[target],
+                });
+            }
+        }
+        imports
+    }
+
+    /// Import paths recorded anywhere in the file.
+    fn file_imports(&self, path: &str) -> Vec<&'a str> {
+        let mut imports = Vec::new();
+        for chunk in self.files.get(path).into_iter().flatten() {
+            for import in &chunk.imports {
+                if !imports.contains(&import.as_str()) {
+                    imports.push(import.as_str());
+                }
+            }
+        }
+        imports
+    }
+
+    fn declared_in(&self, path: &str, qualified_name: &str) -> Option<&'a Chunk> {
+        unique(
+            self.files
+                .get(path)?
+                .iter()
+                .copied()
+                .filter(|chunk| chunk.qualified_name.as_deref() == Some(qualified_name)),
+        )
+    }
+
+    /// Bind a dotted import path to its module file, or to the definition the segments
+    /// after the module name inside it.
+    fn import_path(&self, from: &str, dotted: &str) -> Option<Binding<'a>> {
+        let (file, item) = self.split_module(from, dotted)?;
+        if item.is_empty() {
+            return Some(Binding {
+                chunk: self.modules[file],
+                name: module_name(file),
+            });
+        }
+        self.declared_in(file, &item.join(".")).map(Binding::item)
+    }
+
+    /// The longest prefix of `dotted` that is an indexed module, and the segments after it.
+    fn split_module<'p>(&self, from: &str, dotted: &'p str) -> Option<(&'a str, Vec<&'p str>)> {
+        let level = dotted.len() - dotted.trim_start_matches('.').len();
+        let segments: Vec<&str> = dotted[level..].split('.').filter(|segment| !segment.is_empty()).collect();
+        (1..=segments.len()).rev().find_map(|split| {
+            let (module, item) = segments.split_at(split);
+            Some((self.module_file(from, level, module)?, item.to_vec()))
+        })
+    }
+
+    /// The file for `module` imported from `from`: relative to its package for
+    /// `level` leading dots, else below the first import root that has it.
+    fn module_file(&self, from: &str, level: usize, module: &[&str]) -> Option<&'a str> {
+        if level > 0 {
+            let mut dir = parent_dir(from);
+            for _ in 1..level {
+                if dir.is_empty() {
+                    return None;
+                }
+                dir = parent_dir(dir);
+            }
+            return self.file_in(dir, module);
+        }
+
+        let (mut above, others): (Vec<&str>, Vec<&str>) = self
+            .roots
+            .iter()
+            .map(String::as_str)
+            .partition(|root| root.is_empty() || from.strip_prefix(root).is_some_and(|rest| rest.starts_with('/')));
+        above.sort_by_key(|root| std::cmp::Reverse(root.len()));
+        // A script's own directory is on `sys.path` too.
+        let own = parent_dir(from);
+        if !above.contains(&own) {
+            above.push(own);
+        }
+        if let Some(found) = above.into_iter().find_map(|root| self.file_in(root, module)) {
+            return Some(found);
+        }
+
+        // Sibling projects only when exactly one provides the module.
+        let mut found = others.into_iter().filter_map(|root| self.file_in(root, module));
+        let first = found.next()?;
+        found.all(|other| other == first).then_some(first)
+    }
+
+    fn file_in(&self, dir: &str, module: &[&str]) -> Option<&'a str> {
+        let base = super::join_path(dir, &module.join("/"));
+        [format!("{base}.py"), format!("{base}/__init__.py")]
+            .iter()
+            .find_map(|candidate| self.modules.get_key_value(candidate.as_str()).map(|(path, _)| *path))
+    }
+}
+
+/// `app/tokens.py` → `tokens`, `app/__init__.py` → `app`.
+fn module_name(path: &str) -> &str {
+    let path = path.strip_suffix("/__init__.py").unwrap_or(path);
+    let file = path.rsplit('/').next().unwrap_or(path);
+    file.strip_suffix(".py").unwrap_or(file)
+}
+
+/// Package directories a `pyproject.toml` declares: setuptools `where` and
+/// `package-dir`, Poetry `from`, and the parents of Hatch wheel `packages`.
+fn pyproject_roots(parts: &[&Chunk]) -> Vec<String> {
+    let mut roots = Vec::new();
+    let mut section = "";
+    for line in parts.iter().flat_map(|chunk| chunk.content.lines()) {
+        let line = line.trim();
+        if let Some(header) = line.strip_prefix('[').filter(|header| !header.starts_with('[')) {
+            section = header.trim_end_matches(']').trim();
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        match (section, key) {
+            (_, "where") => roots.extend(quoted(value).map(str::to_string)),
+            ("tool.setuptools.package-dir", "\"\"") => roots.extend(quoted(value).map(str::to_string)),
+            ("tool.hatch.build.targets.wheel", "packages") => {
+                roots.extend(quoted(value).map(|package| parent_dir(package).to_string()))
+            }
+            _ => {}
+        }
+        // Poetry: `packages = [{ include = "app", from = "src" }]`.
+        let mut rest = line;
+        while let Some(idx) = rest.find("from") {
+            rest = &rest[idx + "from".len()..];
+            if let Some(value) = rest.trim_start().strip_prefix('=') {
+                roots.extend(quoted(value).next().map(str::to_string));
+            }
+        }
+    }
+    roots
+}
+
+/// The double-quoted strings in a TOML value.
+fn quoted(value: &str) -> impl Iterator<Item = &str> {
+    value.split('"').skip(1).step_by(2)
+}
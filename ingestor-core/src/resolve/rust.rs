@@ -0,0 +1,201 @@
+//! Rust scope rules: items of the current module, then `use` bindings, then paths
+//! anchored at `crate`, `self`, `super` or another crate of the workspace.
+//!
+//! Module paths follow the file tree the way rustc lays it out (`lib.rs`/`main.rs`,
+//! `foo.rs`, `foo/mod.rs`), so a path naming a module binds to the file holding it.
+//! Crate names come from the indexed `Cargo.toml` manifests.
+
+use super::{unique, Binding};
+use crate::model::{Chunk, LanguageId};
+use std::collections::HashMap;
+
+pub(super) struct RustScopes<'a> {
+    /// `(crate root, absolute item path)` → defining chunks.
+    items: HashMap<(&'a str, String), Vec<&'a Chunk>>,
+    /// `(crate root, module path)` → first chunk of the file that holds the module.
+    modules: HashMap<(&'a str, &'a str), &'a Chunk>,
+    /// File path → `use` paths imported anywhere in the file.
+    uses: HashMap<&'a str, Vec<&'a str>>,
+    /// Library crate name → crate root, for every indexed Cargo package.
+    crates: HashMap<String, &'a str>,
+}
+
+impl<'a> RustScopes<'a> {
+    pub(super) fn build(chunks: &'a [Chunk]) -> Self {
+        let scoped: Vec<&'a Chunk> = super::scoped_chunks(chunks, LanguageId::Rust).collect();
+
+        // A file's module is the outermost module any of its chunks sits in;
+        // qualified names are relative to it.
+        let mut file_modules: HashMap<&'a str, &'a str> = HashMap::new();
+        let mut uses: HashMap<_, Vec<&'a str>> = HashMap::new();
+        for chunk in &scoped {
+            let module = module_path(chunk);
+            file_modules
+                .entry(chunk.path.as_str())
+                .and_modify(|file_module| {
+                    if module.len() < file_module.len() {
+                        *file_module = module;
+                    }
+                })
+                .or_insert(module);
+            let file_uses = uses.entry(chunk.path.as_str()).or_default();
+            for import in &chunk.imports {
+                if !file_uses.contains(&import.as_str()) {
+                    file_uses.push(import);
+                }
+            }
+        }
+
+        let mut items: HashMap<_, Vec<&'a Chunk>> = HashMap::new();
+        let mut modules: HashMap<_, &'a Chunk> = HashMap::new();
+        for chunk in scoped {
+            let root = crate_root(&chunk.path);
+            let file_module = file_modules[chunk.path.as_str()];
+            if let Some(qualified_name) = chunk.qualified_name.as_deref() {
+                items
+                    .entry((root, format!("{file_module}::{qualified_name}")))
+                    .or_default()
+                    .push(chunk);
+            }
+            let head = modules.entry((root, file_module)).or_insert(chunk);
+            if head.path == chunk.path && chunk.chunk_index < head.chunk_index {
+                *head = chunk;
+            }
+        }
+
+        Self {
+            items,
+            modules,
+            uses,
+            crates: workspace_crates(chunks),
+        }
+    }
+
+    pub(super) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<Binding<'a>> {
+        let root = crate_root(&chunk.path);
+        let module = module_path(chunk);
+        let segments: Vec<&str> = target.split("::").collect();
+        if let Some((base, rest)) = anchor(module, &segments) {
+            return self.path(root, base, rest);
+        }
+
+        // An item or child module of the current module.
+        if let Some(found) = self.path(root, module, &segments) {
+            return Some(found);
+        }
+
+        let (first, rest) = segments.split_first()?;
+        for import in self.uses.get(chunk.path.as_str()).into_iter().flatten() {
+            let mut path: Vec<&str> = import.split("::").collect();
+            match path.last() {
+                Some(&"*") => {
+                    path.pop();
+                    path.extend(&segments);
+                }
+                Some(tail) if tail == first => path.extend(rest),
+                _ => continue,
+            }
+            let found = match anchor(module, &path) {
+                Some((base, rest)) => self.path(root, base, rest),
+                None => self.path(root, module, &path).or_else(|| self.external(&path)),
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        self.external(&segments)
+    }
+
+    /// A path into another crate of the workspace, named as its `Cargo.toml` names it.
+    fn external(&self, segments: &[&str]) -> Option<Binding<'a>> {
+        let (name, rest) = segments.split_first()?;
+        let root = self.crates.get(*name)?;
+        self.path(root, "crate", rest)
+    }
+
+    /// The item or module at `base::segments`; a module binds to the file holding it.
+    fn path(&self, root: &str, base: &str, segments: &[&str]) -> Option<Binding<'a>> {
+        let path = std::iter::once(base)
+            .chain(segments.iter().copied())
+            .collect::<Vec<_>>()
+            .join("::");
+        if let Some(head) = self.modules.get(&(root, path.as_str())) {
+            let module = module_path(head);
+            return Some(Binding {
+                chunk: head,
+                name: module.rsplit("::").next().unwrap_or(module),
+            });
+        }
+        unique(self.items.get(&(root, path))?.iter().copied()).map(Binding::item)
+    }
+}
+
+/// Split a `crate::`, `self::` or `super::` path into the module it starts from and the rest.
+fn anchor<'p>(module: &'p str, segments: &'p [&'p str]) -> Option<(&'p str, &'p [&'p str])> {
+    match segments.split_first()? {
+        (&"crate", rest) => Some(("crate", rest)),
+        (&"self", rest) => Some((module, rest)),
+        (&"super", _) => {
+            let mut base = module;
+            let mut rest = segments;
+            while let Some((&"super", tail)) = rest.split_first() {
+                base = base.rsplit_once("::")?.0;
+                rest = tail;
+            }
+            Some((base, rest))
+        }
+        _ => None,
+    }
+}
+
+fn module_path(chunk: &Chunk) -> &str {
+    chunk.module_path.as_deref().unwrap_or("crate")
+}
+
+/// Everything before the crate's `src/` directory, except that each `src/bin/<name>`
+/// target is a crate of its own; files outside `src/` share their directory.
+fn crate_root(path: &str) -> &str {
+    match path.rfind("src/") {
+        Some(idx) if idx == 0 || path[..idx].ends_with('/') => match path[idx..].strip_prefix("src/bin/") {
+            Some(bin) => &path[..idx + "src/bin/".len() + bin.find('/').unwrap_or(bin.len())],
+            None => &path[..idx],
+        },
+        _ => super::parent_dir(path),
+    }
+}
+
+/// Library crate names declared by the indexed `Cargo.toml` manifests, keyed the way
+/// `use` paths spell them (`llmx-mcp` → `llmx_mcp`) and mapped to the package root.
+fn workspace_crates(chunks: &[Chunk]) -> HashMap<String, &str> {
+    let mut crates = HashMap::new();
+    for (path, parts) in super::manifests(chunks, "Cargo.toml") {
+        let mut section = "";
+        let (mut package, mut lib) = (None, None);
+        for line in parts.iter().flat_map(|chunk| chunk.content.lines()) {
+            let line = line.trim();
+            if let Some(header) = line.strip_prefix('[') {
+                section = header.trim_end_matches(']').trim();
+                continue;
+            }
+            let Some(value) = line
+                .strip_prefix("name")
+                .and_then(|rest| rest.trim_start().strip_prefix('='))
+                .and_then(|value| value.trim().strip_prefix('"'))
+                .and_then(|value| value.split('"').next())
+            else {
+                continue;
+            };
+            match section {
+                "package" => package.get_or_insert(value),
+                "lib" => lib.get_or_insert(value),
+                _ => continue,
+            };
+        }
+        if let Some(name) = lib.or(package) {
+            let root = &path[..path.len() - "Cargo.toml".len()];
+            crates.insert(name.replace('-', "_"), root);
+        }
+    }
+    crates
+}
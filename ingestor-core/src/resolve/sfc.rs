@@ -0,0 +1,42 @@
+//! Single-file component imports: `import BaseCard from "./BaseCard.vue"`, in a Vue or
+//! Svelte component or in a JavaScript/TypeScript module, binds to the imported
+//! component file. Components have a single default export, the component itself.
+
+use super::{join_path, parent_dir, Binding};
+use crate::model::Chunk;
+use std::collections::HashMap;
+
+pub(super) struct ComponentScopes<'a> {
+    /// Component file path → its first chunk.
+    heads: HashMap<&'a str, &'a Chunk>,
+}
+
+impl<'a> ComponentScopes<'a> {
+    pub(super) fn build(chunks: &'a [Chunk]) -> Self {
+        let mut heads: HashMap<&'a str, &'a Chunk> = HashMap::new();
+        for chunk in chunks.iter().filter(|chunk| is_component(&chunk.path)) {
+            let head = heads.entry(chunk.path.as_str()).or_insert(chunk);
+            if chunk.chunk_index < head.chunk_index {
+                *head = chunk;
+            }
+        }
+        Self { heads }
+    }
+
+    /// Bind a relative `specifier#default` import of a component file.
+    pub(super) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<Binding<'a>> {
+        let specifier = target.strip_suffix("#default")?;
+        if !specifier.starts_with('.') || !is_component(specifier) {
+            return None;
+        }
+        let (path, head) = self.heads.get_key_value(join_path(parent_dir(&chunk.path), specifier).as_str())?;
+        Some(Binding {
+            chunk: head,
+            name: path.rsplit('/').next().unwrap_or(path),
+        })
+    }
+}
+
+fn is_component(path: &str) -> bool {
+    path.ends_with(".vue") || path.ends_with(".svelte")
+}
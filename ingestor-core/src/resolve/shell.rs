@@ -0,0 +1,126 @@
+//! Shell scope rules: functions defined in the script itself, and the files it
+//! `source`s.
+//!
+//! A sourced path that starts with an expansion (`$(dirname "$0")/lib.sh`,
+//! `${BASH_SOURCE%/*}/lib.sh`, `$DIR/lib.sh`) is taken to be relative to the
+//! script's directory. A bare relative path depends on the working directory, so
+//! it is tried from the script's directory and then from each directory above it.
+
+use super::{join_path, parent_dir, unique, Binding, ModuleImport};
+use crate::model::{Chunk, LanguageId};
+use std::collections::HashMap;
+
+pub(super) struct ShellScopes<'a> {
+    /// Script path → its chunks.
+    files: HashMap<&'a str, Vec<&'a Chunk>>,
+    /// Every indexed file → its first chunk; scripts may source files of any kind.
+    heads: HashMap<&'a str, &'a Chunk>,
+}
+
+impl<'a> ShellScopes<'a> {
+    pub(super) fn build(chunks: &'a [Chunk]) -> Self {
+        let mut files: HashMap<_, Vec<&'a Chunk>> = HashMap::new();
+        for chunk in super::scoped_chunks(chunks, LanguageId::Shell) {
+            files.entry(chunk.path.as_str()).or_default().push(chunk);
+        }
+
+        let mut heads: HashMap<&'a str, &'a Chunk> = HashMap::new();
+        for chunk in chunks {
+            let head = heads.entry(chunk.path.as_str()).or_insert(chunk);
+            if chunk.chunk_index < head.chunk_index {
+                *head = chunk;
+            }
+        }
+
+        Self { files, heads }
+    }
+
+    pub(super) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<Binding<'a>> {
+        if chunk.imports.iter().any(|import| import == target) {
+            let path = self.sourced(&chunk.path, target)?;
+            return Some(Binding {
+                chunk: self.heads[path],
+                name: path.rsplit('/').next().unwrap_or(path),
+            });
+        }
+        unique(
+            self.files
+                .get(chunk.path.as_str())?
+                .iter()
+                .copied()
+                .filter(|candidate| candidate.qualified_name.as_deref() == Some(target)),
+        )
+        .map(Binding::item)
+    }
+
+    /// One edge per distinct file a script sources.
+    pub(super) fn module_imports(&self) -> Vec<ModuleImport<'a>> {
+        let mut imports = Vec::new();
+        for (path, chunks) in &self.files {
+            let source = self.heads[path];
+            let mut targets: Vec<&str> = Vec::new();
+            for import in chunks.iter().flat_map(|chunk| &chunk.imports) {
+                let Some(target) = self.sourced(path, import) else {
+                    continue;
+                };
+                if target == *path || targets.contains(&target) {
+                    continue;
+                }
+                targets.push(target);
+                imports.push(ModuleImport {
+                    source,
+                    specifier: import,
+                    target: self.heads[target],
+                });
+            }
+        }
+        imports
+    }
+
+    /// The indexed file `specifier` names when sourced from the script at `from`.
+    fn sourced(&self, from: &str, specifier: &str) -> Option<&'a str> {
+        let script_dir = parent_dir(from);
+        let (dirs, relative) = match strip_expansion(specifier) {
+            Some(relative) => (vec![script_dir], relative),
+            None => {
+                let mut dirs = vec![script_dir];
+                let mut dir = script_dir;
+                while !dir.is_empty() {
+                    dir = parent_dir(dir);
+                    dirs.push(dir);
+                }
+                (dirs, specifier)
+            }
+        };
+        if relative.starts_with(['/', '~']) || relative.contains('$') {
+            return None;
+        }
+        dirs.into_iter().find_map(|dir| {
+            let candidate = join_path(dir, relative);
+            self.heads.get_key_value(candidate.as_str()).map(|(path, _)| *path)
+        })
+    }
+}
+
+/// The path after a leading `$(...)`, `${...}` or `$NAME` expansion and its `/`.
+fn strip_expansion(specifier: &str) -> Option<&str> {
+    let rest = specifier.strip_prefix('$')?;
+    let end = if let Some(inner) = rest.strip_prefix('(') {
+        let mut depth = 1;
+        let close = inner.char_indices().find_map(|(idx, ch)| {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            (depth == 0).then_some(idx)
+        })?;
+        close + 2
+    } else if let Some(inner) = rest.strip_prefix('{') {
+        inner.find('}')? + 2
+    } else {
+        rest.find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .unwrap_or(rest.len())
+    };
+    rest[end..].strip_prefix('/')
+}
@@ -0,0 +1,383 @@
+//! JavaScript/TypeScript scope rules: declarations of the current file (innermost
+//! namespace first), then `specifier#name` import bindings resolved to the exporting
+//! file, following re-exports through barrel files.
+//!
+//! Specifiers map onto indexed files the way bundler resolution does: relative paths
+//! with extension and `index.*` probing, then `paths`/`baseUrl` from the nearest
+//! `tsconfig.json` (or `jsconfig.json`), then workspace packages by their
+//! `package.json` `name` and `exports`.
+
+use super::{join_path, parent_dir, unique, ModuleImport};
+use crate::model::{AstNodeKind, Chunk, LanguageId};
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Extensions tried, in order, for an extensionless specifier.
+const MODULE_SUFFIXES: &[&str] = &[
+    ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", "/index.ts", "/index.tsx", "/index.d.ts", "/index.js",
+    "/index.jsx",
+];
+
+/// Re-export hops followed before settling on the barrel file itself.
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+pub(super) struct TypeScriptScopes<'a> {
+    /// File path → chunks declared in it.
+    files: HashMap<&'a str, Vec<&'a Chunk>>,
+    /// Module options of every indexed `tsconfig.json`/`jsconfig.json`.
+    configs: Vec<PathConfig<'a>>,
+    /// Workspace package name → its directory and `package.json`.
+    packages: HashMap<String, Package<'a>>,
+}
+
+struct PathConfig<'a> {
+    dir: &'a str,
+    base_url: Option<String>,
+    /// `paths` patterns with their targets, relative to `baseUrl` (else `dir`).
+    paths: Vec<(String, Vec<String>)>,
+}
+
+struct Package<'a> {
+    dir: &'a str,
+    manifest: Value,
+}
+
+impl<'a> TypeScriptScopes<'a> {
+    pub(super) fn build(chunks: &'a [Chunk]) -> Self {
+        let mut files: HashMap<_, Vec<&'a Chunk>> = HashMap::new();
+        let scoped = super::scoped_chunks(chunks, LanguageId::TypeScript)
+            .chain(super::scoped_chunks(chunks, LanguageId::JavaScript));
+        for chunk in scoped {
+            files.entry(chunk.path.as_str()).or_default().push(chunk);
+        }
+
+        let mut configs = Vec::new();
+        for file_name in ["tsconfig.json", "jsconfig.json"] {
+            for (path, parts) in super::manifests(chunks, file_name) {
+                let Some(options) = super::json_manifest(&parts).and_then(|config| config.get("compilerOptions").cloned())
+                else {
+                    continue;
+                };
+                let dir = parent_dir(path);
+                let paths = options
+                    .get("paths")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .map(|(pattern, targets)| {
+                        let targets = targets.as_array().into_iter().flatten().filter_map(Value::as_str);
+                        (pattern.clone(), targets.map(str::to_string).collect())
+                    })
+                    .collect();
+                configs.push(PathConfig {
+                    dir,
+                    base_url: options.get("baseUrl").and_then(Value::as_str).map(|base| join_path(dir, base)),
+                    paths,
+                });
+            }
+        }
+
+        let mut packages = HashMap::new();
+        for (path, parts) in super::manifests(chunks, "package.json") {
+            let Some(manifest) = super::json_manifest(&parts) else {
+                continue;
+            };
+            if let Some(name) = manifest.get("name").and_then(Value::as_str) {
+                packages.insert(
+                    name.to_string(),
+                    Package {
+                        dir: parent_dir(path),
+                        manifest,
+                    },
+                );
+            }
+        }
+
+        Self {
+            files,
+            configs,
+            packages,
+        }
+    }
+
+    pub(super) fn resolve(&self, chunk: &Chunk, target: &str) -> Option<&'a Chunk> {
+        if let Some((specifier, name)) = target.split_once('#') {
+            return self.imported(&chunk.path, specifier, name);
+        }
+        if let Some(local) = self.local(chunk, target) {
+            return Some(local);
+        }
+
+        // `verify` or `AuthService.login`, where the first segment came in through an import.
+        let (first, rest) = match target.split_once('.') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (target, None),
+        };
+        let bindings = self.file_bindings(&chunk.path);
+        let imported = unique(
+            bindings
+                .into_iter()
+                .filter(|(_, name)| *name == first)
+                .filter_map(|(specifier, name)| self.imported(&chunk.path, specifier, name)),
+        )?;
+        match rest {
+            None => Some(imported),
+            Some(member) => {
+                let owner = imported.qualified_name.as_deref().or(imported.symbol.as_deref())?;
+                self.declared_in(&imported.path, &format!("{owner}.{member}"))
+            }
+        }
+    }
+
+    /// One edge per distinct specifier a file imports that maps onto another indexed file.
+    pub(super) fn module_imports(&self) -> Vec<ModuleImport<'a>> {
+        let mut imports = Vec::new();
+        for (path, chunks) in &self.files {
+            let Some(source) = first_chunk(chunks) else {
+                continue;
+            };
+            let mut specifiers: Vec<&'a str> = Vec::new();
+            for (specifier, _) in self.file_bindings(path) {
+                if !specifiers.contains(&specifier) {
+                    specifiers.push(specifier);
+                }
+            }
+            for specifier in specifiers {
+                let Some(module) = self.module_file(path, specifier).filter(|module| module != path) else {
+                    continue;
+                };
+                if let Some(target) = self.files.get(module).and_then(|chunks| first_chunk(chunks)) {
+                    imports.push(ModuleImport {
+                        source,
+                        specifier,
+                        target,
+                    });
+                }
+            }
+        }
+        imports
+    }
+
+    /// A declaration in the same file, searched from the innermost namespace outwards.
+    fn local(&self, chunk: &Chunk, target: &str) -> Option<&'a Chunk> {
+        let mut namespaces: Vec<&str> = chunk
+            .module_path
+            .as_deref()
+            .map(|path| path.split('.').collect())
+            .unwrap_or_default();
+        loop {
+            let qualified = namespaces
+                .iter()
+                .copied()
+                .chain(std::iter::once(target))
+                .collect::<Vec<_>>()
+                .join(".");
+            if let Some(found) = self.declared_in(&chunk.path, &qualified) {
+                return Some(found);
+            }
+            namespaces.pop()?;
+        }
+    }
+
+    fn declared_in(&self, path: &str, qualified_name: &str) -> Option<&'a Chunk> {
+        unique(
+            self.files
+                .get(path)?
+                .iter()
+                .copied()
+                .filter(|chunk| chunk.qualified_name.as_deref() == Some(qualified_name)),
+        )
+    }
+
+    /// `(specifier, name)` pairs imported anywhere in the file.
+    fn file_bindings(&self, path: &str) -> Vec<(&'a str, &'a str)> {
+        let mut bindings = Vec::new();
+        for chunk in self.files.get(path).into_iter().flatten() {
+            for reference in chunk.imports.iter().chain(&chunk.type_refs) {
+                if let Some(binding) = reference.split_once('#') {
+                    if !bindings.contains(&binding) {
+                        bindings.push(binding);
+                    }
+                }
+            }
+        }
+        bindings
+    }
+
+    /// The chunk exporting `name` from the module `specifier` refers to, relative to `from`.
+    fn imported(&self, from: &str, specifier: &str, name: &str) -> Option<&'a Chunk> {
+        let module = self.module_file(from, specifier)?;
+        self.exported(module, name, 0)
+    }
+
+    /// The chunk `module` exports as `name`. Re-exports are followed to the declaration;
+    /// when that is not indexed, the re-export itself is the binding.
+    fn exported(&self, module: &'a str, name: &str, depth: usize) -> Option<&'a Chunk> {
+        let chunks = self.files.get(module)?;
+        let declared = unique(chunks.iter().copied().filter(|chunk| {
+            chunk.parent_symbol.is_none()
+                && chunk.ast_kind != Some(AstNodeKind::Export)
+                && chunk.exports.iter().any(|export| export == name)
+        }));
+        if declared.is_some() {
+            return declared;
+        }
+
+        for chunk in chunks.iter().filter(|chunk| chunk.ast_kind == Some(AstNodeKind::Export)) {
+            for (export, import) in chunk.exports.iter().zip(&chunk.imports) {
+                let Some((specifier, original)) = import.split_once('#') else {
+                    continue;
+                };
+                let forwarded = match (export.as_str(), original) {
+                    // `export * as ns from` binds the namespace itself.
+                    (export, "*") if export == name => return Some(chunk),
+                    ("*", "*") if name != "default" => name,
+                    (export, original) if export == name => original,
+                    _ => continue,
+                };
+                let found = if depth < MAX_REEXPORT_DEPTH {
+                    self.module_file(module, specifier)
+                        .and_then(|next| self.exported(next, forwarded, depth + 1))
+                } else {
+                    None
+                };
+                match found {
+                    Some(found) => return Some(found),
+                    None if export != "*" => return Some(chunk),
+                    None => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Map a specifier onto an indexed file; packages outside the workspace stay unresolved.
+    fn module_file(&self, from: &str, specifier: &str) -> Option<&'a str> {
+        if specifier.starts_with('.') {
+            return self.file_at(&join_path(parent_dir(from), specifier));
+        }
+        self.aliased(from, specifier).or_else(|| self.package_file(specifier))
+    }
+
+    /// The indexed file `base` names, probing extensions and `index.*`.
+    fn file_at(&self, base: &str) -> Option<&'a str> {
+        // ESM-style `./auth.js` specifiers name the compiled output of `auth.ts`.
+        let stripped = [".js", ".jsx", ".mjs", ".cjs"]
+            .iter()
+            .find_map(|ext| base.strip_suffix(ext))
+            .map(str::to_string);
+        let candidates = std::iter::once(base.to_string())
+            .chain(stripped.iter().flat_map(|stem| MODULE_SUFFIXES.iter().map(move |suffix| format!("{stem}{suffix}"))))
+            .chain(MODULE_SUFFIXES.iter().map(|suffix| format!("{base}{suffix}")));
+        for candidate in candidates {
+            if let Some((path, _)) = self.files.get_key_value(candidate.as_str()) {
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    /// `paths` aliases, then `baseUrl`, from the closest config above `from`.
+    /// Among `paths` patterns the longest matching prefix wins, as in `tsc`.
+    fn aliased(&self, from: &str, specifier: &str) -> Option<&'a str> {
+        let config = self
+            .configs
+            .iter()
+            .filter(|config| config.dir.is_empty() || from.strip_prefix(config.dir).is_some_and(|rest| rest.starts_with('/')))
+            .max_by_key(|config| config.dir.len())?;
+        let root = config.base_url.as_deref().unwrap_or(config.dir);
+
+        let mut matches: Vec<(usize, &[String], &str)> = config
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| match pattern.split_once('*') {
+                Some((prefix, suffix)) => {
+                    let captured = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                    Some((prefix.len(), targets.as_slice(), captured))
+                }
+                None => (pattern == specifier).then_some((usize::MAX, targets.as_slice(), "")),
+            })
+            .collect();
+        matches.sort_by_key(|(prefix_len, ..)| Reverse(*prefix_len));
+        for (_, targets, captured) in matches {
+            for target in targets {
+                if let Some(found) = self.file_at(&join_path(root, &target.replace('*', captured))) {
+                    return Some(found);
+                }
+            }
+        }
+
+        self.file_at(&join_path(config.base_url.as_deref()?, specifier))
+    }
+
+    /// A workspace package by its `package.json` `name`: the entry point from `exports`,
+    /// else `source`/`types`/`module`/`main`, else `index.*` or `src/index.*`.
+    fn package_file(&self, specifier: &str) -> Option<&'a str> {
+        let split = if specifier.starts_with('@') {
+            specifier.match_indices('/').nth(1).map(|(idx, _)| idx)
+        } else {
+            specifier.find('/')
+        };
+        let (name, subpath) = match split {
+            Some(idx) => (&specifier[..idx], &specifier[idx + 1..]),
+            None => (specifier, ""),
+        };
+        let package = self.packages.get(name)?;
+        let resolve = |target: &str| self.file_at(&join_path(package.dir, target));
+
+        if let Some(exports) = package.manifest.get("exports") {
+            let entry = if subpath.is_empty() {
+                ".".to_string()
+            } else {
+                format!("./{subpath}")
+            };
+            return export_targets(exports, &entry).iter().find_map(|target| resolve(target));
+        }
+        if subpath.is_empty() {
+            ["source", "types", "typings", "module", "main"]
+                .iter()
+                .filter_map(|field| package.manifest.get(*field)?.as_str())
+                .chain(["index", "src/index"])
+                .find_map(resolve)
+        } else {
+            resolve(subpath).or_else(|| resolve(&format!("src/{subpath}")))
+        }
+    }
+}
+
+fn first_chunk<'a>(chunks: &[&'a Chunk]) -> Option<&'a Chunk> {
+    chunks.iter().copied().min_by_key(|chunk| chunk.chunk_index)
+}
+
+/// Targets `exports` maps the subpath `entry` (`.` or `./sub`) to, under every condition.
+fn export_targets(exports: &Value, entry: &str) -> Vec<String> {
+    let subpaths = match exports {
+        Value::Object(map) if map.keys().any(|key| key.starts_with('.')) => map,
+        // `"exports": "./index.js"` or a bare conditions object describe `.` only.
+        _ if entry == "." => return condition_targets(exports, ""),
+        _ => return Vec::new(),
+    };
+    if let Some(value) = subpaths.get(entry) {
+        return condition_targets(value, "");
+    }
+    subpaths
+        .iter()
+        .filter_map(|(key, value)| {
+            let (prefix, suffix) = key.split_once('*')?;
+            let captured = entry.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            Some(condition_targets(value, captured))
+        })
+        .flatten()
+        .collect()
+}
+
+/// Every target string under a conditional export (`import`, `types`, ...), `*` filled in.
+fn condition_targets(value: &Value, captured: &str) -> Vec<String> {
+    match value {
+        Value::String(target) => vec![target.replace('*', captured)],
+        Value::Array(items) => items.iter().flat_map(|item| condition_targets(item, captured)).collect(),
+        Value::Object(conditions) => conditions.values().flat_map(|item| condition_targets(item, captured)).collect(),
+        _ => Vec::new(),
+    }
+}
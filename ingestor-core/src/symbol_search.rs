@@ -346,6 +346,7 @@ mod tests {
             is_generated: false,
             quality_score: None,
             resolution_tier: crate::model::ResolutionTier::GenericTreeSitter,
+            log_level: None,
             ast_kind: None,
             qualified_name: None,
             symbol_id: None,
@@ -357,6 +358,7 @@ mod tests {
             imports: Vec::new(),
             exports: Vec::new(),
             calls: Vec::new(),
+            method_calls: Vec::new(),
             type_refs: Vec::new(),
             doc_summary: None,
         }
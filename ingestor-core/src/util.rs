@@ -28,17 +28,39 @@ pub fn detect_kind(path: &str) -> ChunkKind {
         Some("md" | "markdown") => ChunkKind::Markdown,
         Some("json") => ChunkKind::Json,
         Some("js" | "ts" | "tsx") => ChunkKind::JavaScript,
-        Some("html" | "htm") => ChunkKind::Html,
-        Some("xml") => ChunkKind::Text, // XML needs tags preserved, not stripped like HTML
-        Some("txt" | "log" | "jsonl" | "csv" | "ini" | "cfg" | "conf") => ChunkKind::Text,
+        Some("html" | "htm" | "vue" | "svelte") => ChunkKind::Html,
+        Some("xml" | "csproj" | "fsproj" | "vbproj") => ChunkKind::Text, // XML needs tags preserved, not stripped like HTML
+        Some("txt" | "log" | "jsonl" | "ndjson" | "csv" | "tsv" | "ini" | "cfg" | "conf") => ChunkKind::Text,
         Some("png" | "jpg" | "jpeg" | "webp" | "gif" | "bmp") => ChunkKind::Image,
         _ => ChunkKind::Unknown,
     }
 }
 
+/// Build files named rather than given an extension: `Dockerfile`, `Dockerfile.dev`,
+/// `GNUmakefile`, `justfile`.
+fn build_file_language(name: &str) -> Option<LanguageId> {
+    let lower = name.to_ascii_lowercase();
+    let base = lower.trim_start_matches('.').split('.').next().unwrap_or("");
+    let language = match base {
+        "dockerfile" | "containerfile" => "dockerfile",
+        "makefile" | "gnumakefile" => "make",
+        "justfile" => "just",
+        _ if lower.ends_with(".dockerfile") => "dockerfile",
+        _ if lower.ends_with(".mk") => "make",
+        _ if lower.ends_with(".just") => "just",
+        _ => return None,
+    };
+    Some(LanguageId::Other(language.to_string()))
+}
+
 pub fn detect_language(path: &str) -> Option<LanguageId> {
     use std::path::Path;
 
+    let name = Path::new(path).file_name().and_then(|name| name.to_str()).unwrap_or("");
+    if let Some(language) = build_file_language(name) {
+        return Some(language);
+    }
+
     let ext = Path::new(path)
         .extension()
         .and_then(|e| e.to_str())
@@ -286,6 +308,64 @@ pub fn snippet(text: &str, max_len: usize) -> String {
     out
 }
 
+/// Fold each run of at least `min_repeat` identical lines into its first line and a
+/// `... (previous line repeated N more times)` marker. Returns whether anything was folded.
+pub(crate) fn compact_repeated_lines(text: &str, min_repeat: usize) -> (String, bool) {
+    compact_repeated_lines_by(text, min_repeat, str::to_string)
+}
+
+/// Like [`compact_repeated_lines`], but lines repeat when their `key` is equal, e.g. log
+/// lines that differ only in their timestamp.
+pub(crate) fn compact_repeated_lines_by(
+    text: &str,
+    min_repeat: usize,
+    key: impl Fn(&str) -> String,
+) -> (String, bool) {
+    let mut out: Vec<String> = Vec::new();
+    // First line of the current run, its key and the run length.
+    let mut current: Option<(&str, String)> = None;
+    let mut count = 0usize;
+    let mut compacted = false;
+
+    for line in text.lines() {
+        let line_key = key(line);
+        match &current {
+            Some((_, prev_key)) if *prev_key == line_key => {
+                count += 1;
+            }
+            Some((prev, _)) => {
+                push_run(&mut out, prev, count, min_repeat, &mut compacted);
+                current = Some((line, line_key));
+                count = 1;
+            }
+            None => {
+                current = Some((line, line_key));
+                count = 1;
+            }
+        }
+    }
+    if let Some((prev, _)) = current {
+        push_run(&mut out, prev, count, min_repeat, &mut compacted);
+    }
+
+    (out.join("\n"), compacted)
+}
+
+fn push_run(out: &mut Vec<String>, line: &str, count: usize, min_repeat: usize, compacted: &mut bool) {
+    if count >= min_repeat {
+        out.push(line.to_string());
+        out.push(format!(
+            "... (previous line repeated {} more times)",
+            count.saturating_sub(1)
+        ));
+        *compacted = true;
+    } else {
+        for _ in 0..count {
+            out.push(line.to_string());
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub fn redact_secrets(input: &str) -> String {
     let patterns = [
@@ -9,10 +9,11 @@ use std::time::{Instant, SystemTime};
 pub const ALLOWED_EXTENSIONS: &[&str] = &[
     "rs",
     "js", "ts", "tsx", "jsx", "mjs", "cjs",
+    "vue", "svelte",
     "html", "css", "scss", "sass", "less",
     "json", "yaml", "yml", "toml",
     "md", "txt",
-    "py",
+    "py", "ipynb",
     "go",
     "c", "cpp", "cc", "cxx", "h", "hpp", "hxx",
     "java",
@@ -20,11 +21,17 @@ pub const ALLOWED_EXTENSIONS: &[&str] = &[
     "php",
     "swift",
     "sh", "bash", "zsh",
-    "sql",
-    "log", "jsonl", "csv", "xml", "ini", "cfg", "conf",
+    "sql", "proto", "graphql", "gql",
+    "tf", "hcl", "mk", "just", "dockerfile",
+    "log", "jsonl", "ndjson", "csv", "tsv", "xml", "csproj", "fsproj", "vbproj", "ini", "cfg", "conf",
 ];
 
-pub const ALLOWED_DOTFILES: &[&str] = &[".npmrc", ".nvmrc", ".editorconfig", ".gitignore"];
+pub const ALLOWED_DOTFILES: &[&str] = &[".npmrc", ".nvmrc", ".editorconfig", ".gitignore", ".gitattributes"];
+
+/// Build files recognised by name, alone or with a suffix (`Dockerfile.dev`).
+pub const ALLOWED_BUILD_FILES: &[&str] = &[
+    "Dockerfile", "Containerfile", "Makefile", "GNUmakefile", "makefile", "justfile", "Justfile", ".justfile",
+];
 
 const EXCLUDED_DIR_PREFIXES: &[&str] = &[
     "target",
@@ -245,6 +252,15 @@ pub fn read_file(path: &Path, path_root: &Path) -> Result<Option<FileInput>> {
 }
 
 pub fn should_index_path(path: &Path) -> bool {
+    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+        let build_file = ALLOWED_BUILD_FILES
+            .iter()
+            .any(|build| name.strip_prefix(build).is_some_and(|rest| rest.is_empty() || rest.starts_with('.')));
+        if build_file {
+            return true;
+        }
+    }
+
     if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
         return ALLOWED_EXTENSIONS.contains(&ext);
     }
@@ -57,10 +57,16 @@ fn html_strips_script_content() {
     let combined = index
         .chunks
         .iter()
+        .filter(|c| c.kind == llmx_mcp::ChunkKind::Html)
         .map(|c| c.content.clone())
         .collect::<Vec<_>>()
         .join("\n");
     assert!(!combined.contains("console.log"));
+
+    // Inline scripts are indexed as code of their own, not as page text.
+    let script = index.chunks.iter().find(|c| c.content.contains("console.log")).expect("script chunk");
+    assert_ne!(script.kind, llmx_mcp::ChunkKind::Html);
+    assert_eq!(script.start_line, 10);
 }
 
 #[test]
@@ -154,3 +160,284 @@ fn selective_update_keeps_unchanged_paths() {
     assert_eq!(paths, vec!["docs/a.md".to_string(), "docs/b.md".to_string()]);
     assert_eq!(updated.warnings.len(), 0);
 }
+
+#[test]
+fn tagged_code_fences_in_markdown_are_chunked_by_language() {
+    let readme = "\
+# Usage
+
+Open an index and search it:
+
+```rust
+pub fn open_index(path: &str) -> Index {
+    Index::load(path).expect(\"index\")
+}
+```
+
+```bash
+llmx search \"open index\"
+```
+";
+    let input = llmx_mcp::FileInput {
+        path: "README.md".to_string(),
+        data: readme.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let index = ingest_files(vec![input], IngestOptions::default());
+    let chunks = &index.chunks;
+    assert_eq!(chunks.len(), 3);
+
+    assert!(chunks[0].content.ends_with("search it:"));
+    let function = &chunks[1];
+    assert_eq!(function.symbol.as_deref(), Some("open_index"));
+    assert_eq!(function.ast_kind, Some(llmx_mcp::AstNodeKind::Function));
+    assert_eq!((function.start_line, function.end_line), (6, 8));
+    assert_eq!(function.heading_path, vec!["Usage".to_string()]);
+
+    // Snippets that declare nothing stay with the prose around them.
+    assert!(chunks[2].content.starts_with("```bash"));
+    assert_eq!(chunks[2].heading_path, vec!["Usage".to_string()]);
+}
+
+#[test]
+fn inline_scripts_and_styles_in_html_are_chunked_by_language() {
+    let page = r#"<html>
+<head>
+<script src="/static/vendor/chart.js"></script>
+<style>
+.chart { height: 240px; }
+</style>
+</head>
+<body>
+<h1>Dashboard</h1>
+<p>Weekly totals by region.</p>
+<h2>Charts</h2>
+<p>One chart per region.</p>
+<script src="/static/charts.js"></script>
+<script>
+function renderChart(element, data) {
+  return new Chart(element, { data });
+}
+</script>
+</body>
+</html>
+"#;
+    let input = llmx_mcp::FileInput {
+        path: "site/dashboard.html".to_string(),
+        data: page.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let index = ingest_files(vec![input], IngestOptions::default());
+    let chunks = &index.chunks;
+
+    let style = chunks.iter().find(|chunk| chunk.content.contains(".chart")).expect("style chunk");
+    assert_eq!((style.start_line, style.end_line), (5, 5));
+
+    let function = chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some("renderChart")).expect("script chunk");
+    assert_eq!(function.ast_kind, Some(llmx_mcp::AstNodeKind::Function));
+    assert_eq!((function.start_line, function.end_line), (15, 17));
+    assert_eq!(function.heading_path, vec!["Dashboard".to_string(), "Charts".to_string()]);
+
+    // A `<head>` without text gets a chunk of its script tags rather than lending
+    // the import to the stylesheet below it.
+    let importer = |src: &str| chunks.iter().find(|chunk| chunk.imports.iter().any(|import| import == src));
+    let head = importer("/static/vendor/chart.js").expect("head script import");
+    assert_eq!(head.kind, llmx_mcp::ChunkKind::Html);
+    assert_eq!((head.start_line, head.end_line), (3, 3));
+    assert!(head.heading_path.is_empty());
+    assert!(style.imports.is_empty());
+    let section = importer("/static/charts.js").expect("section script import");
+    assert!(section.content.contains("One chart per region."), "{}", section.content);
+    assert!(!chunks.iter().any(|chunk| chunk.kind == llmx_mcp::ChunkKind::Html && chunk.content.contains("renderChart")));
+}
+
+#[test]
+fn log_chunks_rank_below_docs_and_filter_by_level() {
+    let file = |path: &str, text: &str| llmx_mcp::FileInput {
+        path: path.to_string(),
+        data: text.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let log = "\
+2024-05-01T10:03:01Z INFO upstream timeout set to 30s
+2024-05-01T10:03:02Z ERROR upstream timeout after 30s
+";
+    let docs = "# Operations\n\nRaise the upstream timeout when the proxy is slow to answer requests from clients.\n";
+    let index = ingest_files(vec![file("logs/app.log", log), file("docs/ops.md", docs)], IngestOptions::default());
+    let log_chunk = index.chunks.iter().find(|chunk| chunk.path == "logs/app.log").expect("log chunk");
+    assert_eq!(log_chunk.log_level, Some(llmx_mcp::LogLevel::Error));
+
+    let search = |filters: llmx_mcp::SearchFilters| {
+        llmx_mcp::search_advanced(&index, "upstream timeout", filters, 10, false, llmx_mcp::QueryIntent::Semantic, false)
+            .expect("search")
+    };
+    let results = search(llmx_mcp::SearchFilters::default());
+    assert_eq!(results.first().map(|result| result.path.as_str()), Some("docs/ops.md"));
+
+    let errors = search(llmx_mcp::SearchFilters {
+        log_level: Some(llmx_mcp::LogLevel::Warn),
+        ..Default::default()
+    });
+    let paths: Vec<&str> = errors.iter().map(|result| result.path.as_str()).collect();
+    assert_eq!(paths, ["logs/app.log"]);
+}
+
+#[test]
+fn large_data_files_are_sampled_with_a_warning() {
+    let rows: String = (0..50).map(|id| format!("{id},item-{id}\n")).collect();
+    let input = llmx_mcp::FileInput {
+        path: "data/items.csv".to_string(),
+        data: format!("id,name\n{rows}").into_bytes(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let options = IngestOptions {
+        sample_data_rows: Some(10),
+        ..IngestOptions::default()
+    };
+    let index = ingest_files(vec![input], options);
+    assert_eq!(index.warnings.len(), 1);
+    assert_eq!(index.warnings[0].code, "sampled_rows");
+    assert_eq!(index.files[0].line_count, 51);
+
+    let combined: String = index.chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+    assert!(combined.starts_with("id,name\n0,item-0"));
+    assert!(combined.contains("9,item-9"));
+    assert!(!combined.contains("10,item-10"));
+}
+
+#[test]
+fn generated_files_are_flagged_ranked_lower_and_filterable() {
+    let file = |path: &str, text: &str| llmx_mcp::FileInput {
+        path: path.to_string(),
+        data: text.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let generated = "\
+// Code generated by protoc-gen-go. DO NOT EDIT.
+package users
+
+func (x *GetUserRequest) GetUserId() string { return x.UserId }
+";
+    let handwritten = "\
+package users
+
+// GetUserId returns the user id of the request.
+func GetUserId(request Request) string { return request.UserId }
+";
+    let index = ingest_files(
+        vec![
+            file(".gitattributes", "snapshots/** linguist-generated\n"),
+            file("api/users.pb.go", generated),
+            file("api/users.go", handwritten),
+            file("snapshots/users.txt", "GetUserId user id\n"),
+        ],
+        IngestOptions::default(),
+    );
+    let flagged: Vec<&str> =
+        index.files.iter().filter(|file| file.is_generated).map(|file| file.path.as_str()).collect();
+    assert_eq!(flagged, ["api/users.pb.go", "snapshots/users.txt"]);
+    assert!(index.chunks.iter().filter(|chunk| chunk.path == "api/users.pb.go").all(|chunk| chunk.is_generated));
+
+    let search = |filters: llmx_mcp::SearchFilters| {
+        llmx_mcp::search_advanced(&index, "GetUserId user id", filters, 10, false, llmx_mcp::QueryIntent::Semantic, false)
+            .expect("search")
+    };
+    let results = search(llmx_mcp::SearchFilters::default());
+    assert_eq!(results.first().map(|result| result.path.as_str()), Some("api/users.go"));
+
+    let handwritten_only = search(llmx_mcp::SearchFilters {
+        exclude_generated: true,
+        ..Default::default()
+    });
+    let paths: Vec<&str> = handwritten_only.iter().map(|result| result.path.as_str()).collect();
+    assert_eq!(paths, ["api/users.go"]);
+}
+
+#[test]
+fn handwritten_package_json_is_not_flagged_generated() {
+    let dependencies: Vec<String> =
+        (0..40).map(|idx| format!("    \"@scope/package-number-{idx}\": \"^1.{idx}.0\"")).collect();
+    let manifest = format!(
+        "{{\n  \"name\": \"web\",\n  \"version\": \"1.0.0\",\n  \"dependencies\": {{\n{}\n  }}\n}}\n",
+        dependencies.join(",\n")
+    );
+    assert!(manifest.len() > 1_024);
+    let input = llmx_mcp::FileInput {
+        path: "package.json".to_string(),
+        data: manifest.into_bytes(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let index = ingest_files(vec![input], IngestOptions::default());
+    assert!(!index.files[0].is_generated);
+    assert!(index.chunks.iter().all(|chunk| !chunk.is_generated), "{:?}", index.chunks);
+    assert!(index.chunks.iter().all(|chunk| chunk.quality_score >= Some(1_000)));
+}
+
+#[test]
+fn quality_priors_weight_ranking_and_show_in_explanations() {
+    let file = |path: &str, text: &str| llmx_mcp::FileInput {
+        path: path.to_string(),
+        data: text.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    let source = "/// Parse a retry policy from config.\npub fn parse_retry_policy(text: &str) -> Policy {\n    todo!()\n}\n";
+    let test = "#[test]\nfn parse_retry_policy_reads_limits() {\n    parse_retry_policy(\"retries = 3\");\n}\n";
+    let index = ingest_files(
+        vec![file("src/retry.rs", source), file("tests/retry_tests.rs", test)],
+        IngestOptions::default(),
+    );
+    assert!(index.chunks.iter().all(|chunk| chunk.quality_score.is_some()));
+
+    let results = llmx_mcp::search_advanced(
+        &index,
+        "parse_retry_policy",
+        llmx_mcp::SearchFilters::default(),
+        10,
+        false,
+        llmx_mcp::QueryIntent::Keyword,
+        true,
+    )
+    .expect("search");
+    let paths: Vec<&str> = results.iter().map(|result| result.path.as_str()).collect();
+    assert_eq!(paths, ["src/retry.rs", "tests/retry_tests.rs"]);
+    let reasons: Vec<&str> = results.iter().filter_map(|result| result.match_reason.as_deref()).collect();
+    assert!(reasons[0].contains("[priors: source ×1.00"), "{reasons:?}");
+    assert!(reasons[1].contains("[priors: test ×0.60"), "{reasons:?}");
+}
+
+#[test]
+fn go_packages_are_named_by_their_module_import_path() {
+    let file = |path: &str, text: &str| llmx_mcp::FileInput {
+        path: path.to_string(),
+        data: text.as_bytes().to_vec(),
+        mtime_ms: None,
+        fingerprint_sha256: None,
+    };
+    // The root go.mod is only on disk, the nested module's is passed in.
+    let root = tempfile::tempdir().expect("tempdir");
+    std::fs::write(root.path().join("go.mod"), "// Service.\nmodule example.com/app\n\ngo 1.22\n").expect("go.mod");
+    let index = llmx_mcp::ingest_files_with_root(
+        vec![
+            file("main.go", "package main\n\nfunc main() {}\n"),
+            file("internal/server/server.go", "package server\n\nfunc Start() {}\n"),
+            file("tools/go.mod", "module \"example.com/app/tools\"\n"),
+            file("tools/gen/gen.go", "package gen\n\nfunc Run() {}\n"),
+        ],
+        IngestOptions::default(),
+        Some(root.path()),
+    );
+    let module_path = |symbol: &str| {
+        let chunk = index.chunks.iter().find(|chunk| chunk.symbol.as_deref() == Some(symbol)).expect(symbol);
+        chunk.module_path.as_deref()
+    };
+    assert_eq!(module_path("main"), Some("example.com/app"));
+    assert_eq!(module_path("Start"), Some("example.com/app/internal/server"));
+    assert_eq!(module_path("Run"), Some("example.com/app/tools/gen"));
+}
@@ -149,6 +149,8 @@ fn test_handler_index_with_custom_options() {
             chunk_target_chars: Some(1000),
             max_file_bytes: Some(1024 * 1024),
             max_total_bytes: None,
+            notebook_outputs: None,
+            sample_data_rows: None,
         }),
     };
 
@@ -248,6 +250,8 @@ fn test_handler_search_with_path_filter() {
             kind: None,
             symbol_prefix: None,
             heading_prefix: None,
+            log_level: None,
+            exclude_generated: None,
         }),
         limit: Some(10),
         max_tokens: Some(16000),
@@ -771,6 +775,8 @@ fn test_handler_search_with_kind_filter() {
             kind: Some("markdown".to_string()),
             symbol_prefix: None,
             heading_prefix: None,
+            log_level: None,
+            exclude_generated: None,
         }),
         limit: Some(10),
         max_tokens: Some(16000),
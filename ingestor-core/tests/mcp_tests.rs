@@ -220,6 +220,8 @@ fn test_mcp_index_input_struct_construction() {
             chunk_target_chars: Some(3000),
             max_file_bytes: Some(1_000_000),
             max_total_bytes: None,
+            notebook_outputs: None,
+            sample_data_rows: None,
         }),
     };
 
@@ -240,6 +242,8 @@ fn test_mcp_search_input_struct_construction() {
             kind: Some("javascript".to_string()),
             symbol_prefix: None,
             heading_prefix: None,
+            log_level: None,
+            exclude_generated: None,
         }),
         limit: Some(15),
         max_tokens: Some(10_000),
@@ -340,3 +340,41 @@ export function startB(): boolean {
         target_chunk.path
     );
 }
+
+#[test]
+fn refs_type_users_finds_sql_files_touching_a_table() {
+    let (_project, mut store, index_id) = build_store(&[
+        (
+            "migrations/001_users.sql",
+            "CREATE TABLE users (\n  id serial PRIMARY KEY,\n  email text NOT NULL\n);\n",
+        ),
+        (
+            "migrations/002_email_index.sql",
+            "ALTER TABLE users ADD COLUMN seen_at timestamptz;\nCREATE INDEX users_email_idx ON users (email);\n",
+        ),
+        ("queries/active.sql", "SELECT u.email FROM users u WHERE u.seen_at > now() - interval '1 day';\n"),
+    ]);
+
+    let output = llmx_refs_handler(
+        &mut store,
+        RefsInput {
+            index_id: Some(index_id),
+            loc: None,
+            symbol: "users".to_string(),
+            direction: "type_users".to_string(),
+            depth: Some(1),
+            limit: Some(10),
+        },
+    )
+    .expect("refs should succeed");
+
+    let mut paths: Vec<&str> = output.refs.iter().map(|reference| reference.path.as_str()).collect();
+    paths.sort();
+    paths.dedup();
+    assert_eq!(
+        paths,
+        ["migrations/002_email_index.sql", "queries/active.sql"],
+        "got {:?}",
+        output.refs
+    );
+}
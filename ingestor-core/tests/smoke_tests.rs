@@ -226,6 +226,8 @@ fn smoke_search_with_filters() {
                 kind: kind.map(String::from),
                 symbol_prefix: None,
                 heading_prefix: None,
+                log_level: None,
+                exclude_generated: None,
             }),
             limit: Some(10),
             max_tokens: Some(8000),